@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use criterion::measurement::WallTime;
+
+use aoc_common::{Budget, Solution, Supervisor};
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(PartialEq, Copy, Clone)]
+enum Speed { Fast, Slow, Unbearable }
+
+// Scenarios explored by the searches too slow to finish, enough for their cost per step to show
+const UNBEARABLE_STEPS : usize = 2000;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn configure_group(group: &mut BenchmarkGroup<WallTime>, speed: Speed) {
+    // Criterion refuses less than ten samples, slow days only get a shorter measurement
+    if speed != Speed::Fast {
+        group.sample_size(10);
+        group.measurement_time(Duration::from_secs(1));
+        group.warm_up_time(Duration::from_millis(100));
+    }
+}
+
+fn limited_supervisor() -> Supervisor { Supervisor::new().with_budget(Budget::unlimited().with_max_steps(UNBEARABLE_STEPS)) }
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, input: &str, speed: Speed) {
+
+    let mut group = c.benchmark_group(format!("day_{:0>2}", day));
+    configure_group(&mut group, speed);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+
+    let parsed = S::parse(input).unwrap();
+    if speed != Speed::Unbearable {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+        // Day 25 only has a single puzzle
+        if day != 25 { group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed)))); }
+    } else {
+        // A single full search of day 23 takes minutes, the same number of steps of it is measured instead
+        group.bench_function(format!("part1 ({} steps)", UNBEARABLE_STEPS), |b| b.iter(|| S::part1_supervised(black_box(&parsed), limited_supervisor())));
+        group.bench_function(format!("part2 ({} steps)", UNBEARABLE_STEPS), |b| b.iter(|| S::part2_supervised(black_box(&parsed), limited_supervisor())));
+    }
+
+    group.finish();
+}
+
+// ================================================== BENCHES ==================================================
+
+fn bench_days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c, 1, include_str!("../../inputs/day01.txt"), Speed::Fast);
+    bench_day::<day_02::Day02>(c, 2, include_str!("../../inputs/day02.txt"), Speed::Fast);
+    bench_day::<day_03::Day03>(c, 3, include_str!("../../inputs/day03.txt"), Speed::Fast);
+    bench_day::<day_04::Day04>(c, 4, include_str!("../../inputs/day04.txt"), Speed::Fast);
+    bench_day::<day_05::Day05>(c, 5, include_str!("../../inputs/day05.txt"), Speed::Slow);
+    bench_day::<day_06::Day06>(c, 6, include_str!("../../inputs/day06.txt"), Speed::Fast);
+    bench_day::<day_07::Day07>(c, 7, include_str!("../../inputs/day07.txt"), Speed::Fast);
+    bench_day::<day_08::Day08>(c, 8, include_str!("../../inputs/day08.txt"), Speed::Fast);
+    bench_day::<day_09::Day09>(c, 9, include_str!("../../inputs/day09.txt"), Speed::Fast);
+    bench_day::<day_10::Day10>(c, 10, include_str!("../../inputs/day10.txt"), Speed::Fast);
+    bench_day::<day_11::Day11>(c, 11, include_str!("../../inputs/day11.txt"), Speed::Fast);
+    bench_day::<day_12::Day12>(c, 12, include_str!("../../inputs/day12.txt"), Speed::Slow);
+    bench_day::<day_13::Day13>(c, 13, include_str!("../../inputs/day13.txt"), Speed::Slow);
+    bench_day::<day_14::Day14>(c, 14, include_str!("../../inputs/day14.txt"), Speed::Fast);
+    bench_day::<day_15::Day15>(c, 15, include_str!("../../inputs/day15.txt"), Speed::Slow);
+    bench_day::<day_16::Day16>(c, 16, include_str!("../../inputs/day16.txt"), Speed::Fast);
+    bench_day::<day_17::Day17>(c, 17, include_str!("../../inputs/day17.txt"), Speed::Fast);
+    bench_day::<day_18::Day18>(c, 18, include_str!("../../inputs/day18.txt"), Speed::Slow);
+    bench_day::<day_19::Day19>(c, 19, include_str!("../../inputs/day19.txt"), Speed::Slow);
+    bench_day::<day_20::Day20>(c, 20, include_str!("../../inputs/day20.txt"), Speed::Slow);
+    bench_day::<day_21::Day21>(c, 21, include_str!("../../inputs/day21.txt"), Speed::Slow);
+    bench_day::<day_22::Day22>(c, 22, include_str!("../../inputs/day22.txt"), Speed::Slow);
+    bench_day::<day_23::Day23>(c, 23, include_str!("../../inputs/day23.txt"), Speed::Unbearable);
+    bench_day::<day_24::Day24>(c, 24, include_str!("../../inputs/day24.txt"), Speed::Slow);
+    bench_day::<day_25::Day25>(c, 25, include_str!("../../inputs/day25.txt"), Speed::Slow);
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+// ================================================== STRUCTS ==================================================
+
+// day -> input hash -> part -> answer
+type StoredAnswers = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+pub struct AnswerStore {
+    path:       PathBuf,
+    answers:    StoredAnswers,
+}
+
+pub enum Verdict {
+    Match,
+    Mismatch(String),
+    Missing,
+}
+
+const HASH_LENGTH : usize = 16;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+pub fn default_answers() -> PathBuf { PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("answers.toml") }
+
+pub fn hash_input(text: &str) -> String {
+    let digest = Sha256::digest(text.as_bytes());
+    let hex : String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    return hex[..HASH_LENGTH].to_owned();
+}
+
+fn day_key(day: u8) -> String { format!("day_{:0>2}", day) }
+fn part_key(part: u8) -> String { format!("part{}", part) }
+
+pub fn diff(expected: &str, actual: &str) -> String {
+
+    let expected_lines : Vec<&str> = expected.split('\n').collect();
+    let actual_lines : Vec<&str> = actual.split('\n').collect();
+
+    let mut lines : Vec<String> = Vec::new();
+    for index in 0..std::cmp::max(expected_lines.len(), actual_lines.len()) {
+
+        let expected_line = expected_lines.get(index);
+        let actual_line = actual_lines.get(index);
+        if expected_line == actual_line {
+            lines.push(format!("  {}", expected_line.unwrap()));
+            continue;
+        }
+
+        if let Some(expected_line) = expected_line { lines.push(format!("- {}", expected_line)) }
+        if let Some(actual_line) = actual_line { lines.push(format!("+ {}", actual_line)) }
+    }
+
+    return lines.join("\n");
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl AnswerStore {
+
+    pub fn load(path: &Path) -> Result<AnswerStore, String> {
+
+        // Nothing was recorded yet
+        if !path.exists() { return Ok(AnswerStore { path: path.to_path_buf(), answers: BTreeMap::new() }) }
+
+        let text = aoc_input::read_to_string(path).map_err(|error| error.to_string())?;
+        let answers : StoredAnswers = toml::from_str(&text)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        Ok(AnswerStore {
+            path: path.to_path_buf(),
+            answers: answers,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+
+        let text = toml::to_string(&self.answers).map_err(|error| error.to_string())?;
+        return std::fs::write(&self.path, text).map_err(|error| format!("{}: {}", self.path.display(), error));
+    }
+
+    pub fn get(&self, day: u8, input_hash: &str, part: u8) -> Option<&String> {
+        return self.answers.get(&day_key(day))
+            .and_then(|inputs| inputs.get(input_hash))
+            .and_then(|parts| parts.get(&part_key(part)));
+    }
+
+    pub fn record(&mut self, day: u8, input_hash: &str, part: u8, answer: &str) {
+        self.answers.entry(day_key(day)).or_default()
+            .entry(input_hash.to_owned()).or_default()
+            .insert(part_key(part), answer.to_owned());
+    }
+
+    pub fn check(&self, day: u8, input_hash: &str, part: u8, answer: &str) -> Verdict {
+        return match self.get(day, input_hash, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Match,
+            Some(expected) => Verdict::Mismatch(diff(expected, answer)),
+        };
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn recorded_answers_survive_a_round_trip() {
+
+        let mut store = AnswerStore { path: PathBuf::new(), answers: BTreeMap::new() };
+        store.record(13, "abc", 2, "\n# .\n. #\n");
+        store.record(1, "def", 1, "1564");
+
+        let answers : StoredAnswers = toml::from_str(&toml::to_string(&store.answers).unwrap()).unwrap();
+        let store = AnswerStore { path: PathBuf::new(), answers: answers };
+        assert!(matches!(store.check(13, "abc", 2, "\n# .\n. #\n"), Verdict::Match));
+        assert!(matches!(store.check(1, "def", 1, "1565"), Verdict::Mismatch(_)));
+        assert!(matches!(store.check(1, "other", 1, "1564"), Verdict::Missing));
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("1564", "1565"), "- 1564\n+ 1565");
+        assert_eq!(diff("# .\n. #", "# .\n# #\n"), "  # .\n- . #\n+ # #\n+ ");
+    }
+
+    #[test]
+    fn input_hash_is_stable() {
+        assert_eq!(hash_input("199\n200\n"), hash_input("199\n200\n"));
+        assert_ne!(hash_input("199\n200\n"), hash_input("199\n201\n"));
+        assert_eq!(hash_input("").len(), HASH_LENGTH);
+    }
+}
@@ -0,0 +1,77 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{Solution, Supervisor};
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum Part { One, Two }
+
+pub const NUMBER_OF_DAYS : u8 = 25;
+
+pub struct PartResult {
+    pub answer:     String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+type DaySolver = fn(Part, &str, Supervisor) -> Result<PartResult, String>;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+pub fn get_parts(day: u8) -> Vec<Part> {
+    // Day 25 only has a single puzzle
+    if day == NUMBER_OF_DAYS { vec!(Part::One) } else { vec!(Part::One, Part::Two) }
+}
+
+pub fn solve<S: Solution>(part: Part, input: &str, supervisor: Supervisor) -> Result<PartResult, String> {
+
+    let parse_start = Instant::now();
+    let parsed = S::parse(input).map_err(|error| error.to_string())?;
+    let parse_time = parse_start.elapsed();
+
+    let solve_start = Instant::now();
+    let answer = match part {
+        Part::One => S::part1_supervised(&parsed, supervisor).map(|answer| answer.to_string()),
+        Part::Two => S::part2_supervised(&parsed, supervisor).map(|answer| answer.to_string()),
+    }.map_err(|error| error.to_string())?;
+
+    return Ok(PartResult {
+        answer: answer,
+        parse_time: parse_time,
+        solve_time: solve_start.elapsed(),
+    });
+}
+
+pub fn get_solver(day: u8) -> Option<DaySolver> {
+    let solver : DaySolver = match day {
+        1 => solve::<day_01::Day01>,
+        2 => solve::<day_02::Day02>,
+        3 => solve::<day_03::Day03>,
+        4 => solve::<day_04::Day04>,
+        5 => solve::<day_05::Day05>,
+        6 => solve::<day_06::Day06>,
+        7 => solve::<day_07::Day07>,
+        8 => solve::<day_08::Day08>,
+        9 => solve::<day_09::Day09>,
+        10 => solve::<day_10::Day10>,
+        11 => solve::<day_11::Day11>,
+        12 => solve::<day_12::Day12>,
+        13 => solve::<day_13::Day13>,
+        14 => solve::<day_14::Day14>,
+        15 => solve::<day_15::Day15>,
+        16 => solve::<day_16::Day16>,
+        17 => solve::<day_17::Day17>,
+        18 => solve::<day_18::Day18>,
+        19 => solve::<day_19::Day19>,
+        20 => solve::<day_20::Day20>,
+        21 => solve::<day_21::Day21>,
+        22 => solve::<day_22::Day22>,
+        23 => solve::<day_23::Day23>,
+        24 => solve::<day_24::Day24>,
+        25 => solve::<day_25::Day25>,
+        _ => return None,
+    };
+
+    return Some(solver);
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::answers;
+
+// ================================================== STRUCTS ==================================================
+
+// day -> input name -> hash of the input
+type Checksums = BTreeMap<String, BTreeMap<String, String>>;
+
+/// A directory of inputs named `dayNN.txt` for the real one and `dayNN.<name>.txt` for the others,
+/// with the checksum of every one of them in `checksums.toml`.
+pub struct InputDirectory {
+    path:       PathBuf,
+    checksums:  Checksums,
+}
+
+pub enum Integrity {
+    Valid,
+    Corrupted(String),
+    Unknown,
+}
+
+pub const REAL_INPUT : &str = "real";
+const CHECKSUMS_FILE : &str = "checksums.toml";
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+pub fn default_directory() -> PathBuf { PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs") }
+
+fn day_key(day: u8) -> String { format!("day_{:0>2}", day) }
+
+fn file_name(day: u8, name: &str) -> String {
+    if name == REAL_INPUT { format!("day{:0>2}.txt", day) } else { format!("day{:0>2}.{}.txt", day, name) }
+}
+
+/// The day and name of an input from its file name, `None` for files that do not follow the convention.
+fn parse_file_name(file_name: &str) -> Option<(u8, String)> {
+
+    let stem = file_name.strip_prefix("day")?.strip_suffix(".txt")?;
+    let (day, name) = match stem.split_once('.') {
+        Some((day, name)) if !name.is_empty() && !name.contains('.') => (day, name),
+        Some(_) => return None,
+        None => (stem, REAL_INPUT),
+    };
+
+    if day.len() != 2 { return None }
+    return day.parse().ok().map(|day| (day, name.to_owned()));
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl InputDirectory {
+
+    pub fn load(path: &Path) -> Result<InputDirectory, String> {
+
+        let checksums_path = path.join(CHECKSUMS_FILE);
+        // Nothing was checked yet
+        if !checksums_path.exists() { return Ok(InputDirectory { path: path.to_path_buf(), checksums: BTreeMap::new() }) }
+
+        let text = aoc_input::read_to_string(&checksums_path).map_err(|error| error.to_string())?;
+        let checksums : Checksums = toml::from_str(&text)
+            .map_err(|error| format!("{}: {}", checksums_path.display(), error))?;
+
+        Ok(InputDirectory {
+            path: path.to_path_buf(),
+            checksums: checksums,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+
+        let checksums_path = self.path.join(CHECKSUMS_FILE);
+        let text = toml::to_string(&self.checksums).map_err(|error| error.to_string())?;
+        return std::fs::write(&checksums_path, text).map_err(|error| format!("{}: {}", checksums_path.display(), error));
+    }
+
+    pub fn path_of(&self, day: u8, name: &str) -> PathBuf { self.path.join(file_name(day, name)) }
+
+    /// Every input in the directory, by day and then by name.
+    pub fn list(&self) -> Result<Vec<(u8, String)>, String> {
+
+        let entries = std::fs::read_dir(&self.path).map_err(|error| format!("{}: {}", self.path.display(), error))?;
+        let mut inputs : Vec<(u8, String)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().to_str().and_then(parse_file_name))
+            .collect();
+
+        inputs.sort();
+        return Ok(inputs);
+    }
+
+    pub fn check(&self, day: u8, name: &str, text: &str) -> Integrity {
+        let input_hash = answers::hash_input(text);
+        return match self.checksums.get(&day_key(day)).and_then(|names| names.get(name)) {
+            None => Integrity::Unknown,
+            Some(expected) if *expected == input_hash => Integrity::Valid,
+            Some(expected) => Integrity::Corrupted(format!("expected checksum {}, found {}", expected, input_hash)),
+        };
+    }
+
+    /// Drops every checksum, so inputs that were deleted stop being listed in the file.
+    pub fn clear(&mut self) { self.checksums.clear() }
+
+    pub fn record(&mut self, day: u8, name: &str, text: &str) {
+        self.checksums.entry(day_key(day)).or_default().insert(name.to_owned(), answers::hash_input(text));
+    }
+
+    /// Reads the named input of the day, refusing it when it no longer matches its checksum.
+    pub fn read(&self, day: u8, name: &str) -> Result<String, String> {
+
+        let path = self.path_of(day, name);
+        if !path.exists() { return Err(format!("day {} has no input named '{}' ({} is missing)", day, name, path.display())) }
+
+        let text = aoc_input::read_to_string(&path).map_err(|error| error.to_string())?;
+        return match self.check(day, name, &text) {
+            Integrity::Valid => Ok(text),
+            Integrity::Corrupted(reason) => Err(format!("input '{}' changed since it was checked, {}", name, reason)),
+            Integrity::Unknown => {
+                log::warn!("Input '{}' of day {} has no checksum yet, run `aoc inputs --update` to record it", name, day);
+                Ok(text)
+            },
+        };
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn file_names_follow_the_convention() {
+        assert_eq!(file_name(1, REAL_INPUT), "day01.txt");
+        assert_eq!(file_name(23, "unfolded"), "day23.unfolded.txt");
+        assert_eq!(parse_file_name("day01.txt"), Some((1, REAL_INPUT.to_owned())));
+        assert_eq!(parse_file_name("day23.unfolded.txt"), Some((23, "unfolded".to_owned())));
+        assert_eq!(parse_file_name("checksums.toml"), None);
+        assert_eq!(parse_file_name("day1.txt"), None);
+        assert_eq!(parse_file_name("day01.a.b.txt"), None);
+    }
+
+    #[test]
+    fn changed_inputs_are_caught() {
+        let mut directory = InputDirectory { path: PathBuf::new(), checksums: BTreeMap::new() };
+        directory.record(1, "example", "199\n200\n");
+        assert!(matches!(directory.check(1, "example", "199\n200\n"), Integrity::Valid));
+        assert!(matches!(directory.check(1, "example", "199\n201\n"), Integrity::Corrupted(_)));
+        assert!(matches!(directory.check(1, REAL_INPUT, "199\n200\n"), Integrity::Unknown));
+    }
+
+    #[test]
+    fn committed_inputs_match_their_checksums() {
+        let directory = InputDirectory::load(&default_directory()).unwrap();
+        for (day, name) in directory.list().unwrap() {
+            assert!(directory.read(day, &name).is_ok(), "day {} input '{}'", day, name);
+            assert!(!matches!(directory.check(day, &name, &directory.read(day, &name).unwrap()), Integrity::Unknown), "day {} input '{}'", day, name);
+        }
+    }
+}
@@ -0,0 +1,479 @@
+mod answers;
+mod days;
+mod inputs;
+mod output;
+mod progress;
+mod repl;
+mod schedule;
+mod visualize;
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser, Subcommand};
+
+use answers::{AnswerStore, Verdict};
+use aoc_common::{Budget, Supervisor};
+
+use days::{Part, PartResult};
+use inputs::{InputDirectory, Integrity};
+use output::{Format, Record};
+use visualize::PaletteName;
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Answers file used to record and verify answers
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+    /// Directory holding the inputs of every day as dayNN.txt and dayNN.<name>.txt
+    #[arg(long, global = true, value_name = "DIR")]
+    inputs: Option<PathBuf>,
+    /// Show what the days log, once for info, twice for debug and three times for trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArgs),
+    /// Check answers against the ones previously recorded
+    Verify(Selection),
+    /// Write a random input for a day, to stress or benchmark it past the official input
+    Generate(GenerateArgs),
+    /// List the inputs of every day and check them against their checksums
+    Inputs(InputsArgs),
+    /// Step through the simulation of a day interactively
+    Repl(ReplArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day to solve
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Solve every day in order
+    #[arg(long)]
+    all: bool,
+    /// Only solve the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file to use instead of the day's committed input
+    #[arg(short, long, conflicts_with_all = ["all", "name"])]
+    input: Option<PathBuf>,
+    /// Named input of the inputs directory to use, such as example or stress
+    #[arg(short, long, default_value = inputs::REAL_INPUT)]
+    name: String,
+    /// Seconds every part may run before giving up, only the searches of days 12, 19, 23 and 24 check it
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+    /// Parts solved at the same time, answers are still printed in order
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Print a timing table once every part is solved
+    #[arg(long)]
+    summary: bool,
+    /// Solve every part this many times and keep the fastest timings
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// How answers are printed, progress and errors always go to stderr
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Store the answers so later runs can be verified against them
+    #[arg(long)]
+    record: bool,
+    /// Dump the frames of the simulation days (6, 11, 13, 20, 22 and 25) into DIR/day_NN
+    #[arg(long, value_name = "DIR")]
+    visualize: Option<PathBuf>,
+    /// Encode the frames of the day as a single .gif or .png/.apng animation
+    #[arg(long, value_name = "FILE", conflicts_with = "all")]
+    animate: Option<PathBuf>,
+    /// Milliseconds every frame of the animation stays on screen
+    #[arg(long, default_value_t = 100, requires = "animate")]
+    frame_delay: u64,
+    /// Side in pixels of every cell in the frames
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    cell_size: u32,
+    /// Palette to draw the frames with instead of the day's own
+    #[arg(long, value_enum)]
+    palette: Option<PaletteName>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Seed of the generator, the same seed and size always give the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// How big the input is, about the size of the official one by default
+    #[arg(long)]
+    size: Option<usize>,
+    /// File to write the input to instead of stdout
+    #[arg(short, long, conflicts_with = "name")]
+    output: Option<PathBuf>,
+    /// Save the input in the inputs directory under this name and record its checksum
+    #[arg(short, long)]
+    name: Option<String>,
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// Day to load before the first command
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Input file to load the day with instead of its committed input
+    #[arg(short, long, requires = "day", conflicts_with = "name")]
+    input: Option<PathBuf>,
+    /// Named input of the inputs directory to load the day with
+    #[arg(short, long, requires = "day", default_value = inputs::REAL_INPUT)]
+    name: String,
+}
+
+#[derive(Args)]
+struct InputsArgs {
+    /// Record the checksums of the inputs as they are now
+    #[arg(long)]
+    update: bool,
+}
+
+/// One part of one day, what the runner hands to its threads.
+struct Task {
+    day:            u8,
+    part:           Part,
+    part_number:    u8,
+}
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn convert_part(part: u8) -> Part {
+    return match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => panic!("🚨  Part '{}' not recognized!", part),
+    };
+}
+
+fn read_input(day: u8, selection: &Selection, inputs: &InputDirectory) -> Result<String, String> {
+    return match selection.input.as_ref() {
+        Some(path) => aoc_input::read_to_string(path).map_err(|error| error.to_string()),
+        None => inputs.read(day, &selection.name),
+    };
+}
+
+/// Every run of a part gets its own observer and, with a timeout, its own deadline.
+fn build_supervisor(day: u8, part_number: u8, selection: &Selection) -> Supervisor {
+
+    // Parts solved side by side would keep overwriting each other's progress line
+    let supervisor = if selection.jobs == 1 { Supervisor::new().with_observer(progress::terminal_observer(day, part_number)) }
+        else { Supervisor::new() };
+    return match selection.timeout {
+        Some(seconds) => supervisor.with_budget(Budget::unlimited().with_timeout(Duration::from_secs(seconds))),
+        None => supervisor,
+    };
+}
+
+fn solve_part(task: &Task, selection: &Selection, inputs: &InputDirectory, repeat: u32) -> Result<(PartResult, String), String> {
+
+    let solver = days::get_solver(task.day).ok_or(format!("day {} has no solver", task.day))?;
+    let text = read_input(task.day, selection, inputs)?;
+
+    // Keep the fastest run, the others are mostly noise from the rest of the system
+    let mut best = solver(task.part, &text, build_supervisor(task.day, task.part_number, selection))?;
+    for _ in 1..repeat {
+        let result = solver(task.part, &text, build_supervisor(task.day, task.part_number, selection))?;
+        best.parse_time = std::cmp::min(best.parse_time, result.parse_time);
+        best.solve_time = std::cmp::min(best.solve_time, result.solve_time);
+    }
+
+    return Ok((best, answers::hash_input(&text)));
+}
+
+/// Every selected part of the selected days, in the order their answers are printed.
+fn get_tasks(selection: &Selection) -> (Vec<Task>, bool) {
+
+    let mut tasks : Vec<Task> = Vec::new();
+    let mut success = true;
+    for day in get_days(selection) {
+
+        let mut parts = days::get_parts(day);
+        if let Some(part) = selection.part { parts.retain(|&day_part| day_part == convert_part(part)) }
+        if parts.len() == 0 {
+            eprintln!("🚨  Day {:0>2} has no part {}", day, selection.part.unwrap());
+            success = false;
+        }
+
+        for part in parts {
+            let part_number = if part == Part::One { 1 } else { 2 };
+            tasks.push(Task { day: day, part: part, part_number: part_number });
+        }
+    }
+
+    return (tasks, success);
+}
+
+/// Solves the selected parts on `--jobs` threads, `on_record` still sees them day by day and part by part.
+fn solve_days<F: FnMut(Record)>(selection: &Selection, inputs: &InputDirectory, repeat: u32, mut on_record: F) -> bool {
+
+    let (tasks, mut success) = get_tasks(selection);
+    schedule::run_in_order(&tasks, selection.jobs as usize, |task| solve_part(task, selection, inputs, repeat), |task, solved| {
+        progress::clear_progress();
+        match solved.map_err(|error| error.to_string()).and_then(|solved| solved) {
+            Ok((result, input_hash)) => on_record(Record::new(task.day, task.part_number, result.answer, input_hash, result.parse_time, result.solve_time)),
+            Err(error) => {
+                eprintln!("🚨  Day {:0>2} Part {}: {}", task.day, task.part_number, error);
+                success = false;
+            },
+        }
+    });
+
+    return success;
+}
+
+fn visualize_day(day: u8, selection: &Selection, inputs: &InputDirectory, output_dir: &Path, cell_size: u32, palette: Option<PaletteName>) -> bool {
+
+    // Asking for every day only dumps the ones with a simulation
+    if !visualize::has_visualization(day) {
+        if !selection.all { eprintln!("🚨  Day {:0>2} has no visualization", day) }
+        return selection.all;
+    }
+
+    let frames = read_input(day, selection, inputs).and_then(|text| visualize::visualize(day, &text, output_dir, cell_size, palette));
+    return match frames {
+        Ok(writer) => {
+            eprintln!("🎞️  Day {:0>2}: {} frames in {}", day, writer.frames_written(), writer.output_dir().display());
+            true
+        },
+        Err(error) => {
+            eprintln!("🚨  Day {:0>2} visualization: {}", day, error);
+            false
+        },
+    };
+}
+
+fn animate_day(day: u8, args: &RunArgs, inputs: &InputDirectory, path: &Path) -> bool {
+
+    if !visualize::has_visualization(day) {
+        eprintln!("🚨  Day {:0>2} has no visualization", day);
+        return false;
+    }
+
+    let frame_delay = Duration::from_millis(args.frame_delay);
+    let animation = read_input(day, &args.selection, inputs)
+        .and_then(|text| visualize::animate(day, &text, path, args.cell_size, args.palette, frame_delay));
+    return match animation {
+        Ok(animation) => {
+            eprintln!("🎞️  Day {:0>2}: {} frames animated in {}", day, animation.frame_count(), path.display());
+            true
+        },
+        Err(error) => {
+            eprintln!("🚨  Day {:0>2} animation: {}", day, error);
+            false
+        },
+    };
+}
+
+fn get_days(selection: &Selection) -> Vec<u8> {
+    if selection.all { (1..=days::NUMBER_OF_DAYS).collect() } else { vec!(selection.day.unwrap()) }
+}
+
+fn load_inputs(inputs: &Option<PathBuf>) -> InputDirectory {
+
+    let path = inputs.clone().unwrap_or_else(inputs::default_directory);
+    return match InputDirectory::load(&path) {
+        Ok(directory) => directory,
+        Err(error) => {
+            eprintln!("🚨  {}", error);
+            std::process::exit(1);
+        },
+    };
+}
+
+fn load_store(answers: &Option<PathBuf>) -> AnswerStore {
+
+    let path = answers.clone().unwrap_or_else(answers::default_answers);
+    return match AnswerStore::load(&path) {
+        Ok(store) => store,
+        Err(error) => {
+            eprintln!("🚨  {}", error);
+            std::process::exit(1);
+        },
+    };
+}
+
+// ================================================== COMMANDS ==================================================
+
+fn run(args: RunArgs, answers: &Option<PathBuf>, inputs: &InputDirectory) -> bool {
+
+    let mut store = if args.record { Some(load_store(answers)) } else { None };
+    let mut records : Vec<Record> = Vec::new();
+    let start = Instant::now();
+    let success = solve_days(&args.selection, inputs, args.repeat, |record| {
+        output::print_record(args.format, &record);
+        records.push(record);
+    });
+    let elapsed = start.elapsed();
+
+    output::print_records(args.format, &records).expect("🚨  Could not write the answers");
+    // Keep stdout parseable when answers are structured
+    if args.summary && args.format == Format::Text { output::print_summary(&mut std::io::stdout(), &records, elapsed).unwrap() }
+    else if args.summary { output::print_summary(&mut std::io::stderr(), &records, elapsed).unwrap() }
+
+    let mut visualized = true;
+    if let Some(output_dir) = args.visualize.as_ref() {
+        visualized = get_days(&args.selection).into_iter()
+            .map(|day| visualize_day(day, &args.selection, inputs, output_dir, args.cell_size, args.palette))
+            .collect::<Vec<bool>>()
+            .into_iter().all(|day_success| day_success);
+    }
+    if let Some(path) = args.animate.as_ref() {
+        visualized = animate_day(args.selection.day.unwrap(), &args, inputs, path) && visualized;
+    }
+
+    if let Some(store) = store.as_mut() {
+        for record in records.iter() { store.record(record.day, &record.input_hash, record.part, &record.answer) }
+        if let Err(error) = store.save() {
+            eprintln!("🚨  {}", error);
+            return false;
+        }
+    }
+
+    return success && visualized;
+}
+
+fn verify(selection: Selection, answers: &Option<PathBuf>, inputs: &InputDirectory) -> bool {
+
+    let store = load_store(answers);
+    let mut matching = true;
+    let success = solve_days(&selection, inputs, 1, |record| {
+        match store.check(record.day, &record.input_hash, record.part, &record.answer) {
+            Verdict::Match => println!("✅  Day {:0>2} Part {}: {}", record.day, record.part, record.answer),
+            Verdict::Missing => println!("❔  Day {:0>2} Part {}: no answer recorded for input {}", record.day, record.part, record.input_hash),
+            Verdict::Mismatch(diff) => {
+                println!("❌  Day {:0>2} Part {}: answer changed\n{}", record.day, record.part, diff);
+                matching = false;
+            },
+        }
+    });
+
+    return success && matching;
+}
+
+fn generate(args: GenerateArgs, inputs: &mut InputDirectory) -> bool {
+
+    let size = args.size.unwrap_or_else(|| aoc_generator::default_size(args.day));
+    let input = aoc_generator::generate(args.day, args.seed, size).expect("🚨  Every day has a generator");
+    let written = match (args.output.as_ref(), args.name.as_ref()) {
+        (Some(path), _) => std::fs::write(path, &input).map_err(|error| format!("could not write '{}': {}", path.display(), error)),
+        (None, Some(name)) => {
+            // A named input is only trusted once its checksum is stored next to it
+            let path = inputs.path_of(args.day, name);
+            inputs.record(args.day, name, &input);
+            std::fs::write(&path, &input).map_err(|error| format!("could not write '{}': {}", path.display(), error))
+                .and_then(|_| inputs.save())
+        },
+        (None, None) => std::io::Write::write_all(&mut std::io::stdout(), input.as_bytes()).map_err(|error| error.to_string()),
+    };
+
+    if let Err(error) = written.as_ref() { eprintln!("🚨  {}", error) }
+    return written.is_ok();
+}
+
+fn list_inputs(args: InputsArgs, inputs: &mut InputDirectory) -> bool {
+
+    let listed = match inputs.list() {
+        Ok(listed) => listed,
+        Err(error) => {
+            eprintln!("🚨  {}", error);
+            return false;
+        },
+    };
+
+    if args.update { inputs.clear() }
+    let mut valid = true;
+    for (day, name) in listed.iter() {
+
+        let text = match aoc_input::read_to_string(inputs.path_of(*day, name)) {
+            Ok(text) => text,
+            Err(error) => {
+                eprintln!("🚨  {}", error);
+                valid = false;
+                continue;
+            },
+        };
+
+        if args.update { inputs.record(*day, name, &text) }
+        match inputs.check(*day, name, &text) {
+            Integrity::Valid => println!("✅  Day {:0>2} {}", day, name),
+            Integrity::Unknown => println!("❔  Day {:0>2} {}: no checksum recorded", day, name),
+            Integrity::Corrupted(reason) => {
+                println!("❌  Day {:0>2} {}: {}", day, name, reason);
+                valid = false;
+            },
+        }
+    }
+
+    if let Err(error) = if args.update { inputs.save() } else { Ok(()) } {
+        eprintln!("🚨  {}", error);
+        return false;
+    }
+
+    return valid;
+}
+
+fn explore(args: ReplArgs, inputs: &InputDirectory) -> bool {
+
+    let mut session = repl::Session::new(inputs).with_prompt(std::io::stdin().is_terminal());
+    if let Some(day) = args.day {
+        let input = match args.input.as_ref() {
+            Some(path) => aoc_input::read_to_string(path).map_err(|error| error.to_string()),
+            None => inputs.read(day, &args.name),
+        };
+
+        match input.and_then(|input| session.load(day, input)) {
+            Ok(loaded) => println!("{}", loaded),
+            Err(error) => {
+                eprintln!("🚨  {}", error);
+                return false;
+            },
+        }
+    }
+
+    if let Err(error) = session.run(std::io::stdin().lock(), &mut std::io::stdout()) {
+        eprintln!("🚨  {}", error);
+        return false;
+    }
+
+    return true;
+}
+
+// ================================================== MAIN ==================================================
+
+fn main() {
+
+    let cli = Cli::parse();
+    progress::init_logger(cli.verbose);
+    let mut inputs = load_inputs(&cli.inputs);
+    let success = match cli.command {
+        Command::Run(args) => run(args, &cli.answers, &inputs),
+        Command::Verify(selection) => verify(selection, &cli.answers, &inputs),
+        Command::Generate(args) => generate(args, &mut inputs),
+        Command::Inputs(args) => list_inputs(args, &mut inputs),
+        Command::Repl(args) => explore(args, &inputs),
+    };
+
+    if !success { std::process::exit(1) }
+}
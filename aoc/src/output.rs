@@ -0,0 +1,134 @@
+use std::io::Write;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(ValueEnum, PartialEq, Copy, Clone)]
+pub enum Format { Text, Json, Csv }
+
+#[derive(Serialize)]
+pub struct Record {
+    pub day:            u8,
+    pub part:           u8,
+    pub answer:         String,
+    pub duration_ms:    f64,
+    #[serde(skip)]
+    pub input_hash:     String,
+    #[serde(skip)]
+    pub parse_time:     Duration,
+    #[serde(skip)]
+    pub solve_time:     Duration,
+}
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+pub fn as_millis(duration: Duration) -> f64 { duration.as_secs_f64() * 1000.0 }
+
+fn escape_csv(value: &str) -> String {
+    if !value.contains([',', '"', '\n']) { return value.to_owned() }
+    return format!("\"{}\"", value.replace('"', "\"\""));
+}
+
+fn write_json(out: &mut dyn Write, records: &Vec<Record>) -> std::io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, records)?;
+    return writeln!(out);
+}
+
+fn write_csv(out: &mut dyn Write, records: &Vec<Record>) -> std::io::Result<()> {
+
+    writeln!(out, "day,part,answer,duration_ms")?;
+    for record in records.iter() {
+        writeln!(out, "{},{},{},{:.3}", record.day, record.part, escape_csv(&record.answer), record.duration_ms)?;
+    }
+
+    return Ok(());
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Record {
+
+    pub fn new(day: u8, part: u8, answer: String, input_hash: String, parse_time: Duration, solve_time: Duration) -> Record {
+        Record {
+            day: day,
+            part: part,
+            answer: answer,
+            duration_ms: as_millis(parse_time + solve_time),
+            input_hash: input_hash,
+            parse_time: parse_time,
+            solve_time: solve_time,
+        }
+    }
+}
+
+// ================================================== OUTPUT ==================================================
+
+pub fn print_record(format: Format, record: &Record) {
+    // Structured formats are written all at once, after every day was solved
+    if format != Format::Text { return }
+    println!("🎄  Day {:0>2} Part {}: {} ({:.3} ms)", record.day, record.part, record.answer, record.duration_ms);
+}
+
+pub fn print_records(format: Format, records: &Vec<Record>) -> std::io::Result<()> {
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    return match format {
+        Format::Text => Ok(()),
+        Format::Json => write_json(&mut out, records),
+        Format::Csv => write_csv(&mut out, records),
+    };
+}
+
+/// `elapsed` is the wall clock of the whole run, with `--jobs` it drops below the total of the parts.
+pub fn print_summary(out: &mut dyn Write, records: &Vec<Record>, elapsed: Duration) -> std::io::Result<()> {
+
+    writeln!(out)?;
+    writeln!(out, "{: >4} | {: >4} | {: >12} | {: >12} | {: >12}", "Day", "Part", "Parse (ms)", "Solve (ms)", "Total (ms)")?;
+    writeln!(out, "{}", "-".repeat(4 + 4 + 12 * 3 + 3 * 4))?;
+    for record in records.iter() {
+        writeln!(out, "{: >4} | {: >4} | {: >12.3} | {: >12.3} | {: >12.3}", format!("{:0>2}", record.day), record.part,
+            as_millis(record.parse_time), as_millis(record.solve_time), record.duration_ms)?;
+    }
+
+    let parse_total : Duration = records.iter().map(|record| record.parse_time).sum();
+    let solve_total : Duration = records.iter().map(|record| record.solve_time).sum();
+    writeln!(out, "{}", "-".repeat(4 + 4 + 12 * 3 + 3 * 4))?;
+    writeln!(out, "{: >4} | {: >4} | {: >12.3} | {: >12.3} | {: >12.3}", "All", records.len(),
+        as_millis(parse_total), as_millis(solve_total), as_millis(parse_total + solve_total))?;
+    return writeln!(out, "Wall clock: {:.3} ms", as_millis(elapsed));
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn csv_quotes_multiline_answers() {
+        assert_eq!(escape_csv("1564"), "1564");
+        assert_eq!(escape_csv("\n# .\n"), "\"\n# .\n\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn csv_has_one_row_per_record() {
+        let records = vec!(Record::new(1, 1, "7".to_owned(), String::new(), Duration::from_millis(1), Duration::from_millis(2)));
+        let mut out : Vec<u8> = Vec::new();
+        write_csv(&mut out, &records).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "day,part,answer,duration_ms\n1,1,7,3.000\n");
+    }
+
+    #[test]
+    fn summary_ends_with_the_wall_clock() {
+        let records = vec!(Record::new(1, 1, "7".to_owned(), String::new(), Duration::from_millis(1), Duration::from_millis(2)));
+        let mut out : Vec<u8> = Vec::new();
+        print_summary(&mut out, &records, Duration::from_millis(2)).unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with("|        3.000\nWall clock: 2.000 ms\n"));
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::IsTerminal;
+use std::time::{Duration, Instant};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use aoc_common::{NoProgress, Progress, ProgressObserver};
+
+// ================================================== STRUCTS ==================================================
+
+/// Prints the log records of the days to stderr, each on its own line.
+struct StderrLogger;
+
+static LOGGER : StderrLogger = StderrLogger;
+
+// Solvers may report millions of times, the terminal only needs a few updates per second
+const REFRESH_PERIOD : Duration = Duration::from_millis(100);
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn show_progress() -> bool { std::io::stderr().is_terminal() }
+
+fn describe_progress(progress: &Progress) -> String {
+    return match progress.total {
+        Some(total) if total != 0 => format!("{} {} out of {} ({:.2}%)", progress.stage, progress.done, total, progress.done as f64 / total as f64 * 100.0),
+        _ => format!("{} {}", progress.done, progress.stage),
+    };
+}
+
+/// No verbosity only shows warnings, every `-v` adds a level up to trace.
+pub fn init_logger(verbosity: u8) {
+
+    let level : LevelFilter = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    log::set_logger(&LOGGER).expect("🚨  The logger was already set");
+    log::set_max_level(level);
+}
+
+/// A line on stderr rewritten as the part goes, only when stderr is a terminal.
+pub fn terminal_observer(day: u8, part_number: u8) -> Box<dyn ProgressObserver> {
+
+    if !show_progress() { return Box::new(NoProgress) }
+
+    let mut last_refresh : Option<Instant> = None;
+    return Box::new(move |progress: &Progress| {
+
+        if last_refresh.is_some_and(|instant| instant.elapsed() < REFRESH_PERIOD) { return }
+        last_refresh = Some(Instant::now());
+        eprint!("\r\x1b[2K⚙️  Day {:0>2} Part {}: {}...", day, part_number, describe_progress(progress));
+    });
+}
+
+/// Wipes whatever progress line was left before printing anything else.
+pub fn clear_progress() {
+    if show_progress() { eprint!("\r\x1b[2K") }
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Log for StderrLogger {
+
+    fn enabled(&self, metadata: &Metadata) -> bool { metadata.level() <= log::max_level() }
+
+    fn log(&self, record: &Record) {
+
+        if !self.enabled(record.metadata()) { return }
+        clear_progress();
+        match record.level() {
+            Level::Error | Level::Warn => eprintln!("🚨  {}", record.args()),
+            _ => eprintln!("{}", record.args()),
+        }
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,441 @@
+use std::io::{BufRead, Write};
+
+use aoc_common::Solution;
+
+use crate::inputs::{InputDirectory, REAL_INPUT};
+
+// ================================================== STRUCTS ==================================================
+
+/// A day whose puzzle can be driven one step at a time.
+trait Simulation {
+    /// Moves one step forward, stepping a simulation that is over does nothing.
+    fn step(&mut self) -> Result<(), String>;
+    /// True once the puzzle of the day would stop stepping.
+    fn is_over(&self) -> bool;
+    fn show(&self) -> String;
+    /// Every value that can be asked for with `get`, by name.
+    fn values(&self) -> Vec<(&'static str, String)>;
+}
+
+type Loader = fn(&str) -> Result<Box<dyn Simulation>, String>;
+
+/// The day being explored, along with what is needed to start it again.
+struct Loaded {
+    day:            u8,
+    input:          String,
+    simulation:     Box<dyn Simulation>,
+    steps:          usize,
+}
+
+/// An interactive session reading commands from `reader` and answering on `writer`.
+pub struct Session<'a> {
+    inputs:         &'a InputDirectory,
+    loaded:         Option<Loaded>,
+    prompt:         bool,
+}
+
+/// Day 11 is over once every octopus flashed on the same step.
+struct Octopi(day_11::Map);
+
+/// Day 25 is over once a step leaves every sea cucumber in place.
+struct SeaCucumbers {
+    map:            day_25::Map,
+    moving:         bool,
+}
+
+/// Day 22 only follows the cubes close to the origin, like its first part.
+struct Reactor {
+    map:            day_22::LimitedMap,
+    rules:          usize,
+    rules_done:     usize,
+}
+
+const HELP : &str = "\
+day <N> [NAME]  explore a day with its real input or the named one
+step [N]        move one or N steps forward
+run             step until the simulation is over
+show            print the current state
+get [VALUE]     print a value, or every one of them
+reset           start the day again
+help            print this help
+quit            leave";
+
+// Days 6, 14 and 20 could go on forever, these are the steps their puzzles stop at
+const LANTERNFISH_DAYS : usize = 256;
+const POLYMER_STEPS : usize = 10;
+const ENHANCEMENTS : usize = 50;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn describe<E: ToString>(error: E) -> String { error.to_string() }
+
+fn load_lanternfish(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let lifes = day_06::Day06::parse(input).map_err(describe)?;
+    return Ok(Box::new(day_06::ExponentialSeaFloor::new(lifes).map_err(describe)?));
+}
+
+fn load_octopi(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let energy_levels = day_11::Day11::parse(input).map_err(describe)?;
+    return Ok(Box::new(Octopi(day_11::Map::new(energy_levels).map_err(describe)?)));
+}
+
+fn load_origami(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let (points, folds) = day_13::Day13::parse(input).map_err(describe)?;
+    return Ok(Box::new(day_13::Map::new(points, folds).map_err(describe)?));
+}
+
+fn load_polymerization(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let (template, rules) = day_14::Day14::parse(input).map_err(describe)?;
+    return Ok(Box::new(day_14::Polymerization::new(template, rules)));
+}
+
+fn load_scanners(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let scanners = day_19::Day19::parse(input).map_err(describe)?;
+    return Ok(Box::new(day_19::Map::new(scanners)));
+}
+
+fn load_trench_map(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let (codification, image) = day_20::Day20::parse(input).map_err(describe)?;
+    return Ok(Box::new(day_20::EnhancingTool::new(codification, image).map_err(describe)?));
+}
+
+fn load_dirac_dice(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let player_infos = day_21::Day21::parse(input).map_err(describe)?;
+    if player_infos.len() == 0 { return Err("there are no players".to_owned()) }
+    return Ok(Box::new(day_21::Game::new(player_infos, (1, 10), (1, 100), 1000, 3)));
+}
+
+fn load_reactor(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let rules = day_22::Day22::parse(input).map_err(describe)?;
+    let number_of_rules = rules.len();
+    let map = day_22::LimitedMap::new(rules, vec!(('x', -50, 50), ('y', -50, 50), ('z', -50, 50))).map_err(describe)?;
+    return Ok(Box::new(Reactor { map: map, rules: number_of_rules, rules_done: 0 }));
+}
+
+fn load_sea_cucumbers(input: &str) -> Result<Box<dyn Simulation>, String> {
+    let info = day_25::Day25::parse(input).map_err(describe)?;
+    return Ok(Box::new(SeaCucumbers { map: day_25::Map::new(info).map_err(describe)?, moving: true }));
+}
+
+fn get_loader(day: u8) -> Option<Loader> {
+    let loader : Loader = match day {
+        6 => load_lanternfish,
+        11 => load_octopi,
+        13 => load_origami,
+        14 => load_polymerization,
+        19 => load_scanners,
+        20 => load_trench_map,
+        21 => load_dirac_dice,
+        22 => load_reactor,
+        25 => load_sea_cucumbers,
+        _ => return None,
+    };
+
+    return Some(loader);
+}
+
+fn parse_count(argument: Option<&str>) -> Result<usize, String> {
+    return match argument {
+        None => Ok(1),
+        Some(count) => count.parse().map_err(|_| format!("'{}' is not a number of steps", count)),
+    };
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Simulation for day_06::ExponentialSeaFloor {
+    fn step(&mut self) -> Result<(), String> {
+        self.do_iteration();
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.get_days_done() as usize >= LANTERNFISH_DAYS }
+
+    fn show(&self) -> String {
+        let lifes : Vec<String> = self.get_fishes_by_life().iter().enumerate()
+            .map(|(life, fishes)| format!("{}: {}", life, fishes))
+            .collect();
+        return format!("After {} days: {}", self.get_days_done(), lifes.join(", "));
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("days", self.get_days_done().to_string()), ("fishes", self.number_of_fishes().to_string()))
+    }
+}
+
+impl Simulation for Octopi {
+    fn step(&mut self) -> Result<(), String> {
+        self.0.do_iteration();
+        return Ok(());
+    }
+
+    // Flashing octopi end their step at zero
+    fn is_over(&self) -> bool { self.0.get_iteration() != 0 && self.0.get_energy_levels().iter().all(|(_, &energy_level)| energy_level == 0) }
+    fn show(&self) -> String { self.0.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("steps", self.0.get_iteration().to_string()), ("flashes", self.0.get_number_of_flashes().to_string()))
+    }
+}
+
+impl Simulation for day_13::Map {
+    fn step(&mut self) -> Result<(), String> {
+        if !self.is_over() { self.make_next_fold() }
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.number_of_folds() == 0 }
+    fn show(&self) -> String { self.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("folds", self.number_of_folds().to_string()), ("dots", self.count_set().to_string()))
+    }
+}
+
+impl Simulation for day_14::Polymerization {
+    fn step(&mut self) -> Result<(), String> {
+        self.do_iteration();
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.get_current_iteration() >= POLYMER_STEPS }
+    fn show(&self) -> String { self.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        let counts = self.count_element();
+        let elements : Vec<String> = counts.iter().map(|(element, count)| format!("{}: {}", element, count)).collect();
+        let difference = counts.last().map(|most| most.1 - counts[0].1).unwrap_or(0);
+        return vec!(("steps", self.get_current_iteration().to_string()), ("elements", elements.join(", ")), ("difference", difference.to_string()));
+    }
+}
+
+impl Simulation for day_19::Map {
+    fn step(&mut self) -> Result<(), String> {
+        if !self.is_over() { self.add_next_scanner_info().map_err(describe)? }
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.number_of_scanner_processed() == self.get_number_of_scanners() }
+    fn show(&self) -> String { format!("{} out of {} scanners placed", self.number_of_scanner_processed(), self.get_number_of_scanners()) }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        let largest_distance = self.largest_distance_scanners()
+            .map(|(first, second, distance)| format!("{} between {} and {}", distance, first, second))
+            .unwrap_or_else(|| "no scanner placed".to_owned());
+        return vec!(("placed", self.number_of_scanner_processed().to_string()), ("beacons", self.compute_number_of_beacons().to_string()),
+            ("distance", largest_distance));
+    }
+}
+
+impl Simulation for day_20::EnhancingTool {
+    fn step(&mut self) -> Result<(), String> {
+        self.do_iteration();
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.get_current_iteration() >= ENHANCEMENTS }
+    fn show(&self) -> String { self.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("enhancements", self.get_current_iteration().to_string()), ("lit", self.compute_finite_number_of_characther('#').to_string()))
+    }
+}
+
+impl Simulation for day_21::Game {
+    fn step(&mut self) -> Result<(), String> {
+        self.next_play();
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.some_player_won() }
+    fn show(&self) -> String { self.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("rolls", self.get_iteration().to_string()), ("losing", self.get_loosing_score().to_string()),
+            ("product", (self.get_iteration() as day_21::Value * self.get_loosing_score()).to_string()))
+    }
+}
+
+impl Simulation for Reactor {
+    fn step(&mut self) -> Result<(), String> {
+        if !self.is_over() {
+            self.map.do_iteration();
+            self.rules_done = self.rules_done + 1;
+        }
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { self.map.completed_rules() }
+    fn show(&self) -> String { format!("{} out of {} rules applied", self.rules_done, self.rules) }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec!(("rules", self.rules_done.to_string()), ("on", self.map.compute_with_state(day_22::CubeState::On).to_string()))
+    }
+}
+
+impl Simulation for SeaCucumbers {
+    fn step(&mut self) -> Result<(), String> {
+        if self.moving { self.moving = self.map.iterate() }
+        return Ok(());
+    }
+
+    fn is_over(&self) -> bool { !self.moving }
+    fn show(&self) -> String { self.map.to_string() }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        let still = if self.moving { "not yet".to_owned() } else { self.map.get_final_iteration().to_string() };
+        return vec!(("still", still));
+    }
+}
+
+impl<'a> Session<'a> {
+
+    pub fn new(inputs: &'a InputDirectory) -> Session<'a> {
+        Session {
+            inputs: inputs,
+            loaded: None,
+            prompt: false,
+        }
+    }
+
+    /// Writes a prompt before every command, for people typing rather than scripts.
+    pub fn with_prompt(mut self, prompt: bool) -> Session<'a> {
+        self.prompt = prompt;
+        return self;
+    }
+
+    pub fn load(&mut self, day: u8, input: String) -> Result<String, String> {
+
+        let loader = get_loader(day).ok_or(format!("day {} has no simulation, try 6, 11, 13, 14, 19, 20, 21, 22 or 25", day))?;
+        self.loaded = Some(Loaded { day: day, simulation: loader(&input)?, input: input, steps: 0 });
+        return Ok(format!("Day {:0>2} loaded", day));
+    }
+
+    fn loaded(&mut self) -> Result<&mut Loaded, String> { self.loaded.as_mut().ok_or("no day loaded, use 'day <N>' first".to_owned()) }
+
+    fn step(&mut self, steps: usize) -> Result<String, String> {
+
+        let loaded = self.loaded()?;
+        for _ in 0..steps {
+            if loaded.simulation.is_over() { break }
+            loaded.simulation.step()?;
+            loaded.steps = loaded.steps + 1;
+        }
+
+        let over = if loaded.simulation.is_over() { ", the simulation is over" } else { "" };
+        return Ok(format!("Step {}{}", loaded.steps, over));
+    }
+
+    fn get(&mut self, name: Option<&str>) -> Result<String, String> {
+
+        let values = self.loaded()?.simulation.values();
+        return match name {
+            None => Ok(values.iter().map(|(name, value)| format!("{} = {}", name, value)).collect::<Vec<String>>().join("\n")),
+            Some(name) => values.into_iter()
+                .find(|(value_name, _)| *value_name == name)
+                .map(|(_, value)| value)
+                .ok_or(format!("there is no value named '{}'", name)),
+        };
+    }
+
+    /// Runs a single command, `None` when the session should end.
+    fn execute(&mut self, line: &str) -> Option<Result<String, String>> {
+
+        let words : Vec<&str> = line.split_whitespace().collect();
+        let result = match words.as_slice() {
+            [] => Ok(String::new()),
+            ["quit"] | ["exit"] => return None,
+            ["help"] => Ok(HELP.to_owned()),
+            ["day", day] | ["day", day, _] => {
+                let name = words.get(2).copied().unwrap_or(REAL_INPUT);
+                day.parse::<u8>().map_err(|_| format!("'{}' is not a day", day))
+                    .and_then(|day| self.inputs.read(day, name).map(|input| (day, input)))
+                    .and_then(|(day, input)| self.load(day, input))
+            },
+            ["step"] => self.step(1),
+            ["step", count] => parse_count(Some(count)).and_then(|count| self.step(count)),
+            ["run"] => self.step(usize::MAX),
+            ["show"] => self.loaded().map(|loaded| loaded.simulation.show()),
+            ["get"] => self.get(None),
+            ["get", name] => self.get(Some(name)),
+            ["reset"] => self.loaded().map(|loaded| (loaded.day, loaded.input.clone()))
+                .and_then(|(day, input)| self.load(day, input)),
+            _ => Err(format!("unknown command '{}', try 'help'", line.trim())),
+        };
+
+        return Some(result);
+    }
+
+    fn write_prompt(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+
+        if !self.prompt { return Ok(()) }
+        match self.loaded.as_ref() {
+            Some(loaded) => write!(writer, "day {:0>2} @ {}> ", loaded.day, loaded.steps)?,
+            None => write!(writer, "aoc> ")?,
+        }
+        return writer.flush();
+    }
+
+    /// Answers every command of `reader` until it ends or asks to quit.
+    pub fn run<R: BufRead>(&mut self, reader: R, writer: &mut dyn Write) -> std::io::Result<()> {
+
+        self.write_prompt(writer)?;
+        for line in reader.lines() {
+
+            match self.execute(&line?) {
+                None => return Ok(()),
+                Some(Ok(output)) if output.is_empty() => {},
+                Some(Ok(output)) => writeln!(writer, "{}", output)?,
+                Some(Err(error)) => writeln!(writer, "🚨  {}", error)?,
+            }
+            self.write_prompt(writer)?;
+        }
+
+        return Ok(());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::inputs;
+
+    fn run_script(script: &str) -> String {
+        let inputs = InputDirectory::load(&inputs::default_directory()).unwrap();
+        let mut output : Vec<u8> = Vec::new();
+        Session::new(&inputs).run(script.as_bytes(), &mut output).unwrap();
+        return String::from_utf8(output).unwrap();
+    }
+
+    #[test]
+    fn octopi_step_until_they_synchronize() {
+        let output = run_script("day 11 example\nstep 10\nget flashes\nrun\nget steps\n");
+        assert_eq!(output, "Day 11 loaded\nStep 10\n204\nStep 195, the simulation is over\n195\n");
+    }
+
+    #[test]
+    fn origami_shows_the_sheet() {
+        let output = run_script("day 13 example\nstep\nget dots\nrun\nshow\nreset\nget folds\nquit\nget dots\n");
+        assert!(output.starts_with("Day 13 loaded\nStep 1\n17\nStep 2, the simulation is over\n"));
+        assert!(output.ends_with("Day 13 loaded\n2\n"));
+    }
+
+    #[test]
+    fn every_simulation_runs_on_its_example() {
+        for day in [6, 13, 14, 20, 21, 22, 25] {
+            let output = run_script(&format!("day {} example\nrun\n", day));
+            assert!(output.ends_with("the simulation is over\n"), "day {}: {}", day, output);
+        }
+    }
+
+    #[test]
+    fn mistakes_are_reported() {
+        let output = run_script("step\nday 1\nday 6 missing\nday 6 example\nstep many\nget nothing\nfly\n");
+        let errors : Vec<&str> = output.lines().filter(|line| line.starts_with("🚨")).collect();
+        assert_eq!(errors.len(), 6, "{}", output);
+    }
+}
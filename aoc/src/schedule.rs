@@ -0,0 +1,97 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+use aoc_common::AocError;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    return match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map(|message| message.to_string()).unwrap_or_else(|| "no message".to_owned()),
+    };
+}
+
+/// Runs `work` on every task with up to `jobs` threads, `on_result` gets the results in the order of the tasks
+/// as soon as every task before them is done, whatever order they finished in.
+///
+/// A task that panics gets an `AocError::Panicked` as its result, the others carry on.
+pub fn run_in_order<T, R, W, F>(tasks: &[T], jobs: usize, work: W, mut on_result: F)
+    where T: Sync, R: Send, W: Fn(&T) -> R + Sync, F: FnMut(&T, Result<R, AocError>) {
+
+    let next_task : AtomicUsize = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, Result<R, AocError>)>();
+    std::thread::scope(|scope| {
+
+        // Workers pick the next task when they are free, so a slow day does not hold back a whole share of the others
+        for _ in 0..std::cmp::min(std::cmp::max(jobs, 1), tasks.len()) {
+            let sender = sender.clone();
+            let (next_task, work) = (&next_task, &work);
+            scope.spawn(move || loop {
+                let index = next_task.fetch_add(1, Ordering::Relaxed);
+                if index >= tasks.len() { break }
+                let result = panic::catch_unwind(AssertUnwindSafe(|| work(&tasks[index])))
+                    .map_err(|payload| AocError::panicked(&panic_message(payload)));
+                if sender.send((index, result)).is_err() { break }
+            });
+        }
+        drop(sender);
+
+        let mut finished : Vec<Option<Result<R, AocError>>> = tasks.iter().map(|_| None).collect();
+        let mut next_result : usize = 0;
+        for (index, result) in receiver {
+            finished[index] = Some(result);
+            while next_result < tasks.len() && finished[next_result].is_some() {
+                on_result(&tasks[next_result], finished[next_result].take().unwrap());
+                next_result = next_result + 1;
+            }
+        }
+    });
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::time::Duration;
+
+    #[test]
+    fn results_keep_the_order_of_the_tasks() {
+        // The first tasks take the longest, so they finish last
+        let tasks : Vec<u64> = (0..8).collect();
+        let mut results : Vec<u64> = Vec::new();
+        run_in_order(&tasks, 4, |task| {
+            std::thread::sleep(Duration::from_millis(40 - task * 5));
+            task * 10
+        }, |_, result| results.push(result.unwrap()));
+
+        assert_eq!(results, vec!(0, 10, 20, 30, 40, 50, 60, 70));
+    }
+
+    #[test]
+    fn every_task_runs_once_whatever_the_jobs() {
+        let tasks : Vec<u64> = (1..=5).collect();
+        for jobs in [0, 1, 2, 16] {
+            let mut results : Vec<(u64, u64)> = Vec::new();
+            run_in_order(&tasks, jobs, |task| task * task, |&task, result| results.push((task, result.unwrap())));
+            assert_eq!(results, vec!((1, 1), (2, 4), (3, 9), (4, 16), (5, 25)), "{} jobs", jobs);
+        }
+    }
+
+    #[test]
+    fn a_panicking_task_does_not_lose_the_others() {
+        let tasks : Vec<u64> = (1..=4).collect();
+        for jobs in [1, 3] {
+            let mut results : Vec<String> = Vec::new();
+            run_in_order(&tasks, jobs, |&task| if task == 2 { panic!("task {} broke", task) } else { task }, |_, result| {
+                results.push(match result { Ok(value) => value.to_string(), Err(error) => error.to_string() });
+            });
+            assert_eq!(results, vec!("1", "panicked, task 2 broke", "3", "4"), "{} jobs", jobs);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use aoc_common::{Grid, Solution};
+use aoc_visualization::{Animation, AnimationFormat, FrameSink, FrameWriter, Palette};
+
+// ================================================== STRUCTS ==================================================
+
+/// Palettes that can replace the one every day is drawn with.
+#[derive(ValueEnum, Copy, Clone)]
+pub enum PaletteName { Sunset, Ember, Grayscale }
+
+type Visualizer = fn(&str, &mut dyn FrameSink) -> Result<(), String>;
+
+// Rows used to draw the tallest bar of the lanternfish chart
+const BAR_HEIGHT : usize = 32;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn describe<E: ToString>(error: E) -> String { error.to_string() }
+
+fn fish_bars(fishes_by_life: &Vec<usize>) -> Grid<f64> {
+
+    let most_fishes : f64 = std::cmp::max(*fishes_by_life.iter().max().unwrap_or(&0), 1) as f64;
+    let oldest_life : f64 = fishes_by_life.len() as f64 - 1.0;
+    return Grid::from_fn(fishes_by_life.len(), BAR_HEIGHT, |(life, row)| {
+
+        let bar_height : f64 = fishes_by_life[life] as f64 / most_fishes * BAR_HEIGHT as f64;
+        if ((BAR_HEIGHT - row) as f64) > bar_height.ceil() { return 0.0 }
+        return 0.3 + 0.7 * life as f64 / oldest_life;
+    });
+}
+
+fn visualize_lanternfish(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let lifes = day_06::Day06::parse(input).map_err(describe)?;
+    let mut sea_floor = day_06::ExponentialSeaFloor::new(lifes).map_err(describe)?;
+    loop {
+        sink.add_frame(&fish_bars(&sea_floor.get_fishes_by_life())).map_err(describe)?;
+        if sea_floor.get_days_done() == 256 { return Ok(()) }
+        sea_floor.do_iteration();
+    }
+}
+
+fn visualize_octopi(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let energy_levels = day_11::Day11::parse(input).map_err(describe)?;
+    let mut map = day_11::Map::new(energy_levels).map_err(describe)?;
+    // Octopi that just flashed sit at zero and are drawn the brightest
+    let intensity = |&energy_level: &u32| if energy_level == 0 { 1.0 } else { energy_level as f64 / 18.0 };
+
+    sink.add_frame(&map.get_energy_levels().map(intensity)).map_err(describe)?;
+    loop {
+        let all_flashed : bool = map.do_synchronizing_iteration().map_err(describe)?;
+        sink.add_frame(&map.get_energy_levels().map(intensity)).map_err(describe)?;
+        if all_flashed { return Ok(()) }
+    }
+}
+
+fn visualize_origami(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let (points, folds) = day_13::Day13::parse(input).map_err(describe)?;
+    let mut map = day_13::Map::new(points, folds).map_err(describe)?;
+    let intensity = |&point: &char| match point {
+        '#' => 1.0,
+        '-' => 0.5,
+        '.' => 0.15,
+        _ => 0.0,
+    };
+
+    sink.add_frame(&map.get_sheet().map(intensity)).map_err(describe)?;
+    while map.number_of_folds() != 0 {
+        map.make_next_fold();
+        sink.add_frame(&map.get_sheet().map(intensity)).map_err(describe)?;
+    }
+
+    return Ok(());
+}
+
+fn visualize_trench_map(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let (codification, image) = day_20::Day20::parse(input).map_err(describe)?;
+    let mut tool = day_20::EnhancingTool::new(codification, image).map_err(describe)?;
+    loop {
+        sink.add_frame(&tool.get_image().map(|&slot| if slot == '#' { 1.0 } else { 0.0 })).map_err(describe)?;
+        if tool.get_current_iteration() == 50 { return Ok(()) }
+        tool.do_iteration();
+    }
+}
+
+fn visualize_reactor(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let rules = day_22::Day22::parse(input).map_err(describe)?;
+    let mut map = day_22::LimitedMap::new(rules, vec!(('x', -50, 50), ('y', -50, 50), ('z', -50, 50))).map_err(describe)?;
+    // Most rules fall outside the limits, only draw the ones changing what is seen from above
+    let mut last_view : Option<Grid<usize>> = None;
+    while !map.completed_rules() {
+
+        map.do_iteration();
+        let top_view : Grid<usize> = map.get_top_view().unwrap();
+        if last_view.as_ref() == Some(&top_view) { continue }
+
+        sink.add_frame(&top_view.map(|&cubes_on| cubes_on as f64 / 101.0)).map_err(describe)?;
+        last_view = Some(top_view);
+    }
+
+    return Ok(());
+}
+
+fn visualize_sea_cucumbers(input: &str, sink: &mut dyn FrameSink) -> Result<(), String> {
+
+    let info = day_25::Day25::parse(input).map_err(describe)?;
+    let mut map = day_25::Map::new(info).map_err(describe)?;
+    let intensity = |&cell: &char| match cell {
+        '>' => 0.5,
+        'v' => 1.0,
+        _ => 0.0,
+    };
+
+    sink.add_frame(&map.get_cells().map(intensity)).map_err(describe)?;
+    while map.iterate() { sink.add_frame(&map.get_cells().map(intensity)).map_err(describe)?; }
+    return Ok(());
+}
+
+/// Simulation days with frames to dump, along with the palette they are drawn with.
+fn get_visualizer(day: u8) -> Option<(Visualizer, Palette)> {
+    return match day {
+        6 => Some((visualize_lanternfish, Palette::sunset())),
+        11 => Some((visualize_octopi, Palette::ember())),
+        13 => Some((visualize_origami, Palette::ember())),
+        20 => Some((visualize_trench_map, Palette::grayscale())),
+        22 => Some((visualize_reactor, Palette::ember())),
+        25 => Some((visualize_sea_cucumbers, Palette::ember())),
+        _ => None,
+    };
+}
+
+fn choose_palette(day_palette: Palette, palette: Option<PaletteName>) -> Palette {
+    return match palette {
+        Some(PaletteName::Sunset) => Palette::sunset(),
+        Some(PaletteName::Ember) => Palette::ember(),
+        Some(PaletteName::Grayscale) => Palette::grayscale(),
+        None => day_palette,
+    };
+}
+
+pub fn has_visualization(day: u8) -> bool { get_visualizer(day).is_some() }
+
+/// Writes the frames of the day into `output_dir/day_NN`, the writer tells how many there were.
+pub fn visualize(day: u8, input: &str, output_dir: &Path, cell_size: u32, palette: Option<PaletteName>) -> Result<FrameWriter, String> {
+
+    let (visualizer, day_palette) = get_visualizer(day).ok_or(format!("day {} has no visualization", day))?;
+    let mut writer = FrameWriter::new(output_dir.join(format!("day_{:0>2}", day)))
+        .with_cell_size(cell_size)
+        .with_palette(choose_palette(day_palette, palette));
+
+    visualizer(input, &mut writer)?;
+    return Ok(writer);
+}
+
+/// Encodes every frame of the day into a single GIF or APNG, picked from the extension of `path`.
+pub fn animate(day: u8, input: &str, path: &Path, cell_size: u32, palette: Option<PaletteName>, frame_delay: Duration) -> Result<Animation, String> {
+
+    let (visualizer, day_palette) = get_visualizer(day).ok_or(format!("day {} has no visualization", day))?;
+    let format = AnimationFormat::from_path(path).ok_or(format!("'{}' is neither a .gif nor a .png/.apng file", path.display()))?;
+    let mut animation = Animation::new()
+        .with_cell_size(cell_size)
+        .with_palette(choose_palette(day_palette, palette))
+        .with_frame_delay(frame_delay);
+
+    visualizer(input, &mut animation)?;
+    animation.save(path, format).map_err(describe)?;
+    return Ok(animation);
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use aoc_input::{InputError, InputErrorKind};
+
+// ================================================== STRUCTS ==================================================
+
+/// Why a day could not produce an answer.
+///
+/// `Input` means the puzzle input itself is wrong, `Unsolvable` means it is well formed but
+/// the puzzle has no answer for it (no path to the exit, no valid model number, ...) and
+/// `GaveUp` means the search ran out of budget before finding out, with the answers it had found until then
+/// in `partial_answers`. `Panicked` is a bug of the solver itself, caught so the other days still get solved.
+#[derive(Debug)]
+pub enum AocError {
+    Input(InputError),
+    Unsolvable(String),
+    GaveUp { message: String, partial_answers: Vec<i64> },
+    Panicked(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl AocError {
+
+    pub fn malformed(message: &str) -> AocError {
+        return AocError::Input(InputError::new(InputErrorKind::Malformed(message.to_owned())));
+    }
+
+    pub fn unsolvable(message: &str) -> AocError {
+        return AocError::Unsolvable(message.to_owned());
+    }
+
+    pub fn gave_up(message: &str) -> AocError {
+        return AocError::GaveUp { message: message.to_owned(), partial_answers: Vec::new() };
+    }
+
+    pub fn panicked(message: &str) -> AocError {
+        return AocError::Panicked(message.to_owned());
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(error: InputError) -> AocError { AocError::Input(error) }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AocError::Input(error) => write!(f, "{}", error),
+            AocError::Unsolvable(message) => write!(f, "no solution, {}", message),
+            AocError::GaveUp { message, .. } => write!(f, "gave up, {}", message),
+            AocError::Panicked(message) => write!(f, "panicked, {}", message),
+        };
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            AocError::Input(error) => Some(error),
+            AocError::Unsolvable(_) | AocError::GaveUp { .. } | AocError::Panicked(_) => None,
+        };
+    }
+}
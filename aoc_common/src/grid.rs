@@ -0,0 +1,291 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_input::InputError;
+
+// ================================================== STRUCTS ==================================================
+
+/// Cell coordinates as `(x, y)`, that is `(column, row)`, with `(0, 0)` at the top left.
+pub type Position = (usize, usize);
+
+/// Which cells count as neighbours: the four orthogonal ones or all eight around.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Neighbourhood { Four, Eight }
+
+/// What happens when stepping over an edge: nothing is there, or the opposite edge is reached.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Edges { Bounded, Wrapping }
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width:          usize,
+    height:         usize,
+    cells:          Vec<T>,
+    neighbourhood:  Neighbourhood,
+    edges:          Edges,
+}
+
+const FOUR_DELTAS : [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const EIGHT_DELTAS : [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn wrap_coordinate(coordinate: isize, size: usize) -> usize {
+    return coordinate.rem_euclid(size as isize) as usize;
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl<T> Grid<T> {
+
+    /// Every cell set to `value`, panics on an empty grid like `from_fn`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        return Grid::from_fn(width, height, |_| value.clone());
+    }
+
+    /// Every cell computed from its position. Grids are never empty, as with `from_rows`, so
+    /// a width or height of zero panics: callers sizing grids from the input have to check it first.
+    pub fn from_fn<F: FnMut(Position) -> T>(width: usize, height: usize, cell: F) -> Grid<T> {
+
+        assert!(width > 0 && height > 0, "🚨  Grids cannot be empty, got {}x{}", width, height);
+        let cells : Vec<T> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(cell)
+            .collect();
+
+        Grid {
+            width: width,
+            height: height,
+            cells: cells,
+            neighbourhood: Neighbourhood::Four,
+            edges: Edges::Bounded,
+        }
+    }
+
+    /// Builds the grid from the rows returned by `read_digits`/`read_chars`, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, InputError> {
+
+        let width : usize = rows.first().map(|row| row.len()).unwrap_or(0);
+        if width == 0 { return Err(InputError::malformed("expected a non empty grid", 1)) }
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            return Err(InputError::malformed("expected rows of the same length", index + 1));
+        }
+
+        let height : usize = rows.len();
+        Ok(Grid {
+            width: width,
+            height: height,
+            cells: rows.into_iter().flatten().collect(),
+            neighbourhood: Neighbourhood::Four,
+            edges: Edges::Bounded,
+        })
+    }
+
+    pub fn with_neighbourhood(mut self, neighbourhood: Neighbourhood) -> Grid<T> {
+        self.neighbourhood = neighbourhood;
+        return self;
+    }
+
+    pub fn with_edges(mut self, edges: Edges) -> Grid<T> {
+        self.edges = edges;
+        return self;
+    }
+
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+    pub fn contains(&self, position: Position) -> bool { position.0 < self.width && position.1 < self.height }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if !self.contains(position) { return None }
+        return self.cells.get(position.1 * self.width + position.0);
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) { return None }
+        return self.cells.get_mut(position.1 * self.width + position.0);
+    }
+
+    /// Moves from `position` by `delta`, which may start outside the grid. Bounded grids give
+    /// `None` past the edges while wrapping ones come back from the other side.
+    pub fn offset(&self, position: Position, delta: (isize, isize)) -> Option<Position> {
+
+        let x : isize = position.0 as isize + delta.0;
+        let y : isize = position.1 as isize + delta.1;
+        return match self.edges {
+            Edges::Wrapping => Some((wrap_coordinate(x, self.width), wrap_coordinate(y, self.height))),
+            Edges::Bounded if x < 0 || y < 0 => None,
+            Edges::Bounded => Some((x as usize, y as usize)).filter(|&position| self.contains(position)),
+        };
+    }
+
+    /// Every neighbour once, on wrapping grids with a side of two or less both ways around can reach the same cell.
+    pub fn neighbours(&self, position: Position) -> Vec<Position> {
+
+        let deltas : &[(isize, isize)] = match self.neighbourhood {
+            Neighbourhood::Four => &FOUR_DELTAS,
+            Neighbourhood::Eight => &EIGHT_DELTAS,
+        };
+
+        let mut neighbours : Vec<Position> = deltas.iter()
+            .filter_map(|&delta| self.offset(position, delta))
+            .collect();
+        if self.edges == Edges::Wrapping {
+            let mut seen : Vec<Position> = Vec::with_capacity(neighbours.len());
+            neighbours.retain(|&neighbour| {
+                if neighbour == position || seen.contains(&neighbour) { return false }
+                seen.push(neighbour);
+                return true;
+            });
+        }
+
+        return neighbours;
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        return self.positions().zip(self.cells.iter_mut());
+    }
+
+    pub fn row(&self, y: usize) -> &[T] { &self.cells[(y * self.width)..((y + 1) * self.width)] }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> { self.cells.chunks(self.width) }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(move |x| self.column(x));
+    }
+
+    /// Builds a grid of the same shape and modes from the value of every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, cell: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+            neighbourhood: self.neighbourhood,
+            edges: self.edges,
+        }
+    }
+
+    /// One line per row with a characther per cell.
+    pub fn render<F: Fn(&T) -> char>(&self, symbol: F) -> String {
+
+        let mut rendered : String = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&symbol));
+            rendered.push('\n');
+        }
+
+        return rendered;
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        return self.get(position).unwrap_or_else(|| panic!("🚨  Position {:?} is outside the grid", position));
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        return self.get_mut(position).unwrap_or_else(|| panic!("🚨  Position {:?} is outside the grid", position));
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        for row in self.rows() {
+            for cell in row { write!(f, "{}", cell)? }
+            writeln!(f)?;
+        }
+
+        return Ok(());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn example_grid() -> Grid<u32> { Grid::from_rows(vec!(vec!(1, 2, 3), vec!(4, 5, 6))).unwrap() }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let error = Grid::from_rows(vec!(vec!(1, 2), vec!(3))).unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert!(Grid::<u32>::from_rows(Vec::new()).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Grids cannot be empty")]
+    fn grids_without_columns_are_refused() {
+        Grid::filled(0, 3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grids cannot be empty")]
+    fn grids_without_rows_are_refused() {
+        Grid::from_fn(3, 0, |(x, _)| x);
+    }
+
+    #[test]
+    fn cells_are_stored_row_by_row() {
+        let grid = example_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec!(2, 5));
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().last(), Some(((2, 1), &6)));
+    }
+
+    #[test]
+    fn bounded_neighbours() {
+        let grid = example_grid();
+        assert_eq!(grid.neighbours((0, 0)), vec!((1, 0), (0, 1)));
+        assert_eq!(grid.clone().with_neighbourhood(Neighbourhood::Eight).neighbours((0, 0)), vec!((1, 0), (0, 1), (1, 1)));
+        assert_eq!(grid.neighbours((1, 1)).len(), 3);
+    }
+
+    #[test]
+    fn wrapping_neighbours() {
+        let grid = example_grid().with_edges(Edges::Wrapping);
+        assert_eq!(grid.neighbours((0, 0)), vec!((0, 1), (2, 0), (1, 0)));
+        let column = Grid::filled(1, 2, 0).with_edges(Edges::Wrapping).with_neighbourhood(Neighbourhood::Eight);
+        assert_eq!(column.neighbours((0, 0)), vec!((0, 1)));
+        assert_eq!(grid.offset((2, 1), (1, 1)), Some((0, 0)));
+    }
+
+    #[test]
+    fn offsets_may_start_outside() {
+        let grid = example_grid();
+        assert_eq!(grid.offset((4, 1), (-2, -1)), Some((2, 0)));
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn rendering() {
+        let grid = example_grid();
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.map(|&value| value % 2 == 0).render(|&even| if even { '#' } else { '.' }), ".#.\n#.#\n");
+    }
+}
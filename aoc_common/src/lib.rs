@@ -0,0 +1,12 @@
+mod error;
+pub mod grid;
+mod progress;
+mod solution;
+mod supervisor;
+
+pub use aoc_input::{InputError, InputErrorKind};
+pub use error::{AocError, AocResult};
+pub use grid::Grid;
+pub use progress::{NoProgress, Progress, ProgressObserver};
+pub use solution::Solution;
+pub use supervisor::{Budget, Supervisor};
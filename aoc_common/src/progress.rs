@@ -0,0 +1,38 @@
+// ================================================== STRUCTS ==================================================
+
+/// How far a long running solver has gone, searches with no known end leave `total` empty.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Progress {
+    pub stage:  &'static str,
+    pub done:   usize,
+    pub total:  Option<usize>,
+}
+
+/// Receives the progress of a solver, any `FnMut(&Progress)` closure is an observer.
+pub trait ProgressObserver {
+    fn on_progress(&mut self, progress: &Progress);
+}
+
+/// The observer solvers start with, it ignores every update.
+pub struct NoProgress;
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Progress {
+
+    pub fn new(stage: &'static str, done: usize, total: Option<usize>) -> Progress {
+        Progress {
+            stage: stage,
+            done: done,
+            total: total,
+        }
+    }
+}
+
+impl<F: FnMut(&Progress)> ProgressObserver for F {
+    fn on_progress(&mut self, progress: &Progress) { self(progress) }
+}
+
+impl ProgressObserver for NoProgress {
+    fn on_progress(&mut self, _progress: &Progress) {}
+}
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use aoc_input::InputError;
+
+use crate::AocResult;
+use crate::supervisor::Supervisor;
+
+// ================================================== STRUCTS ==================================================
+
+/// A day of the calendar: how its puzzle input is read and how both parts are answered.
+///
+/// The parsed input is handed to each part by reference, so a part that needs to mutate
+/// state builds its own working structures from it. Parts fail instead of panicking when
+/// the input turns out to have no answer.
+///
+/// Days that take a while also answer to a supervisor: they report how far along they are
+/// and give up once its budget is spent. The others just ignore it. Nothing is ever printed,
+/// the caller decides how progress is shown.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError>;
+    fn part1(input: &Self::Input) -> AocResult<Self::Answer1>;
+    fn part2(input: &Self::Input) -> AocResult<Self::Answer2>;
+
+    fn part1_supervised(input: &Self::Input, _supervisor: Supervisor) -> AocResult<Self::Answer1> { Self::part1(input) }
+    fn part2_supervised(input: &Self::Input, _supervisor: Supervisor) -> AocResult<Self::Answer2> { Self::part2(input) }
+}
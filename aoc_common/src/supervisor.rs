@@ -0,0 +1,129 @@
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+use crate::progress::{NoProgress, Progress, ProgressObserver};
+
+// ================================================== STRUCTS ==================================================
+
+/// How long a search may go on: until a deadline, for a number of steps, or both.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Budget {
+    deadline:   Option<Instant>,
+    max_steps:  Option<usize>,
+}
+
+/// Watches over a long running solver: it hears about its progress and tells it when to give up.
+///
+/// Searches count their steps through `exhausted` and stop cooperatively, returning
+/// whatever they found so far through `give_up`.
+pub struct Supervisor {
+    observer:   Box<dyn ProgressObserver>,
+    budget:     Budget,
+    steps:      usize,
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Budget {
+
+    pub fn unlimited() -> Budget {
+        Budget {
+            deadline: None,
+            max_steps: None,
+        }
+    }
+
+    pub fn with_deadline(mut self, deadline: Instant) -> Budget {
+        self.deadline = Some(deadline);
+        return self;
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Budget { self.with_deadline(Instant::now() + timeout) }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Budget {
+        self.max_steps = Some(max_steps);
+        return self;
+    }
+
+    /// Why the budget is spent after `steps` steps, if it is.
+    fn spent(&self, steps: usize) -> Option<&'static str> {
+
+        if self.max_steps.is_some_and(|max_steps| steps > max_steps) { return Some("out of steps") }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) { return Some("out of time") }
+        return None;
+    }
+}
+
+impl Supervisor {
+
+    pub fn new() -> Supervisor {
+        Supervisor {
+            observer: Box::new(NoProgress),
+            budget: Budget::unlimited(),
+            steps: 0,
+        }
+    }
+
+    pub fn with_observer(mut self, observer: Box<dyn ProgressObserver>) -> Supervisor {
+        self.observer = observer;
+        return self;
+    }
+
+    pub fn with_budget(mut self, budget: Budget) -> Supervisor {
+        self.budget = budget;
+        return self;
+    }
+
+    pub fn get_steps(&self) -> usize { self.steps }
+    pub fn report(&mut self, progress: &Progress) { self.observer.on_progress(progress) }
+
+    /// Counts one more step of the search, true once the budget is spent.
+    pub fn exhausted(&mut self) -> bool {
+        self.steps = self.steps + 1;
+        return self.budget.spent(self.steps).is_some();
+    }
+
+    /// The error of a search that ran out of budget, `partial` tells what it had found until then.
+    pub fn give_up(&self, partial: &str) -> AocError { self.give_up_with_answers(partial, Vec::new()) }
+
+    /// Like `give_up`, keeping the answers found so far for the callers that can make do with them.
+    pub fn give_up_with_answers(&self, partial: &str, partial_answers: Vec<i64>) -> AocError {
+        let reason : &str = self.budget.spent(self.steps).unwrap_or("stopped");
+        return AocError::GaveUp {
+            message:            format!("{} after {} steps, {}", reason, self.steps, partial),
+            partial_answers:    partial_answers,
+        };
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn unlimited_supervisors_never_give_up() {
+        let mut supervisor = Supervisor::new();
+        assert!((0..1000).all(|_| !supervisor.exhausted()));
+        assert_eq!(supervisor.get_steps(), 1000);
+    }
+
+    #[test]
+    fn step_budgets_allow_that_many_steps() {
+        let mut supervisor = Supervisor::new().with_budget(Budget::unlimited().with_max_steps(2));
+        assert!(!supervisor.exhausted());
+        assert!(!supervisor.exhausted());
+        assert!(supervisor.exhausted());
+        assert_eq!(supervisor.give_up("nothing found").to_string(), "gave up, out of steps after 3 steps, nothing found");
+    }
+
+    #[test]
+    fn past_deadlines_are_spent() {
+        let mut supervisor = Supervisor::new().with_budget(Budget::unlimited().with_timeout(Duration::ZERO));
+        assert!(supervisor.exhausted());
+        assert!(matches!(supervisor.give_up(""), AocError::GaveUp { .. }));
+        assert!(matches!(supervisor.give_up_with_answers("", vec!(3)), AocError::GaveUp { partial_answers, .. } if partial_answers == vec!(3)));
+    }
+}
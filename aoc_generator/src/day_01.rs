@@ -0,0 +1,16 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` sonar depths, a random walk that mostly goes deeper like the real sea floor.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut depth : i64 = rng.gen_range(100..200);
+    return join_lines((0..size).map(|_| {
+        depth = std::cmp::max(depth + rng.gen_range(-8..=12), 0);
+        depth.to_string()
+    }));
+}
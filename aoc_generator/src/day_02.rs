@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` submarine commands, forward moves being as common as both vertical ones together.
+///
+/// The submarine never goes up past the surface.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut depth : i64 = 0;
+    return join_lines((0..size).map(|_| {
+        let value : i64 = rng.gen_range(1..=9);
+        let movement : &str = match rng.gen_range(0..4) {
+            0 | 1 => "forward",
+            2 if depth >= value => "up",
+            _ => "down",
+        };
+
+        if movement == "up" { depth = depth - value } else if movement == "down" { depth = depth + value }
+        format!("{} {}", movement, value)
+    }));
+}
@@ -0,0 +1,26 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+const NUMBER_OF_BITS : usize = 12;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` different diagnostic numbers, repeated ones leave the ratings without an answer.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let size : usize = std::cmp::min(size, 1 << NUMBER_OF_BITS);
+    let mut numbers : Vec<u32> = Vec::new();
+    let mut seen : HashSet<u32> = HashSet::new();
+    while numbers.len() < size {
+        let number : u32 = rng.gen_range(0..(1 << NUMBER_OF_BITS));
+        if seen.insert(number) { numbers.push(number) }
+    }
+
+    return join_lines(numbers.into_iter().map(|number| format!("{:0>width$b}", number, width = NUMBER_OF_BITS)));
+}
@@ -0,0 +1,29 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+// ================================================== STRUCTS ==================================================
+
+const CARD_SIDE : usize = 5;
+const HIGHEST_NUMBER : u32 = 99;
+
+// ================================================== GENERATOR ==================================================
+
+/// A bingo game with `size` cards, every number gets called so every card ends up winning.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut numbers : Vec<u32> = (0..=HIGHEST_NUMBER).collect();
+    numbers.shuffle(rng);
+    let mut input : String = numbers.iter().map(|number| number.to_string()).collect::<Vec<String>>().join(",") + "\n";
+
+    for _ in 0..size {
+
+        let card_numbers : Vec<u32> = numbers.choose_multiple(rng, CARD_SIDE * CARD_SIDE).cloned().collect();
+        input.push('\n');
+        for row in card_numbers.chunks(CARD_SIDE) {
+            input.push_str(&row.iter().map(|number| format!("{: >2}", number)).collect::<Vec<String>>().join(" "));
+            input.push('\n');
+        }
+    }
+
+    return input;
+}
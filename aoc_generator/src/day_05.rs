@@ -0,0 +1,32 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+const FLOOR_SIDE : i64 = 1000;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` vent lines, horizontal, vertical or at exactly 45 degrees.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    return join_lines((0..size).map(|_| {
+
+        let (x1, y1) = (rng.gen_range(0..FLOOR_SIDE), rng.gen_range(0..FLOOR_SIDE));
+        let (x2, y2) = match rng.gen_range(0..3) {
+            0 => (rng.gen_range(0..FLOOR_SIDE), y1),
+            1 => (x1, rng.gen_range(0..FLOOR_SIDE)),
+            _ => {
+                // Diagonals run until one of the coordinates hits the border
+                let (step_x, step_y) : (i64, i64) = (if rng.gen() { 1 } else { -1 }, if rng.gen() { 1 } else { -1 });
+                let room_x : i64 = if step_x == 1 { FLOOR_SIDE - 1 - x1 } else { x1 };
+                let room_y : i64 = if step_y == 1 { FLOOR_SIDE - 1 - y1 } else { y1 };
+                let length : i64 = rng.gen_range(0..=std::cmp::min(room_x, room_y));
+                (x1 + step_x * length, y1 + step_y * length)
+            },
+        };
+
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    }));
+}
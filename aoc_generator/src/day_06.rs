@@ -0,0 +1,10 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` lanternfish, each somewhere in the middle of its cycle like in the official inputs.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let lives : Vec<String> = (0..size).map(|_| rng.gen_range(1..=5).to_string()).collect();
+    return lives.join(",") + "\n";
+}
@@ -0,0 +1,10 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` crab positions, spread over twice as many positions as there are crabs.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let positions : Vec<String> = (0..size).map(|_| rng.gen_range(0..=2 * size).to_string()).collect();
+    return positions.join(",") + "\n";
+}
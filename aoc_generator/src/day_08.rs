@@ -0,0 +1,41 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Segments lit by every digit on a correctly wired display
+const DIGIT_SEGMENTS : [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+const NUMBER_OF_OUTPUTS : usize = 4;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn scramble(rng: &mut StdRng, segments: &str, wiring: &Vec<char>) -> String {
+    let mut wires : Vec<char> = segments.chars().map(|segment| wiring[(segment as u8 - b'a') as usize]).collect();
+    wires.shuffle(rng);
+    return wires.into_iter().collect();
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` displays, each wired at random and showing four digits.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    return join_lines((0..size).map(|_| {
+
+        let mut wiring : Vec<char> = "abcdefg".chars().collect();
+        wiring.shuffle(rng);
+
+        let mut patterns : Vec<String> = DIGIT_SEGMENTS.iter().map(|segments| scramble(rng, segments, &wiring)).collect();
+        patterns.shuffle(rng);
+        let outputs : Vec<String> = (0..NUMBER_OF_OUTPUTS)
+            .map(|_| {
+                let digit : usize = rng.gen_range(0..DIGIT_SEGMENTS.len());
+                scramble(rng, DIGIT_SEGMENTS[digit], &wiring)
+            })
+            .collect();
+
+        format!("{} | {}", patterns.join(" "), outputs.join(" "))
+    }));
+}
@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Part 2 multiplies the three largest basins, these cells are always low points of one
+const BASIN_CENTERS : [(usize, usize); 3] = [(0, 0), (0, 2), (2, 0)];
+
+// ================================================== GENERATOR ==================================================
+
+/// A `size` x `size` heightmap, with ridges of nines splitting it into basins.
+///
+/// Heightmaps are at least 3 x 3, so there is room for the three basins part 2 needs.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let size = std::cmp::max(size, 3);
+    let ridge_columns : Vec<bool> = (0..size).map(|_| rng.gen_bool(0.15)).collect();
+    let ridge_rows : Vec<bool> = (0..size).map(|_| rng.gen_bool(0.15)).collect();
+    let mut heights : Vec<Vec<u32>> = (0..size).map(|row| (0..size)
+        .map(|column| {
+            // Ridges have gaps, otherwise every basin would be a perfect rectangle
+            let on_ridge : bool = (ridge_rows[row] || ridge_columns[column]) && !rng.gen_bool(0.1);
+            if on_ridge || rng.gen_bool(0.05) { 9 } else { rng.gen_range(0..=8) }
+        })
+        .collect())
+        .collect();
+
+    // A zero is a low point as long as none of its neighbours is another zero
+    for &(row, column) in BASIN_CENTERS.iter() {
+        heights[row][column] = 0;
+        let neighbours = [(row.wrapping_sub(1), column), (row + 1, column), (row, column.wrapping_sub(1)), (row, column + 1)];
+        for (neighbour_row, neighbour_column) in neighbours {
+            if let Some(height) = heights.get_mut(neighbour_row).and_then(|cells| cells.get_mut(neighbour_column)) {
+                if *height == 0 { *height = 1 }
+            }
+        }
+    }
+
+    return join_lines(heights.into_iter()
+        .map(|row| row.into_iter().map(|height| std::char::from_digit(height, 10).unwrap()).collect::<String>()));
+}
@@ -0,0 +1,55 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+const PAIRS : [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+// Completion scores grow five times per bracket, deeper lines would overflow them
+const MAX_OPEN : usize = 20;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Brackets opened and closed at random, `corrupted` lines close one of them with the wrong bracket.
+fn generate_line(rng: &mut StdRng, corrupted: bool) -> String {
+
+    let length : usize = rng.gen_range(20..=110);
+    let corruption_index : usize = rng.gen_range(length / 2..length);
+    let mut line : String = String::new();
+    let mut open : Vec<usize> = Vec::new();
+    // Corrupted lines only stop once their wrong bracket is in
+    while corrupted || line.len() < length {
+
+        let must_open : bool = open.len() == 0;
+        if !must_open && corrupted && line.len() >= corruption_index {
+            let expected : usize = open.pop().unwrap();
+            let wrong : usize = (expected + rng.gen_range(1..PAIRS.len())) % PAIRS.len();
+            line.push(PAIRS[wrong].1);
+            return line;
+        }
+
+        if must_open || (open.len() < MAX_OPEN && rng.gen_bool(0.6)) {
+            let pair : usize = rng.gen_range(0..PAIRS.len());
+            open.push(pair);
+            line.push(PAIRS[pair].0);
+        } else { line.push(PAIRS[open.pop().unwrap()].1) }
+    }
+
+    // Incomplete lines need something left to close
+    if open.len() == 0 { line.push(PAIRS[rng.gen_range(0..PAIRS.len())].0) }
+    return line;
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` navigation lines, an odd number of them incomplete so their scores have a middle one.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let size : usize = std::cmp::max(size, 1);
+    let incomplete : usize = (size / 2) | 1;
+    let mut lines : Vec<String> = (0..size).map(|index| generate_line(rng, index >= incomplete)).collect();
+    lines.shuffle(rng);
+    return join_lines(lines);
+}
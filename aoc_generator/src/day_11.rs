@@ -0,0 +1,62 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Plenty of random grids keep flashing out of step forever, those are drawn again
+const MAX_ATTEMPTS : usize = 20;
+const MAX_STEPS : usize = 2000;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Runs one step of the octopi, true when every one of them flashed.
+fn do_step(grid: &mut Vec<Vec<u32>>) -> bool {
+
+    let size : usize = grid.len();
+    let mut to_flash : Vec<(usize, usize)> = Vec::new();
+    for row in 0..size {
+        for column in 0..size {
+            grid[row][column] = grid[row][column] + 1;
+            if grid[row][column] == 10 { to_flash.push((row, column)) }
+        }
+    }
+
+    let mut flashes : usize = 0;
+    while let Some((row, column)) = to_flash.pop() {
+        flashes = flashes + 1;
+        for neighbour_row in row.saturating_sub(1)..std::cmp::min(row + 2, size) {
+            for neighbour_column in column.saturating_sub(1)..std::cmp::min(column + 2, size) {
+                grid[neighbour_row][neighbour_column] = grid[neighbour_row][neighbour_column] + 1;
+                if grid[neighbour_row][neighbour_column] == 10 { to_flash.push((neighbour_row, neighbour_column)) }
+            }
+        }
+    }
+
+    for row in grid.iter_mut() {
+        for energy_level in row.iter_mut() { if *energy_level > 9 { *energy_level = 0 } }
+    }
+
+    return flashes == size * size;
+}
+
+fn synchronizes(grid: &Vec<Vec<u32>>) -> bool {
+    let mut grid : Vec<Vec<u32>> = grid.clone();
+    return (0..MAX_STEPS).any(|_| do_step(&mut grid));
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// A `size` x `size` grid of octopus energy levels that, whenever one is found, ends up flashing all at once.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut grid : Vec<Vec<u32>> = Vec::new();
+    for _ in 0..MAX_ATTEMPTS {
+        grid = (0..size).map(|_| (0..size).map(|_| rng.gen_range(1..=9)).collect()).collect();
+        if synchronizes(&grid) { break }
+    }
+
+    return join_lines(grid.into_iter()
+        .map(|row| row.into_iter().map(|energy_level| std::char::from_digit(energy_level, 10).unwrap()).collect::<String>()));
+}
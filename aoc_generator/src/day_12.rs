@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn is_big(cave: &str) -> bool { cave == cave.to_uppercase() }
+
+fn generate_name(rng: &mut StdRng, big: bool, used: &mut HashSet<String>) -> String {
+    loop {
+        let name : String = (0..2).map(|_| rng.gen_range(b'a'..=b'z') as char).collect();
+        let name : String = if big { name.to_uppercase() } else { name };
+        if name != "start" && name != "end" && used.insert(name.clone()) { return name }
+    }
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// A cave system with `size` caves besides 'start' and 'end', paths grow exponentially with it.
+///
+/// Big caves are never linked together, otherwise paths could bounce between them forever.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut used : HashSet<String> = HashSet::new();
+    let mut caves : Vec<String> = vec!("start".to_owned());
+    for _ in 0..size {
+        let big : bool = rng.gen_bool(0.3);
+        caves.push(generate_name(rng, big, &mut used));
+    }
+    caves.push("end".to_owned());
+
+    // Every cave hangs from an earlier one so all of them are reachable
+    let mut links : Vec<(usize, usize)> = Vec::new();
+    for cave_index in 1..caves.len() {
+        let candidates : Vec<usize> = (0..cave_index).filter(|&other| !is_big(&caves[other]) || !is_big(&caves[cave_index])).collect();
+        // 'start' is small, so there is always somewhere to hang from
+        let parent : usize = *candidates.choose(rng).unwrap();
+        links.push((parent, cave_index));
+    }
+
+    let mut linked : HashSet<(usize, usize)> = links.iter().cloned().collect();
+    for _ in 0..size {
+        let (first, second) = (rng.gen_range(0..caves.len()), rng.gen_range(0..caves.len()));
+        let (first, second) = (std::cmp::min(first, second), std::cmp::max(first, second));
+        if first == second || (is_big(&caves[first]) && is_big(&caves[second])) || !linked.insert((first, second)) { continue }
+        links.push((first, second));
+    }
+
+    links.shuffle(rng);
+    return join_lines(links.into_iter().map(|(first, second)| {
+        if rng.gen() { format!("{}-{}", caves[first], caves[second]) } else { format!("{}-{}", caves[second], caves[first]) }
+    }));
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== STRUCTS ==================================================
+
+// The folded sheet ends up as big as the official eight letter code
+const FINAL_WIDTH : i64 = 40;
+const FINAL_HEIGHT : i64 = 6;
+const FOLDS_PER_AXIS : usize = 5;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Fold lines of one axis from the first to the last, each one halving what the previous left.
+fn axis_folds(final_size: i64) -> Vec<i64> {
+    let mut folds : Vec<i64> = vec!(final_size);
+    while folds.len() < FOLDS_PER_AXIS { folds.push(2 * folds.last().unwrap() + 1) }
+    return folds.into_iter().rev().collect();
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` dots and the folds that stack them into a 40 x 6 code.
+///
+/// Dots are placed on the lit cells of the code and unfolded at random, so none ever lies on a fold line.
+/// Like in the official inputs the first fold of each axis cuts the sheet in half, two dots unfolded
+/// from the top left corner of the code mark its far edges.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let (mut x_folds, mut y_folds) = (axis_folds(FINAL_WIDTH), axis_folds(FINAL_HEIGHT));
+    let mut folds : Vec<(char, i64)> = Vec::new();
+    while x_folds.len() + y_folds.len() != 0 {
+        let take_x : bool = y_folds.len() == 0 || (x_folds.len() != 0 && rng.gen());
+        if take_x { folds.push(('x', x_folds.remove(0))) } else { folds.push(('y', y_folds.remove(0))) }
+    }
+
+    let mut code : Vec<(i64, i64)> = (0..FINAL_WIDTH).flat_map(|x| (0..FINAL_HEIGHT).map(move |y| (x, y))).collect();
+    code.retain(|&cell| cell == (0, 0) || rng.gen_bool(0.4));
+
+    let first_fold = |axis: char| folds.iter().find(|fold| fold.0 == axis).unwrap().1;
+    let mut dots : Vec<(i64, i64)> = vec!((2 * first_fold('x'), 0), (0, 2 * first_fold('y')));
+    let mut placed : HashSet<(i64, i64)> = dots.iter().copied().collect();
+    for _ in 0..size {
+
+        let (mut x, mut y) = code[rng.gen_range(0..code.len())];
+        for &(axis, line) in folds.iter().rev() {
+            if !rng.gen::<bool>() { continue }
+            if axis == 'x' { x = 2 * line - x } else { y = 2 * line - y }
+        }
+
+        if placed.insert((x, y)) { dots.push((x, y)) }
+    }
+
+    let mut input : String = dots.into_iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+    input.push('\n');
+    for (axis, line) in folds { input.push_str(&format!("fold along {}={}\n", axis, line)) }
+    return input;
+}
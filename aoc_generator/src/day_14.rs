@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+const ELEMENTS : [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+// ================================================== GENERATOR ==================================================
+
+/// A polymer template of `size` elements and an insertion rule for every pair of elements.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let template : String = (0..std::cmp::max(size, 1)).map(|_| *ELEMENTS.choose(rng).unwrap()).collect();
+    let mut rules : Vec<String> = Vec::new();
+    for first in ELEMENTS.iter() {
+        for second in ELEMENTS.iter() {
+            rules.push(format!("{}{} -> {}", first, second, ELEMENTS.choose(rng).unwrap()));
+        }
+    }
+    rules.shuffle(rng);
+
+    return format!("{}\n\n", template) + &join_lines(rules);
+}
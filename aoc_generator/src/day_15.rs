@@ -0,0 +1,12 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== GENERATOR ==================================================
+
+/// A `size` x `size` grid of risk levels.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    return join_lines((0..size)
+        .map(|_| (0..size).map(|_| std::char::from_digit(rng.gen_range(1..=9), 10).unwrap()).collect::<String>()));
+}
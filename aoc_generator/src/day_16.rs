@@ -0,0 +1,80 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== STRUCTS ==================================================
+
+const TYPE_PRODUCT : u64 = 1;
+const TYPE_LITERAL : u64 = 4;
+const MAX_SUB_PACKETS : usize = 4;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn to_bits(value: u64, length: usize) -> String { format!("{:0>width$b}", value, width = length) }
+
+fn encode_literal(rng: &mut StdRng, value: u64) -> String {
+
+    let mut groups : Vec<String> = Vec::new();
+    let mut remaining : u64 = value;
+    loop {
+        groups.push(to_bits(remaining & 0b1111, 4));
+        remaining = remaining >> 4;
+        if remaining == 0 { break }
+    }
+
+    let last : usize = groups.len() - 1;
+    let groups : String = groups.into_iter().rev().enumerate()
+        .map(|(index, group)| if index == last { format!("0{}", group) } else { format!("1{}", group) })
+        .collect();
+    return to_bits(rng.gen_range(0..8), 3) + &to_bits(TYPE_LITERAL, 3) + &groups;
+}
+
+fn encode_operator(rng: &mut StdRng, packet_type: u64, sub_packets: Vec<String>) -> String {
+
+    let header : String = to_bits(rng.gen_range(0..8), 3) + &to_bits(packet_type, 3);
+    let sub_bits : String = sub_packets.concat();
+    // Both length types are exercised, the bit length one only fits up to 2^15 bits
+    if sub_bits.len() < (1 << 15) && rng.gen() { return header + "0" + &to_bits(sub_bits.len() as u64, 15) + &sub_bits }
+    return header + "1" + &to_bits(sub_packets.len() as u64, 11) + &sub_bits;
+}
+
+/// A packet holding `size` packets in total, itself included.
+fn generate_packet(rng: &mut StdRng, size: usize) -> String {
+
+    if size <= 1 {
+        let bits : u32 = rng.gen_range(1..=16);
+        let value : u64 = rng.gen_range(0..1 << bits);
+        return encode_literal(rng, value);
+    }
+
+    let packet_type : u64 = [0, 1, 2, 3, 5, 6, 7][rng.gen_range(0..7)];
+    // Products only multiply small literals so nested ones never overflow
+    if packet_type == TYPE_PRODUCT {
+        let values : Vec<u64> = (0..std::cmp::min(size - 1, 3)).map(|_| rng.gen_range(0..256)).collect();
+        let literals : Vec<String> = values.into_iter().map(|value| encode_literal(rng, value)).collect();
+        return encode_operator(rng, packet_type, literals);
+    }
+
+    let number_of_sub_packets : usize = if packet_type > TYPE_LITERAL { 2 } else { rng.gen_range(1..=std::cmp::min(size - 1, MAX_SUB_PACKETS)) };
+    let mut sizes : Vec<usize> = vec!(1; number_of_sub_packets);
+    for _ in number_of_sub_packets..(size - 1) {
+        let index : usize = rng.gen_range(0..number_of_sub_packets);
+        sizes[index] = sizes[index] + 1;
+    }
+
+    let sub_packets : Vec<String> = sizes.into_iter().map(|sub_size| generate_packet(rng, sub_size)).collect();
+    return encode_operator(rng, packet_type, sub_packets);
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// A BITS transmission of roughly `size` packets, padded with zeros to whole hexadecimal digits.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut bits : String = generate_packet(rng, std::cmp::max(size, 1));
+    while !bits.len().is_multiple_of(4) { bits.push('0') }
+
+    let hexadecimal : String = bits.as_bytes().chunks(4)
+        .map(|nibble| format!("{:X}", u8::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap()))
+        .collect();
+    return hexadecimal + "\n";
+}
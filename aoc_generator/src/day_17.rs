@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn triangular(steps: i64) -> i64 { steps * (steps + 1) / 2 }
+
+// ================================================== GENERATOR ==================================================
+
+/// A target area ahead and below the probe, about `size` units away on both axes.
+///
+/// The horizontal range always holds a point where some shot stops drifting, so the highest shot falls straight in.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let distance : i64 = std::cmp::max(size as i64, 10);
+    let x_start : i64 = rng.gen_range(distance..2 * distance);
+    let mut x_end : i64 = x_start + rng.gen_range(distance / 10..=distance / 4);
+    let stopping_steps : i64 = (1..).find(|&steps| triangular(steps) >= x_start).unwrap();
+    x_end = std::cmp::max(x_end, triangular(stopping_steps));
+
+    let y_start : i64 = -rng.gen_range(distance..2 * distance);
+    let y_end : i64 = std::cmp::min(y_start + rng.gen_range(distance / 10..=distance / 2), -1);
+
+    return format!("target area: x={}..{}, y={}..{}\n", x_start, x_end, y_start, y_end);
+}
@@ -0,0 +1,23 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Reduced numbers never nest pairs deeper than four
+const MAX_DEPTH : usize = 4;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn generate_element(rng: &mut StdRng, depth: usize) -> String {
+    if depth == MAX_DEPTH || (depth > 1 && rng.gen_bool(0.35)) { return rng.gen_range(0..=9).to_string() }
+    return format!("[{},{}]", generate_element(rng, depth + 1), generate_element(rng, depth + 1));
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` snailfish numbers, all of them already reduced and at least two so part 2 has a pair to add.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    return join_lines((0..std::cmp::max(size, 2)).map(|_| generate_element(rng, 0)));
+}
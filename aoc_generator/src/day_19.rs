@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// ================================================== STRUCTS ==================================================
+
+pub type Position = [i64; 3];
+
+/// The input of a scanner survey along with where every scanner really was.
+pub struct ScannerSurvey {
+    pub input:              String,
+    pub scanner_positions:  Vec<Position>,
+    pub number_of_beacons:  usize,
+}
+
+/// One of the 24 ways a scanner may face, the coordinates it swaps and negates.
+#[derive(Copy, Clone)]
+struct Rotation {
+    axes:   [usize; 3],
+    signs:  [i64; 3],
+}
+
+const DETECTION_RANGE : i64 = 1000;
+// Scanners are matched once they share twelve beacons
+const SHARED_BEACONS : usize = 12;
+const MAX_OWN_BEACONS : usize = 14;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Signed permutations of the axes that keep their handedness.
+fn get_rotations() -> Vec<Rotation> {
+
+    let permutations : [([usize; 3], i64); 6] = [([0, 1, 2], 1), ([1, 2, 0], 1), ([2, 0, 1], 1), ([0, 2, 1], -1), ([2, 1, 0], -1), ([1, 0, 2], -1)];
+    let mut rotations : Vec<Rotation> = Vec::new();
+    for &(axes, parity) in permutations.iter() {
+        for signs_index in 0..8 {
+            let signs : [i64; 3] = [0, 1, 2].map(|axis| if (signs_index >> axis) & 1 == 1 { -1 } else { 1 });
+            if signs[0] * signs[1] * signs[2] == parity { rotations.push(Rotation { axes: axes, signs: signs }) }
+        }
+    }
+
+    return rotations;
+}
+
+fn in_range(beacon: &Position, scanner: &Position) -> bool {
+    return (0..3).all(|axis| (beacon[axis] - scanner[axis]).abs() <= DETECTION_RANGE);
+}
+
+/// A beacon inside the range of every given scanner.
+fn beacon_around(rng: &mut StdRng, scanners: &[Position]) -> Position {
+    return [0, 1, 2].map(|axis| {
+        let low : i64 = scanners.iter().map(|scanner| scanner[axis] - DETECTION_RANGE).max().unwrap();
+        let high : i64 = scanners.iter().map(|scanner| scanner[axis] + DETECTION_RANGE).min().unwrap();
+        rng.gen_range(low..=high)
+    });
+}
+
+fn add_beacons(rng: &mut StdRng, scanners: &[Position], amount: usize, beacons: &mut Vec<Position>, known: &mut HashSet<Position>) {
+    let mut added : usize = 0;
+    while added < amount {
+        let beacon : Position = beacon_around(rng, scanners);
+        if known.insert(beacon) {
+            beacons.push(beacon);
+            added = added + 1;
+        }
+    }
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Rotation {
+
+    fn apply(&self, position: &Position) -> Position {
+        return [0, 1, 2].map(|axis| self.signs[axis] * position[self.axes[axis]]);
+    }
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` scanners, each one sharing at least twelve beacons with some scanner listed before it.
+///
+/// Every scanner faces a random way and reports every beacon within its range, relative to itself.
+pub fn generate_survey(rng: &mut StdRng, size: usize) -> ScannerSurvey {
+
+    let mut scanners : Vec<Position> = Vec::new();
+    let mut beacons : Vec<Position> = Vec::new();
+    let mut known : HashSet<Position> = HashSet::new();
+    for index in 0..std::cmp::max(size, 1) {
+
+        let scanner : Position = match index {
+            0 => [0, 0, 0],
+            _ => {
+                let parent : Position = scanners[rng.gen_range(0..index)];
+                let scanner : Position = [0, 1, 2].map(|axis| parent[axis] + rng.gen_range(-1100..=1100));
+                // Overlapping beacons are added first so the scanner can be placed from its parent
+                add_beacons(rng, &[parent, scanner], SHARED_BEACONS, &mut beacons, &mut known);
+                scanner
+            },
+        };
+
+        let own_beacons : usize = rng.gen_range(0..=MAX_OWN_BEACONS);
+        add_beacons(rng, &[scanner], own_beacons, &mut beacons, &mut known);
+        scanners.push(scanner);
+    }
+
+    let rotations : Vec<Rotation> = get_rotations();
+    let mut input : String = String::new();
+    for (index, scanner) in scanners.iter().enumerate() {
+
+        let rotation : Rotation = *rotations.choose(rng).unwrap();
+        let mut seen : Vec<Position> = beacons.iter()
+            .filter(|beacon| in_range(beacon, scanner))
+            .map(|beacon| rotation.apply(&[beacon[0] - scanner[0], beacon[1] - scanner[1], beacon[2] - scanner[2]]))
+            .collect();
+        seen.shuffle(rng);
+
+        if index != 0 { input.push('\n') }
+        input.push_str(&format!("--- scanner {} ---\n", index));
+        for beacon in seen { input.push_str(&format!("{},{},{}\n", beacon[0], beacon[1], beacon[2])) }
+    }
+
+    return ScannerSurvey {
+        input: input,
+        scanner_positions: scanners,
+        number_of_beacons: beacons.len(),
+    };
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String { generate_survey(rng, size).input }
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn there_are_24_rotations() {
+        let rotations = get_rotations();
+        let rotated : HashSet<Position> = rotations.iter().map(|rotation| rotation.apply(&[1, 2, 3])).collect();
+        assert_eq!(rotated.len(), 24);
+    }
+
+    #[test]
+    fn rotations_keep_handedness() {
+        // The cross product of the rotated x and y axes must be the rotated z axis
+        for rotation in get_rotations() {
+            let (x, y, z) = (rotation.apply(&[1, 0, 0]), rotation.apply(&[0, 1, 0]), rotation.apply(&[0, 0, 1]));
+            let cross : Position = [x[1] * y[2] - x[2] * y[1], x[2] * y[0] - x[0] * y[2], x[0] * y[1] - x[1] * y[0]];
+            assert_eq!(cross, z);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+const CODIFICATION_SIZE : usize = 512;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn random_slot(rng: &mut StdRng) -> char { if rng.gen() { '#' } else { '.' } }
+
+// ================================================== GENERATOR ==================================================
+
+/// A random codification and a `size` x `size` image.
+///
+/// When empty squares light up the lit ones switch off, or the infinite image would stay lit forever.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut codification : Vec<char> = (0..CODIFICATION_SIZE).map(|_| random_slot(rng)).collect();
+    if codification[0] == '#' { codification[CODIFICATION_SIZE - 1] = '.' }
+
+    let size : usize = std::cmp::max(size, 1);
+    let image : Vec<String> = (0..size).map(|_| (0..size).map(|_| random_slot(rng)).collect()).collect();
+    return codification.into_iter().collect::<String>() + "\n\n" + &join_lines(image);
+}
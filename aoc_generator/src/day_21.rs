@@ -0,0 +1,9 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== GENERATOR ==================================================
+
+/// Two players somewhere on the ten spaces of the board, the game has no size to scale.
+pub fn generate(rng: &mut StdRng, _size: usize) -> String {
+    return format!("Player 1 starting position: {}\nPlayer 2 starting position: {}\n", rng.gen_range(1..=10), rng.gen_range(1..=10));
+}
@@ -0,0 +1,34 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Like the official inputs, the first steps stay around the initialization region
+const INITIALIZATION_LIMIT : i64 = 50;
+const REACTOR_LIMIT : i64 = 100_000;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn generate_range(rng: &mut StdRng, limit: i64) -> (i64, i64) {
+    let start : i64 = rng.gen_range(-limit..limit);
+    let end : i64 = std::cmp::min(start + rng.gen_range(0..=limit), limit);
+    return (start, end);
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// `size` reboot steps, the first fifth of them inside the initialization region.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let initialization_steps : usize = std::cmp::max(size / 5, 1);
+    return join_lines((0..size).map(|index| {
+
+        // The first step has something to switch off later on
+        let state : &str = if index == 0 || rng.gen_bool(0.7) { "on" } else { "off" };
+        let limit : i64 = if index < initialization_steps { INITIALIZATION_LIMIT } else { REACTOR_LIMIT };
+        let (x, y, z) = (generate_range(rng, limit), generate_range(rng, limit), generate_range(rng, limit));
+        format!("{} x={}..{},y={}..{},z={}..{}", state, x.0, x.1, y.0, y.1, z.0, z.1)
+    }));
+}
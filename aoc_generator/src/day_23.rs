@@ -0,0 +1,14 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+// ================================================== GENERATOR ==================================================
+
+/// Two amphipods of every kind shuffled into the rooms, the burrow has no size to scale.
+pub fn generate(rng: &mut StdRng, _size: usize) -> String {
+
+    let mut amphipods : Vec<char> = "AABBCCDD".chars().collect();
+    amphipods.shuffle(rng);
+
+    return format!("#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+        amphipods[0], amphipods[1], amphipods[2], amphipods[3], amphipods[4], amphipods[5], amphipods[6], amphipods[7]);
+}
@@ -0,0 +1,47 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// ================================================== STRUCTS ==================================================
+
+const NUMBER_OF_DIGITS : usize = 14;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// The block every digit goes through, either pushing 'digit + y_offset' onto z or trying to pop it.
+fn digit_block(divisor: i64, x_offset: i64, y_offset: i64) -> String {
+    return format!("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+        divisor, x_offset, y_offset);
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// A MONAD program with the official structure, the model number always has fourteen digits.
+///
+/// Pushes and pops are balanced and every popped pair of digits can be matched, so valid model numbers exist.
+/// `size` is how far tied digits may be from differing by eight: zero leaves a single valid model number
+/// and eight or more let any two digits be tied, with millions of valid model numbers.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let mut program : String = String::new();
+    let mut pushed_offsets : Vec<i64> = Vec::new();
+    let mut pushes_left : usize = NUMBER_OF_DIGITS / 2;
+    for _ in 0..NUMBER_OF_DIGITS {
+
+        let must_push : bool = pushed_offsets.len() == 0;
+        if pushes_left != 0 && (must_push || rng.gen()) {
+            // Offsets of at least ten never match a digit, so the block always pushes
+            let y_offset : i64 = rng.gen_range(0..=16);
+            program.push_str(&digit_block(1, rng.gen_range(10..=16), y_offset));
+            pushed_offsets.push(y_offset);
+            pushes_left = pushes_left - 1;
+        } else {
+            // The popped digit plus both offsets must land on the current digit
+            let spread : i64 = std::cmp::min(size, 8) as i64;
+            let difference : i64 = (8 - rng.gen_range(0..=spread)) * if rng.gen() { 1 } else { -1 };
+            let x_offset : i64 = difference - pushed_offsets.pop().unwrap();
+            program.push_str(&digit_block(26, x_offset, rng.gen_range(0..=16)));
+        }
+    }
+
+    return program;
+}
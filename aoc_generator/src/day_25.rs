@@ -0,0 +1,70 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::join_lines;
+
+// ================================================== STRUCTS ==================================================
+
+// Small seabeds often keep their herds circling forever, they are rolled again this many times
+const ATTEMPTS : usize = 100;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn roll_seabed(rng: &mut StdRng, size: usize) -> Vec<Vec<char>> {
+    return (0..size).map(|_| (0..size)
+        .map(|_| match rng.gen_range(0..100) {
+            0..=37 => '>',
+            38..=74 => 'v',
+            _ => '.',
+        })
+        .collect())
+        .collect();
+}
+
+/// Moves every cucumber of `herd` that has room in front of it, `true` if any did.
+fn move_herd(seabed: &mut Vec<Vec<char>>, herd: char) -> bool {
+
+    let size = seabed.len();
+    let ahead = |row: usize, column: usize| if herd == '>' { (row, (column + 1) % size) } else { ((row + 1) % size, column) };
+    let moving : Vec<(usize, usize)> = (0..size).flat_map(|row| (0..size).map(move |column| (row, column)))
+        .filter(|&(row, column)| { let (next_row, next_column) = ahead(row, column); seabed[row][column] == herd && seabed[next_row][next_column] == '.' })
+        .collect();
+
+    for &(row, column) in moving.iter() {
+        let (next_row, next_column) = ahead(row, column);
+        seabed[row][column] = '.';
+        seabed[next_row][next_column] = herd;
+    }
+    return moving.len() != 0;
+}
+
+/// Whether the herds of the seabed stop within a generous number of steps.
+fn jams(mut seabed: Vec<Vec<char>>) -> bool {
+    let size = seabed.len();
+    for _ in 0..(10 * size + 100) {
+        let east_moved = move_herd(&mut seabed, '>');
+        let south_moved = move_herd(&mut seabed, 'v');
+        if !east_moved && !south_moved { return true }
+    }
+    return false;
+}
+
+// ================================================== GENERATOR ==================================================
+
+/// A `size` x `size` seabed, crowded like the official one so the herds jam instead of roaming forever.
+///
+/// Seabeds that do not jam are rolled again, and after too many of them the empty cells are filled so nothing moves.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+
+    let size : usize = std::cmp::max(size, 1);
+    let mut seabed = roll_seabed(rng, size);
+    for _ in 1..ATTEMPTS {
+        if jams(seabed.clone()) { break }
+        seabed = roll_seabed(rng, size);
+    }
+
+    if !jams(seabed.clone()) {
+        for cell in seabed.iter_mut().flat_map(|row| row.iter_mut()) { if *cell == '.' { *cell = 'v' } }
+    }
+    return join_lines(seabed.into_iter().map(|row| row.into_iter().collect::<String>()));
+}
@@ -0,0 +1,131 @@
+//! Random puzzle inputs for every day, written in the same format as the official ones.
+//!
+//! Every generator is seeded, so the same day, seed and size always give the same input,
+//! and `size` scales whatever makes the day harder (lines, grid side, packets, scanners...).
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub use day_19::{generate_survey, ScannerSurvey};
+
+// ================================================== STRUCTS ==================================================
+
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Lines of an input, each one ending in a newline like the committed inputs.
+pub(crate) fn join_lines<I: IntoIterator<Item = String>>(lines: I) -> String {
+    return lines.into_iter().map(|line| line + "\n").collect();
+}
+
+pub fn get_generator(day: u8) -> Option<Generator> {
+    let generator : Generator = match day {
+        1 => day_01::generate,
+        2 => day_02::generate,
+        3 => day_03::generate,
+        4 => day_04::generate,
+        5 => day_05::generate,
+        6 => day_06::generate,
+        7 => day_07::generate,
+        8 => day_08::generate,
+        9 => day_09::generate,
+        10 => day_10::generate,
+        11 => day_11::generate,
+        12 => day_12::generate,
+        13 => day_13::generate,
+        14 => day_14::generate,
+        15 => day_15::generate,
+        16 => day_16::generate,
+        17 => day_17::generate,
+        18 => day_18::generate,
+        19 => day_19::generate,
+        20 => day_20::generate,
+        21 => day_21::generate,
+        22 => day_22::generate,
+        23 => day_23::generate,
+        24 => day_24::generate,
+        25 => day_25::generate,
+        _ => return None,
+    };
+
+    return Some(generator);
+}
+
+/// About as big as the official input of the day.
+pub fn default_size(day: u8) -> usize {
+    return match day {
+        1 => 2000,
+        2 | 3 | 7 => 1000,
+        5 => 500,
+        6 => 300,
+        8 | 16 => 200,
+        11 | 12 => 10,
+        13 => 900,
+        14 => 20,
+        17 => 250,
+        19 => 30,
+        22 => 420,
+        24 => 8,
+        25 => 137,
+        _ => 100,
+    };
+}
+
+/// The input of `day` for the given seed and size, `None` for days outside the calendar.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let generator = get_generator(day)?;
+    return Some(generator(&mut StdRng::seed_from_u64(seed), size));
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        for day in 1..=25 {
+            assert_eq!(generate(day, 7, 10), generate(day, 7, 10), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn only_calendar_days_have_generators() {
+        assert!(generate(0, 1, 10).is_none());
+        assert!(generate(26, 1, 10).is_none());
+    }
+
+    #[test]
+    fn lines_end_in_newlines() {
+        assert_eq!(join_lines(vec!("1".to_owned(), "2".to_owned())), "1\n2\n");
+    }
+}
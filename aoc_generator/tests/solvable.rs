@@ -0,0 +1,76 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use aoc_common::Solution;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+const SEEDS : u64 = 4;
+// The smallest inputs are where generators forget about the edge cases
+const SMALL_SIZES : [usize; 3] = [1, 2, 3];
+
+/// Generated inputs must parse and both parts must find an answer on them, at `size` and at the smallest sizes.
+fn assert_solvable<S: Solution>(day: u8, size: usize) {
+    for size in SMALL_SIZES.iter().copied().chain(std::iter::once(size)) {
+        for seed in 0..SEEDS {
+
+            let input = aoc_generator::generate(day, seed, size).unwrap();
+            let parsed = S::parse(&input).unwrap_or_else(|error| panic!("day {} size {} seed {}: {}\n{}", day, size, seed, error, input));
+            if let Err(error) = S::part1(&parsed) { panic!("day {} size {} seed {} part 1: {}\n{}", day, size, seed, error, input) }
+            if let Err(error) = S::part2(&parsed) { panic!("day {} size {} seed {} part 2: {}\n{}", day, size, seed, error, input) }
+        }
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[test] fn day_01() { assert_solvable::<day_01::Day01>(1, 200) }
+#[test] fn day_02() { assert_solvable::<day_02::Day02>(2, 200) }
+#[test] fn day_03() { assert_solvable::<day_03::Day03>(3, 200) }
+#[test] fn day_04() { assert_solvable::<day_04::Day04>(4, 20) }
+#[test] fn day_05() { assert_solvable::<day_05::Day05>(5, 50) }
+#[test] fn day_06() { assert_solvable::<day_06::Day06>(6, 20) }
+#[test] fn day_07() { assert_solvable::<day_07::Day07>(7, 200) }
+#[test] fn day_08() { assert_solvable::<day_08::Day08>(8, 50) }
+#[test] fn day_09() { assert_solvable::<day_09::Day09>(9, 30) }
+#[test] fn day_10() { assert_solvable::<day_10::Day10>(10, 50) }
+#[test] fn day_11() { assert_solvable::<day_11::Day11>(11, 10) }
+#[test] fn day_12() { assert_solvable::<day_12::Day12>(12, 6) }
+#[test] fn day_13() { assert_solvable::<day_13::Day13>(13, 200) }
+#[test] fn day_14() { assert_solvable::<day_14::Day14>(14, 20) }
+#[test] fn day_15() { assert_solvable::<day_15::Day15>(15, 20) }
+#[test] fn day_16() { assert_solvable::<day_16::Day16>(16, 60) }
+#[test] fn day_17() { assert_solvable::<day_17::Day17>(17, 50) }
+#[test] fn day_18() { assert_solvable::<day_18::Day18>(18, 10) }
+#[test] fn day_20() { assert_solvable::<day_20::Day20>(20, 10) }
+#[test] fn day_21() { assert_solvable::<day_21::Day21>(21, 0) }
+#[test] fn day_22() { assert_solvable::<day_22::Day22>(22, 30) }
+#[test] fn day_24() { assert_solvable::<day_24::Day24>(24, 3) }
+#[test] fn day_25() { assert_solvable::<day_25::Day25>(25, 20) }
+
+#[test]
+fn day_19_finds_the_generated_poses() {
+    // Aligning scanners is slow without optimizations, a couple of small surveys are enough
+    for seed in 0..2 {
+
+        let survey = aoc_generator::generate_survey(&mut StdRng::seed_from_u64(seed), 3);
+        let scanners = day_19::Day19::parse(&survey.input).unwrap();
+        assert_eq!(day_19::Day19::part1(&scanners).unwrap(), survey.number_of_beacons, "seed {}", seed);
+
+        let largest_distance = survey.scanner_positions.iter()
+            .flat_map(|first| survey.scanner_positions.iter().map(move |second| (0..3).map(|axis| (first[axis] - second[axis]).abs()).sum::<i64>()))
+            .max().unwrap();
+        assert_eq!(day_19::Day19::part2(&scanners).unwrap() as i64, largest_distance, "seed {}", seed);
+    }
+}
+
+#[test]
+fn day_23_burrows_parse() {
+    // Even the smallest burrow takes minutes to organize, only its shape is checked
+    for seed in 0..SEEDS {
+        let input = aoc_generator::generate(23, seed, 0).unwrap();
+        let lines = day_23::Day23::parse(&input).unwrap();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[2].matches(|characther| "ABCD".contains(characther)).count(), 4);
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(Debug)]
+pub enum InputErrorKind {
+    Io(std::io::Error),
+    InvalidInteger(String, ParseIntError),
+    InvalidDigit(char),
+    Malformed(String),
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub kind: InputErrorKind,
+}
+
+type InputResult<T> = Result<T, InputError>;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn with_file<T>(result: InputResult<T>, filename: &Path) -> InputResult<T> {
+    return result.map_err(|mut error| {
+        error.file = Some(filename.to_path_buf());
+        error
+    });
+}
+
+fn open<P: AsRef<Path>>(filename: P) -> InputResult<BufReader<File>> {
+    let file = File::open(filename.as_ref())
+        .map_err(|error| InputError::new(InputErrorKind::Io(error)))
+        .map_err(|error| error.in_file(filename.as_ref()))?;
+
+    return Ok(BufReader::new(file));
+}
+
+fn offset_of(line: &str, value: &str) -> usize {
+    return value.as_ptr() as usize - line.as_ptr() as usize;
+}
+
+fn is_blank(line: &str) -> bool { line.trim().is_empty() }
+
+fn parse_digits(line: &str, line_number: usize) -> InputResult<Vec<u32>> {
+    return line.chars()
+        .enumerate()
+        .map(|(index, characther)| characther.to_digit(10)
+            .ok_or_else(|| InputError::new(InputErrorKind::InvalidDigit(characther)).at(line_number, index + 1)))
+        .collect();
+}
+
+// ================================================== PARSERS ==================================================
+
+pub fn parse_value<T: FromStr<Err = ParseIntError>>(value: &str, line: usize, column: usize) -> InputResult<T> {
+    return value.trim().parse()
+        .map_err(|error| InputError::new(InputErrorKind::InvalidInteger(value.to_owned(), error)).at(line, column));
+}
+
+pub fn parse_list<T: FromStr<Err = ParseIntError>>(line: &str, separator: &str, line_number: usize) -> InputResult<Vec<T>> {
+    return line.split(separator)
+        .map(|value| parse_value(value, line_number, offset_of(line, value) + 1))
+        .collect();
+}
+
+pub fn parse_whitespace_list<T: FromStr<Err = ParseIntError>>(line: &str, line_number: usize) -> InputResult<Vec<T>> {
+    return line.split_whitespace()
+        .map(|value| parse_value(value, line_number, offset_of(line, value) + 1))
+        .collect();
+}
+
+// ================================================== READERS ==================================================
+
+/// Lines of the input without the blank lines trailing it, the rule every reader and every day follows.
+/// Blank lines before the last line with content are kept, some inputs use them to split sections.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    let content_lines : usize = input.lines().enumerate()
+        .filter(|(_, line)| !is_blank(line))
+        .last().map_or(0, |(index, _)| index + 1);
+    return input.lines().take(content_lines);
+}
+
+pub fn read_lines_from<R: BufRead>(reader: R) -> InputResult<Vec<String>> {
+    let mut lines : Vec<String> = reader.lines()
+        .enumerate()
+        .map(|(index, line)| line.map_err(|error| InputError::new(InputErrorKind::Io(error)).at(index + 1, 1)))
+        .collect::<InputResult<_>>()?;
+
+    while lines.last().is_some_and(|line| is_blank(line)) { lines.pop(); }
+    return Ok(lines);
+}
+
+pub fn read_int_lines_from<R: BufRead>(reader: R) -> InputResult<Vec<i64>> {
+    return read_lines_from(reader)?.iter()
+        .enumerate()
+        .map(|(index, line)| parse_value(line, index + 1, 1))
+        .collect();
+}
+
+/// Integers of every line as they are read, for inputs too big to keep in memory.
+///
+/// Blank lines are only known to be trailing once the input ends, so they are held back and
+/// reported as an invalid integer when a reading comes after them.
+pub fn stream_int_lines_from<R: BufRead>(reader: R) -> impl Iterator<Item = InputResult<i64>> {
+
+    let mut first_blank_line : Option<usize> = None;
+    return reader.lines()
+        .enumerate()
+        .flat_map(move |(index, line)| {
+
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => return vec!(Err(InputError::new(InputErrorKind::Io(error)).at(index + 1, 1))),
+            };
+            if is_blank(&line) {
+                first_blank_line.get_or_insert(index + 1);
+                return Vec::new();
+            }
+
+            let mut readings : Vec<InputResult<i64>> = Vec::new();
+            if let Some(blank_line) = first_blank_line.take() { readings.push(parse_value("", blank_line, 1)) }
+            readings.push(parse_value(&line, index + 1, 1));
+            return readings;
+        });
+}
+
+pub fn read_list_int_lines_from<R: BufRead>(reader: R) -> InputResult<Vec<Vec<i64>>> {
+    return read_lines_from(reader)?.iter()
+        .enumerate()
+        .map(|(index, line)| parse_list(line, ",", index + 1))
+        .collect();
+}
+
+pub fn read_digits_from<R: BufRead>(reader: R) -> InputResult<Vec<Vec<u32>>> {
+    return read_lines_from(reader)?.iter()
+        .enumerate()
+        .map(|(index, line)| parse_digits(line, index + 1))
+        .collect();
+}
+
+pub fn read_chars_from<R: BufRead>(reader: R) -> InputResult<Vec<Vec<char>>> {
+    return Ok(read_lines_from(reader)?.iter()
+        .map(|line| line.chars().collect())
+        .collect());
+}
+
+pub fn read_to_string<P: AsRef<Path>>(filename: P) -> InputResult<String> {
+    return std::fs::read_to_string(filename.as_ref())
+        .map_err(|error| InputError::new(InputErrorKind::Io(error)).in_file(filename.as_ref()));
+}
+
+pub fn read_lines<P: AsRef<Path>>(filename: P) -> InputResult<Vec<String>> {
+    return with_file(read_lines_from(open(&filename)?), filename.as_ref());
+}
+
+pub fn read_int_lines<P: AsRef<Path>>(filename: P) -> InputResult<Vec<i64>> {
+    return with_file(read_int_lines_from(open(&filename)?), filename.as_ref());
+}
+
+pub fn read_list_int_lines<P: AsRef<Path>>(filename: P) -> InputResult<Vec<Vec<i64>>> {
+    return with_file(read_list_int_lines_from(open(&filename)?), filename.as_ref());
+}
+
+pub fn read_digits<P: AsRef<Path>>(filename: P) -> InputResult<Vec<Vec<u32>>> {
+    return with_file(read_digits_from(open(&filename)?), filename.as_ref());
+}
+
+pub fn read_chars<P: AsRef<Path>>(filename: P) -> InputResult<Vec<Vec<char>>> {
+    return with_file(read_chars_from(open(&filename)?), filename.as_ref());
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl InputError {
+
+    pub fn new(kind: InputErrorKind) -> InputError {
+        InputError {
+            file: None,
+            line: None,
+            column: None,
+            kind: kind,
+        }
+    }
+
+    pub fn malformed(message: &str, line: usize) -> InputError {
+        return InputError::new(InputErrorKind::Malformed(message.to_owned())).at(line, 1);
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> InputError {
+        self.line = Some(line);
+        self.column = Some(column);
+        return self;
+    }
+
+    pub fn in_file(mut self, filename: &Path) -> InputError {
+        self.file = Some(filename.to_path_buf());
+        return self;
+    }
+}
+
+impl fmt::Display for InputErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            InputErrorKind::Io(error) => write!(f, "{}", error),
+            InputErrorKind::InvalidInteger(value, error) => write!(f, "invalid integer '{}' ({})", value, error),
+            InputErrorKind::InvalidDigit(characther) => write!(f, "invalid digit '{}'", characther),
+            InputErrorKind::Malformed(message) => write!(f, "malformed input, {}", message),
+        };
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+
+        match &self.file {
+            Some(file) => write!(f, "{}", file.display())?,
+            None => write!(f, "<input>")?,
+        }
+
+        if let Some(line) = self.line { write!(f, ":{}", line)? }
+        if let Some(column) = self.column { write!(f, ":{}", column)? }
+        return write!(f, ": {}", self.kind);
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match &self.kind {
+            InputErrorKind::Io(error) => Some(error),
+            InputErrorKind::InvalidInteger(_, error) => Some(error),
+            InputErrorKind::InvalidDigit(_) => None,
+            InputErrorKind::Malformed(_) => None,
+        };
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn trailing_blank_lines_are_dropped() {
+        assert_eq!(lines("1\n\n2\n\n  \n").collect::<Vec<&str>>(), vec!("1", "", "2"));
+        assert_eq!(lines("\n\n").count(), 0);
+        assert_eq!(read_lines_from("a\n\nb\n\n".as_bytes()).unwrap(), vec!("a", "", "b"));
+        assert_eq!(read_int_lines_from("1\n2\n\n".as_bytes()).unwrap(), vec!(1, 2));
+    }
+
+    #[test]
+    fn blank_lines_between_readings_are_rejected() {
+        assert!(read_int_lines_from("1\n\n2".as_bytes()).is_err());
+
+        let streamed : Vec<InputResult<i64>> = stream_int_lines_from("1\n\n2\n\n".as_bytes()).collect();
+        assert_eq!(streamed.len(), 3);
+        assert_eq!(streamed[1].as_ref().unwrap_err().line, Some(2));
+        assert_eq!(streamed[2].as_ref().unwrap(), &2);
+        assert_eq!(stream_int_lines_from("1\n2\n\n".as_bytes()).map(|reading| reading.unwrap()).collect::<Vec<i64>>(), vec!(1, 2));
+    }
+}
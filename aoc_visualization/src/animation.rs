@@ -0,0 +1,211 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::time::Duration;
+
+use image::{Delay, Frame, Rgb, RgbImage};
+use image::buffer::ConvertBuffer;
+use image::codecs::gif::{GifEncoder, Repeat};
+
+use aoc_common::Grid;
+
+use crate::error::VisualizationError;
+use crate::frames::{self, FrameSink};
+use crate::palette::Palette;
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AnimationFormat { Gif, Apng }
+
+/// Keeps every frame of a simulation in memory and encodes them as a single looping animation.
+///
+/// Frames may change size between steps (day 20 grows, day 13 shrinks), they all get centered
+/// over the background colour of the palette at the size of the largest one.
+pub struct Animation {
+    cell_size:      u32,
+    palette:        Palette,
+    frame_delay:    Duration,
+    frames:         Vec<RgbImage>,
+}
+
+const DEFAULT_FRAME_DELAY : Duration = Duration::from_millis(100);
+// Fastest colour quantization, the palettes never get close to the 256 colours of a GIF anyway
+const GIF_SPEED : i32 = 30;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn center_frame(frame: &RgbImage, width: u32, height: u32, background: Rgb<u8>) -> RgbImage {
+
+    if frame.dimensions() == (width, height) { return frame.clone() }
+
+    let mut centered : RgbImage = RgbImage::from_pixel(width, height, background);
+    let offset_x : i64 = ((width - frame.width()) / 2) as i64;
+    let offset_y : i64 = ((height - frame.height()) / 2) as i64;
+    image::imageops::replace(&mut centered, frame, offset_x, offset_y);
+
+    return centered;
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl AnimationFormat {
+
+    /// `.gif` files are GIFs, `.png` and `.apng` ones animated PNGs.
+    pub fn from_path(path: &Path) -> Option<AnimationFormat> {
+
+        let extension : String = path.extension()?.to_str()?.to_lowercase();
+        return match extension.as_str() {
+            "gif" => Some(AnimationFormat::Gif),
+            "png" | "apng" => Some(AnimationFormat::Apng),
+            _ => None,
+        };
+    }
+}
+
+impl Animation {
+
+    pub fn new() -> Animation {
+        Animation {
+            cell_size: frames::DEFAULT_CELL_SIZE,
+            palette: Palette::sunset(),
+            frame_delay: DEFAULT_FRAME_DELAY,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn with_cell_size(mut self, cell_size: u32) -> Animation {
+        if cell_size == 0 { panic!("🚨  Cells must be at least one pixel wide") }
+        self.cell_size = cell_size;
+        return self;
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> Animation {
+        self.palette = palette;
+        return self;
+    }
+
+    pub fn with_frame_delay(mut self, frame_delay: Duration) -> Animation {
+        self.frame_delay = frame_delay;
+        return self;
+    }
+
+    pub fn frame_count(&self) -> usize { self.frames.len() }
+
+    pub fn push_frame<T, F: Fn(&T) -> f64>(&mut self, grid: &Grid<T>, intensity: F) {
+        let frame : RgbImage = frames::render_grid(grid, intensity, self.cell_size, &self.palette);
+        self.frames.push(frame);
+    }
+
+    /// The frames as they get encoded, all with the same size.
+    fn sized_frames(&self) -> Vec<RgbImage> {
+
+        let width : u32 = self.frames.iter().map(|frame| frame.width()).max().unwrap_or(0);
+        let height : u32 = self.frames.iter().map(|frame| frame.height()).max().unwrap_or(0);
+        let background : Rgb<u8> = Rgb(self.palette.color_at(0.0));
+
+        return self.frames.iter()
+            .map(|frame| center_frame(frame, width, height, background))
+            .collect();
+    }
+
+    fn save_gif(&self, file: BufWriter<File>) -> Result<(), VisualizationError> {
+
+        let mut encoder = GifEncoder::new_with_speed(file, GIF_SPEED);
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        let delay : Delay = Delay::from_saturating_duration(self.frame_delay);
+        let gif_frames = self.sized_frames().into_iter()
+            .map(|frame| Frame::from_parts(frame.convert(), 0, 0, delay));
+        encoder.encode_frames(gif_frames)?;
+
+        return Ok(());
+    }
+
+    fn save_apng(&self, file: BufWriter<File>) -> Result<(), VisualizationError> {
+
+        let sized_frames : Vec<RgbImage> = self.sized_frames();
+        let mut encoder = png::Encoder::new(file, sized_frames[0].width(), sized_frames[0].height());
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        // Zero plays loops forever, delays are given as a fraction of a second
+        encoder.set_animated(sized_frames.len() as u32, 0)?;
+        let delay_millis : u16 = std::cmp::min(self.frame_delay.as_millis(), u16::MAX as u128) as u16;
+        encoder.set_frame_delay(delay_millis, 1000)?;
+
+        let mut writer = encoder.write_header()?;
+        for frame in sized_frames.iter() { writer.write_image_data(frame.as_raw())? }
+        writer.finish()?;
+
+        return Ok(());
+    }
+
+    pub fn save(&self, path: &Path, format: AnimationFormat) -> Result<(), VisualizationError> {
+
+        if self.frames.len() == 0 { return Err(VisualizationError::NoFrames) }
+        if let Some(directory) = path.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let file : BufWriter<File> = BufWriter::new(File::create(path)?);
+        return match format {
+            AnimationFormat::Gif => self.save_gif(file),
+            AnimationFormat::Apng => self.save_apng(file),
+        };
+    }
+}
+
+impl FrameSink for Animation {
+    fn add_frame(&mut self, intensities: &Grid<f64>) -> Result<(), VisualizationError> {
+        self.push_frame(intensities, |&intensity| intensity);
+        return Ok(());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn growing_animation() -> Animation {
+        let mut animation = Animation::new().with_cell_size(1).with_frame_delay(Duration::from_millis(40));
+        animation.push_frame(&Grid::filled(2, 2, 1.0), |&value| value);
+        animation.push_frame(&Grid::filled(4, 4, 0.5), |&value| value);
+        return animation;
+    }
+
+    #[test]
+    fn formats_come_from_the_extension() {
+        assert_eq!(AnimationFormat::from_path(Path::new("octopi.GIF")), Some(AnimationFormat::Gif));
+        assert_eq!(AnimationFormat::from_path(Path::new("frames/octopi.apng")), Some(AnimationFormat::Apng));
+        assert_eq!(AnimationFormat::from_path(Path::new("octopi.mp4")), None);
+        assert_eq!(AnimationFormat::from_path(Path::new("octopi")), None);
+    }
+
+    #[test]
+    fn smaller_frames_are_centered() {
+        let frames = growing_animation().sized_frames();
+        assert!(frames.iter().all(|frame| frame.dimensions() == (4, 4)));
+        assert_eq!(frames[0].get_pixel(0, 0), &Rgb(Palette::sunset().color_at(0.0)));
+        assert_eq!(frames[0].get_pixel(1, 1), &Rgb(Palette::sunset().color_at(1.0)));
+    }
+
+    #[test]
+    fn animations_are_encoded() {
+        let output_dir = std::env::temp_dir().join(format!("aoc-animation-{}", std::process::id()));
+        let animation = growing_animation();
+
+        animation.save(&output_dir.join("growing.gif"), AnimationFormat::Gif).unwrap();
+        let gif = std::fs::read(output_dir.join("growing.gif")).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+
+        animation.save(&output_dir.join("growing.png"), AnimationFormat::Apng).unwrap();
+        let apng = std::fs::read(output_dir.join("growing.png")).unwrap();
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+
+        assert!(matches!(Animation::new().save(&output_dir.join("empty.gif"), AnimationFormat::Gif), Err(VisualizationError::NoFrames)));
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+use std::io;
+
+// ================================================== STRUCTS ==================================================
+
+/// Why a frame or an animation could not be written out.
+#[derive(Debug)]
+pub enum VisualizationError {
+    Io(io::Error),
+    Image(image::ImageError),
+    Apng(png::EncodingError),
+    NoFrames,
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl From<io::Error> for VisualizationError {
+    fn from(error: io::Error) -> VisualizationError { VisualizationError::Io(error) }
+}
+
+impl From<image::ImageError> for VisualizationError {
+    fn from(error: image::ImageError) -> VisualizationError { VisualizationError::Image(error) }
+}
+
+impl From<png::EncodingError> for VisualizationError {
+    fn from(error: png::EncodingError) -> VisualizationError { VisualizationError::Apng(error) }
+}
+
+impl fmt::Display for VisualizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            VisualizationError::Io(error) => write!(f, "could not write the frame, {}", error),
+            VisualizationError::Image(error) => write!(f, "could not encode the frame, {}", error),
+            VisualizationError::Apng(error) => write!(f, "could not encode the animation, {}", error),
+            VisualizationError::NoFrames => write!(f, "there are no frames to animate"),
+        };
+    }
+}
+
+impl std::error::Error for VisualizationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            VisualizationError::Io(error) => Some(error),
+            VisualizationError::Image(error) => Some(error),
+            VisualizationError::Apng(error) => Some(error),
+            VisualizationError::NoFrames => None,
+        };
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+
+use image::{Rgb, RgbImage};
+
+use aoc_common::Grid;
+
+use crate::error::VisualizationError;
+use crate::palette::Palette;
+
+// ================================================== STRUCTS ==================================================
+
+/// Anything collecting the frames of a simulation, with every cell given as an intensity in `0.0..=1.0`.
+pub trait FrameSink {
+    fn add_frame(&mut self, intensities: &Grid<f64>) -> Result<(), VisualizationError>;
+}
+
+/// Turns grids into numbered PNG frames inside a single directory.
+///
+/// Every cell becomes a `cell_size` square coloured by the palette, days only say how intense
+/// each cell is (`0.0..=1.0`) so the same simulation can be drawn with any palette.
+pub struct FrameWriter {
+    output_dir:     PathBuf,
+    cell_size:      u32,
+    palette:        Palette,
+    frames_written: usize,
+}
+
+pub(crate) const DEFAULT_CELL_SIZE : u32 = 4;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+pub(crate) fn render_grid<T, F: Fn(&T) -> f64>(grid: &Grid<T>, intensity: F, cell_size: u32, palette: &Palette) -> RgbImage {
+
+    let width : u32 = grid.width() as u32 * cell_size;
+    let height : u32 = grid.height() as u32 * cell_size;
+
+    return RgbImage::from_fn(width, height, |x, y| {
+        let cell = &grid[((x / cell_size) as usize, (y / cell_size) as usize)];
+        return Rgb(palette.color_at(intensity(cell)));
+    });
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl FrameWriter {
+
+    pub fn new<P: Into<PathBuf>>(output_dir: P) -> FrameWriter {
+        FrameWriter {
+            output_dir: output_dir.into(),
+            cell_size: DEFAULT_CELL_SIZE,
+            palette: Palette::sunset(),
+            frames_written: 0,
+        }
+    }
+
+    pub fn with_cell_size(mut self, cell_size: u32) -> FrameWriter {
+        if cell_size == 0 { panic!("🚨  Cells must be at least one pixel wide") }
+        self.cell_size = cell_size;
+        return self;
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> FrameWriter {
+        self.palette = palette;
+        return self;
+    }
+
+    pub fn output_dir(&self) -> &Path { &self.output_dir }
+    pub fn frames_written(&self) -> usize { self.frames_written }
+
+    pub fn render<T, F: Fn(&T) -> f64>(&self, grid: &Grid<T>, intensity: F) -> RgbImage {
+        return render_grid(grid, intensity, self.cell_size, &self.palette);
+    }
+
+    /// Saves the grid as the next `frame - NNNN.png`, creating the directory on the first frame.
+    pub fn write_frame<T, F: Fn(&T) -> f64>(&mut self, grid: &Grid<T>, intensity: F) -> Result<PathBuf, VisualizationError> {
+
+        if self.frames_written == 0 { std::fs::create_dir_all(&self.output_dir)? }
+
+        let path : PathBuf = self.output_dir.join(format!("frame - {:0>4}.png", self.frames_written));
+        self.render(grid, intensity).save(&path)?;
+        self.frames_written = self.frames_written + 1;
+
+        return Ok(path);
+    }
+}
+
+impl FrameSink for FrameWriter {
+    fn add_frame(&mut self, intensities: &Grid<f64>) -> Result<(), VisualizationError> {
+        return self.write_frame(intensities, |&intensity| intensity).map(|_| ());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::palette::{BLACK, WHITE};
+
+    #[test]
+    fn cells_are_scaled_up() {
+        let writer = FrameWriter::new("unused").with_cell_size(2).with_palette(Palette::grayscale());
+        let grid = Grid::from_rows(vec!(vec!(false, true))).unwrap();
+        let frame = writer.render(&grid, |&set| if set { 1.0 } else { 0.0 });
+
+        assert_eq!(frame.dimensions(), (4, 2));
+        assert_eq!(frame.get_pixel(1, 1), &Rgb(BLACK));
+        assert_eq!(frame.get_pixel(2, 0), &Rgb(WHITE));
+    }
+
+    #[test]
+    fn frames_are_numbered() {
+        let output_dir = std::env::temp_dir().join(format!("aoc-visualization-{}", std::process::id()));
+        let mut writer = FrameWriter::new(&output_dir);
+        let grid = Grid::filled(3, 3, 0.5);
+
+        writer.write_frame(&grid, |&value| value).unwrap();
+        let path = writer.write_frame(&grid, |&value| value).unwrap();
+        assert_eq!(path, output_dir.join("frame - 0001.png"));
+        assert_eq!(writer.frames_written(), 2);
+        assert_eq!(image::open(&path).unwrap().width(), 3 * DEFAULT_CELL_SIZE);
+
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+mod animation;
+mod error;
+mod frames;
+mod palette;
+
+pub use animation::{Animation, AnimationFormat};
+pub use error::VisualizationError;
+pub use frames::{FrameSink, FrameWriter};
+pub use palette::{Color, Palette, BLACK, WHITE};
@@ -0,0 +1,100 @@
+// ================================================== STRUCTS ==================================================
+
+pub type Color = [u8; 3];
+
+/// A gradient of colours over `0.0..=1.0`, each stop blending linearly into the next one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Palette {
+    stops:  Vec<(f64, Color)>,
+}
+
+pub const BLACK : Color = [0, 0, 0];
+pub const WHITE : Color = [255, 255, 255];
+const NIGHT : Color = [0, 13, 51];
+const CRIMSON : Color = [179, 26, 51];
+const GOLD : Color = [242, 230, 77];
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+fn blend_channel(from: u8, to: u8, ratio: f64) -> u8 {
+    return (from as f64 + (to as f64 - from as f64) * ratio).round() as u8;
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Palette {
+
+    /// Stops may come in any order, values outside `0.0..=1.0` are clamped.
+    pub fn new(stops: Vec<(f64, Color)>) -> Palette {
+
+        if stops.len() == 0 { panic!("🚨  A palette needs at least one colour") }
+        let mut stops : Vec<(f64, Color)> = stops.into_iter()
+            .map(|(value, color)| (value.clamp(0.0, 1.0), color))
+            .collect();
+        stops.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+        Palette {
+            stops: stops,
+        }
+    }
+
+    /// Evenly spaced stops, from the first colour at `0.0` to the last one at `1.0`.
+    pub fn gradient(colors: &[Color]) -> Palette {
+
+        let last : f64 = std::cmp::max(colors.len(), 2) as f64 - 1.0;
+        return Palette::new(colors.iter().enumerate()
+            .map(|(index, &color)| (index as f64 / last, color))
+            .collect());
+    }
+
+    pub fn grayscale() -> Palette { Palette::gradient(&[BLACK, WHITE]) }
+    pub fn ember() -> Palette { Palette::gradient(&[NIGHT, CRIMSON, GOLD]) }
+
+    /// The night blue to gold gradient the day 11 visualization was drawn with.
+    pub fn sunset() -> Palette {
+        Palette::new(vec!((0.0, NIGHT), (0.1, CRIMSON), (0.5, GOLD), (0.6, CRIMSON), (1.0, NIGHT)))
+    }
+
+    pub fn color_at(&self, value: f64) -> Color {
+
+        let value : f64 = if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) };
+        let next_index : usize = self.stops.iter().position(|&(stop, _)| stop >= value).unwrap_or(self.stops.len());
+        if next_index == 0 { return self.stops[0].1 }
+        if next_index == self.stops.len() { return self.stops[next_index - 1].1 }
+
+        let (from_value, from_color) = self.stops[next_index - 1];
+        let (to_value, to_color) = self.stops[next_index];
+        let ratio : f64 = (value - from_value) / (to_value - from_value);
+
+        return [
+            blend_channel(from_color[0], to_color[0], ratio),
+            blend_channel(from_color[1], to_color[1], ratio),
+            blend_channel(from_color[2], to_color[2], ratio),
+        ];
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn colors_blend_between_stops() {
+        let palette = Palette::grayscale();
+        assert_eq!(palette.color_at(0.0), BLACK);
+        assert_eq!(palette.color_at(0.5), [128, 128, 128]);
+        assert_eq!(palette.color_at(1.0), WHITE);
+    }
+
+    #[test]
+    fn values_outside_the_stops_are_clamped() {
+        let palette = Palette::new(vec!((0.75, WHITE), (0.25, BLACK)));
+        assert_eq!(palette.color_at(-3.0), BLACK);
+        assert_eq!(palette.color_at(0.1), BLACK);
+        assert_eq!(palette.color_at(0.9), WHITE);
+        assert_eq!(palette.color_at(f64::NAN), BLACK);
+    }
+}
@@ -0,0 +1,373 @@
+//! Day 1: Sonar Sweep, counting how often the depth measurements increase.
+//!
+//! Beyond the puzzle, sweeps of any size can be streamed through sliding windows reduced by an
+//! `Aggregator`, and an `AnomalyDetector` flags the jumps that stand out.
+
+use std::collections::VecDeque;
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
+// ================================================== STRUCTS ==================================================
+
+#[derive(PartialEq)]
+enum VariationType { Decrement, Constant, Increment }
+
+/// What the readings of a window are reduced to before it is compared with the one before it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aggregator { Sum, Mean, Min, Max, Median }
+
+/// How the aggregates of a sweep changed, each one compared with the one before it.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SweepStats {
+    /// Full windows seen, the first one has nothing to be compared with
+    pub windows:        usize,
+    pub increments:     usize,
+    pub decrements:     usize,
+    pub plateaus:       usize,
+    /// Most increments in a row
+    pub longest_rise:   usize,
+}
+
+/// `size` consecutive readings, kept up to date as readings come in instead of aggregated again.
+pub struct SlidingWindow {
+    size:               usize,
+    aggregator:         Aggregator,
+    readings:           VecDeque<i64>,
+    // Only kept for the aggregators that need the readings in order
+    sorted_readings:    Vec<i64>,
+    sum:                i128,
+    previous_scaled:    Option<i128>,
+    current_rise:       usize,
+    stats:              SweepStats,
+}
+
+/// How far a value has to jump from the one before it to be flagged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    /// Jumps bigger than this, up or down
+    Absolute(f64),
+    /// Jumps this many standard deviations away from the mean of the jumps before them
+    ZScore(f64),
+}
+
+/// A jump flagged by an `AnomalyDetector`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    /// Index of the value the sweep jumped to, also the first reading of its window
+    pub index:      usize,
+    /// Difference with the value before it, negative when the sweep got shallower
+    pub jump:       f64,
+}
+
+/// Flags the jumps of a stream of values, keeping only the running mean and variance of the jumps.
+pub struct AnomalyDetector {
+    threshold:      Threshold,
+    previous:       Option<f64>,
+    index:          usize,
+    jumps:          usize,
+    mean:           f64,
+    squares:        f64,
+}
+
+// The z-score of the first jumps would be measured against almost nothing
+const MIN_JUMPS_FOR_Z_SCORE : usize = 2;
+
+// ================================================== AUX FUNCTIONS ==================================================
+
+/// Stats of the sums of every `window` consecutive readings, windows bigger than the sweep simply see no sum.
+pub fn analyze<I: IntoIterator<Item = i64>>(readings: I, window: usize) -> AocResult<SweepStats> {
+    return analyze_with(readings, window, Aggregator::Sum);
+}
+
+/// Stats of every `window` consecutive readings reduced by `aggregator`.
+pub fn analyze_with<I: IntoIterator<Item = i64>>(readings: I, window: usize, aggregator: Aggregator) -> AocResult<SweepStats> {
+
+    let mut sliding_window = SlidingWindow::new(window)?.with_aggregator(aggregator);
+    for reading in readings { sliding_window.push(reading); }
+    return Ok(sliding_window.get_stats());
+}
+
+/// Jumps between the aggregates of consecutive windows that go past `threshold`.
+pub fn detect_anomalies<I: IntoIterator<Item = i64>>(readings: I, window: usize, aggregator: Aggregator, threshold: Threshold) -> AocResult<Vec<Anomaly>> {
+
+    let mut sliding_window = SlidingWindow::new(window)?.with_aggregator(aggregator);
+    let mut detector = AnomalyDetector::new(threshold)?;
+    return Ok(readings.into_iter()
+        .filter_map(|reading| sliding_window.push(reading))
+        .filter_map(|value| detector.feed(value))
+        .collect());
+}
+
+/// Number of times the sum of `window` consecutive measurements is bigger than the previous sum.
+pub fn count_increments(data: &Vec<i64>, window: usize) -> AocResult<usize> {
+    return Ok(analyze(data.iter().copied(), window)?.increments);
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl Aggregator {
+
+    /// Every aggregate is kept multiplied by this, so windows compare exactly and only reports are divided.
+    fn scale(&self, size: usize) -> i128 {
+        return match self {
+            Aggregator::Sum | Aggregator::Min | Aggregator::Max => 1,
+            Aggregator::Mean => size as i128,
+            // Even windows have two middle readings
+            Aggregator::Median => 2,
+        };
+    }
+
+    fn needs_order(&self) -> bool { matches!(self, Aggregator::Min | Aggregator::Max | Aggregator::Median) }
+}
+
+impl std::str::FromStr for Aggregator {
+    type Err = AocError;
+
+    fn from_str(name: &str) -> AocResult<Aggregator> {
+        return match name {
+            "sum" => Ok(Aggregator::Sum),
+            "mean" => Ok(Aggregator::Mean),
+            "min" => Ok(Aggregator::Min),
+            "max" => Ok(Aggregator::Max),
+            "median" => Ok(Aggregator::Median),
+            _ => Err(AocError::malformed(&format!("unknown aggregator '{}', expected sum, mean, min, max or median", name))),
+        };
+    }
+}
+
+impl SlidingWindow {
+
+    /// Sums the readings, fails for an empty window as it would have nothing to sum.
+    pub fn new(size: usize) -> AocResult<SlidingWindow> {
+
+        if size == 0 { return Err(AocError::malformed("a window needs at least one reading")) }
+        Ok(SlidingWindow {
+            size: size,
+            aggregator: Aggregator::Sum,
+            readings: VecDeque::with_capacity(size),
+            sorted_readings: Vec::new(),
+            sum: 0,
+            previous_scaled: None,
+            current_rise: 0,
+            stats: SweepStats::default(),
+        })
+    }
+
+    pub fn with_aggregator(mut self, aggregator: Aggregator) -> SlidingWindow {
+        self.aggregator = aggregator;
+        return self;
+    }
+
+    pub fn get_size(&self) -> usize { self.size }
+    pub fn get_aggregator(&self) -> Aggregator { self.aggregator }
+    pub fn get_stats(&self) -> SweepStats { self.stats }
+
+    fn scaled_aggregate(&self) -> i128 {
+
+        let sorted = &self.sorted_readings;
+        return match self.aggregator {
+            Aggregator::Sum | Aggregator::Mean => self.sum,
+            Aggregator::Min => sorted[0] as i128,
+            Aggregator::Max => sorted[sorted.len() - 1] as i128,
+            Aggregator::Median => sorted[(sorted.len() - 1) / 2] as i128 + sorted[sorted.len() / 2] as i128,
+        };
+    }
+
+    /// Adds a reading, dropping the oldest one, and gives the aggregate of the window once it is full.
+    pub fn push(&mut self, reading: i64) -> Option<f64> {
+
+        self.readings.push_back(reading);
+        self.sum = self.sum + reading as i128;
+        if self.aggregator.needs_order() {
+            let position = self.sorted_readings.partition_point(|&sorted| sorted < reading);
+            self.sorted_readings.insert(position, reading);
+        }
+
+        if self.readings.len() > self.size {
+            let oldest = self.readings.pop_front().unwrap();
+            self.sum = self.sum - oldest as i128;
+            if self.aggregator.needs_order() {
+                let position = self.sorted_readings.partition_point(|&sorted| sorted < oldest);
+                self.sorted_readings.remove(position);
+            }
+        }
+        if self.readings.len() < self.size { return None }
+
+        let scaled = self.scaled_aggregate();
+        let variation = match self.previous_scaled {
+            None => None,
+            Some(previous_scaled) if scaled > previous_scaled => Some(VariationType::Increment),
+            Some(previous_scaled) if scaled < previous_scaled => Some(VariationType::Decrement),
+            Some(_) => Some(VariationType::Constant),
+        };
+
+        match variation {
+            Some(VariationType::Increment) => {
+                self.stats.increments = self.stats.increments + 1;
+                self.current_rise = self.current_rise + 1;
+                self.stats.longest_rise = std::cmp::max(self.stats.longest_rise, self.current_rise);
+            },
+            Some(VariationType::Decrement) => {
+                self.stats.decrements = self.stats.decrements + 1;
+                self.current_rise = 0;
+            },
+            Some(VariationType::Constant) => {
+                self.stats.plateaus = self.stats.plateaus + 1;
+                self.current_rise = 0;
+            },
+            None => {},
+        }
+
+        self.stats.windows = self.stats.windows + 1;
+        self.previous_scaled = Some(scaled);
+        return Some(scaled as f64 / self.aggregator.scale(self.size) as f64);
+    }
+}
+
+impl AnomalyDetector {
+
+    /// Fails for thresholds that are not a finite number above zero.
+    pub fn new(threshold: Threshold) -> AocResult<AnomalyDetector> {
+
+        let limit = match threshold { Threshold::Absolute(limit) | Threshold::ZScore(limit) => limit };
+        if !(limit.is_finite() && limit > 0.0) { return Err(AocError::malformed(&format!("threshold '{}' is not a positive number", limit))) }
+
+        Ok(AnomalyDetector {
+            threshold: threshold,
+            previous: None,
+            index: 0,
+            jumps: 0,
+            mean: 0.0,
+            squares: 0.0,
+        })
+    }
+
+    fn standard_deviation(&self) -> f64 { (self.squares / self.jumps as f64).sqrt() }
+
+    /// Takes the next value, giving back the jump to it when it goes past the threshold.
+    pub fn feed(&mut self, value: f64) -> Option<Anomaly> {
+
+        let index = self.index;
+        self.index = self.index + 1;
+        let jump = value - self.previous.replace(value)?;
+
+        let flagged = match self.threshold {
+            Threshold::Absolute(limit) => jump.abs() > limit,
+            // Steady jumps have no deviation, any different one is then infinitely far from them
+            Threshold::ZScore(limit) => self.jumps >= MIN_JUMPS_FOR_Z_SCORE && (jump - self.mean).abs() > limit * self.standard_deviation(),
+        };
+
+        // Welford's update, the flagged jump counts for the ones after it
+        self.jumps = self.jumps + 1;
+        let delta = jump - self.mean;
+        self.mean = self.mean + delta / self.jumps as f64;
+        self.squares = self.squares + delta * (jump - self.mean);
+
+        return if flagged { Some(Anomaly { index: index, jump: jump }) } else { None };
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 compares single measurements, part 2 sliding windows of three.
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<i64>, InputError> { aoc_input::read_int_lines_from(input.as_bytes()) }
+    fn part1(data: &Vec<i64>) -> AocResult<usize> { count_increments(data, 1) }
+    fn part2(data: &Vec<i64>) -> AocResult<usize> { count_increments(data, 3) }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn counts_increments_of_single_values() {
+        assert_eq!(count_increments(&vec!(1, 2, 2, 1, 5), 1).unwrap(), 2);
+    }
+
+    #[test]
+    fn counts_increments_of_sliding_windows() {
+        assert_eq!(count_increments(&vec!(1, 2, 3, 4), 3).unwrap(), 1);
+        assert_eq!(count_increments(&vec!(1, 2, 3), 3).unwrap(), 0);
+    }
+
+    #[test]
+    fn windows_bigger_than_the_sweep_see_nothing() {
+        assert_eq!(count_increments(&vec!(1, 2), 3).unwrap(), 0);
+        assert_eq!(analyze(Vec::new(), 1).unwrap(), SweepStats::default());
+        assert!(analyze(vec!(1, 2), 0).is_err());
+    }
+
+    #[test]
+    fn sweeps_report_every_variation() {
+        let stats = analyze(vec!(1, 2, 3, 3, 2, 4, 5, 6, 7), 1).unwrap();
+        assert_eq!(stats, SweepStats { windows: 9, increments: 6, decrements: 1, plateaus: 1, longest_rise: 4 });
+    }
+
+    #[test]
+    fn window_sums_are_kept_up_to_date() {
+        let mut window = SlidingWindow::new(3).unwrap();
+        let sums : Vec<Option<f64>> = vec!(199, 200, 208, 210, 200).into_iter().map(|reading| window.push(reading)).collect();
+        assert_eq!(sums, vec!(None, None, Some(607.0), Some(618.0), Some(618.0)));
+        assert_eq!(window.get_stats().plateaus, 1);
+    }
+
+    #[test]
+    fn huge_readings_do_not_overflow() {
+        let stats = analyze(vec!(i64::MAX, i64::MAX, i64::MAX, 0), 2).unwrap();
+        assert_eq!((stats.plateaus, stats.decrements), (1, 1));
+    }
+
+    #[test]
+    fn aggregators_reduce_the_window() {
+        let readings : Vec<i64> = vec!(5, 1, 4, 2);
+        let aggregates = |aggregator: Aggregator, size: usize| -> Vec<f64> {
+            let mut window = SlidingWindow::new(size).unwrap().with_aggregator(aggregator);
+            return readings.iter().filter_map(|&reading| window.push(reading)).collect();
+        };
+
+        assert_eq!(aggregates(Aggregator::Sum, 2), vec!(6.0, 5.0, 6.0));
+        assert_eq!(aggregates(Aggregator::Mean, 2), vec!(3.0, 2.5, 3.0));
+        assert_eq!(aggregates(Aggregator::Min, 3), vec!(1.0, 1.0));
+        assert_eq!(aggregates(Aggregator::Max, 3), vec!(5.0, 4.0));
+        assert_eq!(aggregates(Aggregator::Median, 3), vec!(4.0, 2.0));
+        assert_eq!(aggregates(Aggregator::Median, 4), vec!(3.0));
+    }
+
+    #[test]
+    fn aggregators_are_named() {
+        assert_eq!("median".parse::<Aggregator>().unwrap(), Aggregator::Median);
+        assert!("mode".parse::<Aggregator>().is_err());
+    }
+
+    #[test]
+    fn big_jumps_are_flagged() {
+        let anomalies = detect_anomalies(vec!(10, 11, 30, 29, 5), 1, Aggregator::Sum, Threshold::Absolute(5.0)).unwrap();
+        assert_eq!(anomalies, vec!(Anomaly { index: 2, jump: 19.0 }, Anomaly { index: 4, jump: -24.0 }));
+    }
+
+    #[test]
+    fn unusual_jumps_are_flagged() {
+        let readings : Vec<i64> = vec!(100, 101, 103, 104, 106, 107, 150, 151);
+        let anomalies = detect_anomalies(readings, 1, Aggregator::Sum, Threshold::ZScore(3.0)).unwrap();
+        assert_eq!(anomalies, vec!(Anomaly { index: 6, jump: 43.0 }));
+    }
+
+    #[test]
+    fn thresholds_must_be_positive() {
+        assert!(AnomalyDetector::new(Threshold::Absolute(-1.0)).is_err());
+        assert!(AnomalyDetector::new(Threshold::ZScore(f64::NAN)).is_err());
+        assert!(AnomalyDetector::new(Threshold::Absolute(0.0)).is_err());
+        assert!(AnomalyDetector::new(Threshold::ZScore(f64::INFINITY)).is_err());
+        assert!(AnomalyDetector::new(Threshold::Absolute(0.5)).is_ok());
+    }
+}
@@ -1,45 +1,94 @@
-mod read;
+use aoc_common::Solution;
+use day_01::{Aggregator, Day01, SlidingWindow, Threshold};
 
-#[derive(PartialEq)]
-enum VariationType { Decrement, Constant, Increment }
+/// Follows every window over the readings of stdin as they arrive, logs of any size never sit in memory.
+fn stream_stdin(window_sizes: Vec<usize>) -> Result<(), String> {
 
-fn count_increments(data: &Vec<i64>, window: usize) -> usize {
+    let mut windows : Vec<SlidingWindow> = window_sizes.into_iter()
+        .map(|size| SlidingWindow::new(size).map_err(|error| error.to_string()))
+        .collect::<Result<_, _>>()?;
 
-    let mut info: Vec<VariationType> = Vec::new();
-    let mut previous: Option<i64> = None;
-    let mut sum: i64 = 0;
+    let stdin = std::io::stdin();
+    for reading in aoc_input::stream_int_lines_from(stdin.lock()) {
+        let reading = reading.map_err(|error| error.to_string())?;
+        for window in windows.iter_mut() { window.push(reading); }
+    }
 
-    for index in 0..(data.len() - window + 1) {
+    for window in windows.iter() {
+        let stats = window.get_stats();
+        println!("📈 Window of {}: {} increments, {} decrements, {} plateaus, longest rise of {} over {} sums",
+            window.get_size(), stats.increments, stats.decrements, stats.plateaus, stats.longest_rise, stats.windows);
+    }
 
-        for sub_index in index..(index + window) {
-            let value = data[sub_index];
-            sum = sum + value;
-        }
+    return Ok(());
+}
 
-        match (previous, sum) {
-            (None, _) => info.push(VariationType::Constant),
-            (Some(prev_value), _) if sum == prev_value => info.push(VariationType::Constant),
-            (Some(prev_value), _) if sum > prev_value => info.push(VariationType::Increment),
-            (Some(prev_value), _) if sum < prev_value => info.push(VariationType::Decrement),
-            (Some(_), _) => {},
-        }
+/// Value given after `--name`, if the option is there at all.
+fn option_value<'a>(arguments: &'a [String], name: &str) -> Result<Option<&'a str>, String> {
+    return match arguments.iter().position(|argument| argument == name) {
+        None => Ok(None),
+        Some(index) => arguments.get(index + 1).map(|value| Some(value.as_str())).ok_or(format!("{} needs a value", name)),
+    };
+}
 
-        previous = Some(sum);
-        sum = 0;
-    }
+fn parse_option<T: std::str::FromStr>(arguments: &[String], name: &str, default: T) -> Result<T, String> {
+    return match option_value(arguments, name)? {
+        None => Ok(default),
+        Some(value) => value.parse().map_err(|_| format!("'{}' is not a valid value for {}", value, name)),
+    };
+}
+
+/// Lists the jumps of the readings in `file`, by default the ones three standard deviations away from the usual jump.
+fn find_anomalies(file: &str, options: &[String]) -> Result<(), String> {
 
-    let increments: usize = info.iter()
-        .filter(|&variation| *variation == VariationType::Increment)
-        .count();
+    let window : usize = parse_option(options, "--window", 1)?;
+    let aggregator : Aggregator = parse_option(options, "--aggregator", Aggregator::Sum)?;
+    let threshold : Threshold = match (option_value(options, "--jump")?, option_value(options, "--z-score")?) {
+        (Some(_), Some(_)) => return Err("--jump and --z-score can not be used together".to_owned()),
+        (Some(_), None) => Threshold::Absolute(parse_option(options, "--jump", 0.0)?),
+        (None, _) => Threshold::ZScore(parse_option(options, "--z-score", 3.0)?),
+    };
 
-    return increments;
+    let readings = aoc_input::read_int_lines(file).map_err(|error| error.to_string())?;
+    let anomalies = day_01::detect_anomalies(readings, window, aggregator, threshold).map_err(|error| error.to_string())?;
+    for anomaly in anomalies.iter() { println!("⚠️  Reading {}: jump of {:+}", anomaly.index + 1, anomaly.jump) }
+    println!("📈 {} anomalies found", anomalies.len());
+
+    return Ok(());
 }
 
 fn main() {
 
-    let data = read::read_int_lines("input.txt".to_owned());
+    // `--stdin [WINDOW...]` sweeps the readings piped in, with windows of 1 and 3 unless others are given,
+    // `--anomalies FILE [--window N] [--aggregator NAME] [--jump DEPTH | --z-score Z]` flags the odd jumps of a file
+    let arguments : Vec<String> = std::env::args().skip(1).collect();
+    let result = match arguments.first().map(|argument| argument.as_str()) {
+        Some("--stdin") => {
+            let window_sizes : Result<Vec<usize>, _> = arguments[1..].iter().map(|argument| argument.parse()).collect();
+            match window_sizes {
+                Ok(window_sizes) if window_sizes.len() == 0 => Some(stream_stdin(vec!(1, 3))),
+                Ok(window_sizes) => Some(stream_stdin(window_sizes)),
+                Err(error) => Some(Err(format!("window sizes must be numbers, {}", error))),
+            }
+        },
+        Some("--anomalies") => match arguments.get(1) {
+            Some(file) => Some(find_anomalies(file, &arguments[2..])),
+            None => Some(Err("--anomalies needs a file of readings".to_owned())),
+        },
+        _ => None,
+    };
+
+    if let Some(result) = result {
+        if let Err(error) = result {
+            eprintln!("🚨  {}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let data = Day01::parse(&aoc_input::read_to_string("../inputs/day01.txt").unwrap()).unwrap();
     // Part 1
-    println!("📈 Increments found (window of 1): {}", count_increments(&data, 1));
+    println!("📈 Increments found (window of 1): {}", Day01::part1(&data).unwrap());
     // Part 2
-    println!("📈 Increments found (window of 3): {}", count_increments(&data, 3));
+    println!("📈 Increments found (window of 3): {}", Day01::part2(&data).unwrap());
 }
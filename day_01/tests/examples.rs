@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_01::Day01;
+
+const EXAMPLE : &str = include_str!("../../inputs/day01.example.txt");
+const INPUT : &str = include_str!("../../inputs/day01.txt");
+
+fn parse(input: &str) -> <Day01 as Solution>::Input { Day01::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day01::part1(&parse(EXAMPLE)).unwrap(), 7);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day01::part2(&parse(EXAMPLE)).unwrap(), 5);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day01::part1(&parse(INPUT)).unwrap(), 1564);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day01::part2(&parse(INPUT)).unwrap(), 1611);
+}
@@ -0,0 +1,49 @@
+use proptest::prelude::*;
+
+use day_01::{Aggregator, SweepStats};
+
+/// Aggregates every window again from scratch, the way the sweep used to be counted.
+fn naive_aggregate(values: &[i64], aggregator: Aggregator) -> f64 {
+
+    let mut sorted : Vec<i64> = values.to_vec();
+    sorted.sort();
+    let sum : f64 = values.iter().map(|&value| value as f64).sum();
+    return match aggregator {
+        Aggregator::Sum => sum,
+        Aggregator::Mean => sum / values.len() as f64,
+        Aggregator::Min => sorted[0] as f64,
+        Aggregator::Max => sorted[sorted.len() - 1] as f64,
+        Aggregator::Median => (sorted[(sorted.len() - 1) / 2] + sorted[sorted.len() / 2]) as f64 / 2.0,
+    };
+}
+
+fn naive_stats(readings: &Vec<i64>, window: usize, aggregator: Aggregator) -> SweepStats {
+
+    let sums : Vec<f64> = readings.windows(window).map(|values| naive_aggregate(values, aggregator)).collect();
+    let mut stats = SweepStats { windows: sums.len(), ..SweepStats::default() };
+    let mut current_rise : usize = 0;
+    for pair in sums.windows(2) {
+        if pair[1] > pair[0] { stats.increments = stats.increments + 1; current_rise = current_rise + 1 }
+        else if pair[1] < pair[0] { stats.decrements = stats.decrements + 1; current_rise = 0 }
+        else { stats.plateaus = stats.plateaus + 1; current_rise = 0 }
+        stats.longest_rise = std::cmp::max(stats.longest_rise, current_rise);
+    }
+
+    return stats;
+}
+
+proptest! {
+
+    #[test]
+    fn streaming_matches_summing_every_window(readings in prop::collection::vec(-50..50i64, 0..200), window in 1..30usize) {
+        prop_assert_eq!(day_01::analyze(readings.clone(), window).unwrap(), naive_stats(&readings, window, Aggregator::Sum));
+    }
+
+    #[test]
+    fn every_aggregator_matches_its_naive_version(readings in prop::collection::vec(-50..50i64, 0..100), window in 1..10usize,
+        aggregator in prop::sample::select(vec!(Aggregator::Sum, Aggregator::Mean, Aggregator::Min, Aggregator::Max, Aggregator::Median))) {
+
+        let stats = day_01::analyze_with(readings.clone(), window, aggregator).unwrap();
+        prop_assert_eq!(stats, naive_stats(&readings, window, aggregator));
+    }
+}
@@ -1,114 +1,658 @@
+//! Day 2: Dive!, following the planned course of the submarine.
+//!
+//! A `Navigator` follows the course and a `NavigationModel` decides what every command does to it,
+//! new kinds of submarines only need a model of their own.
+//!
+//! Courses are written one command per line, the puzzle inputs are the simplest of them:
+//!
+//! ```text
+//! # Comments run until the end of the line, blank lines are skipped
+//! forward 5
+//! down 3
+//! repeat 4 {
+//!     back 1
+//!     turn        # forward and back swap directions
+//!     up 2
+//! }
+//! ```
+//!
+//! `repeat N { ... }` blocks can be nested and written on a single line, mistakes are reported
+//! with the line and column where they are.
+//!
+//! The path of a submarine can be queried once it followed its course, exported as CSV or SVG, and its final
+//! state saved as `horizontal,depth,aim,heading` to resume another course from there.
+
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Write;
+use std::num::ParseIntError;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_common::{AocError, AocResult, InputError, InputErrorKind, Solution};
 
 // ================================================== STRUCTS ==================================================
 
-type CoordinateUnit = i64;
+pub type CoordinateUnit = i64;
 
-#[derive(Copy, Clone)]
+/// Where the submarine is, depth grows downwards from the surface.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Position {
     pub horizontal:     CoordinateUnit,
     pub depth:          CoordinateUnit,
 }
 
+/// Which way forward goes, a turn swaps it with back.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Heading { Ahead, Astern }
+
+/// Everything a command can change, only the models that steer with the aim look at it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NavigationState {
+    pub position:       Position,
+    pub aim:            CoordinateUnit,
+    pub heading:        Heading,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MovementCode { Up, Forward, Down, Back, Turn }
+
+/// A movement and its value, turns take no value and always carry a 0.
+pub type Command = (MovementCode, CoordinateUnit);
+
+/// How a kind of submarine reacts to the commands of its course, parsing and following the course is shared by all of them.
+///
+/// Models can keep state of their own, every course starts again from a copy of the model the navigator was given.
+pub trait NavigationModel: Clone {
+    /// Changes `state` as the command asks, failing when the submarine would leave the chart.
+    fn apply(&mut self, state: &mut NavigationState, command: Command) -> AocResult<()>;
+}
+
+/// Moves straight up, down or forward with every command.
+#[derive(Clone, Default)]
+pub struct DirectModel;
+
+/// Up and down only change the aim, moving forward dives along it.
+#[derive(Clone, Default)]
+pub struct AimedModel;
+
+/// Follows a course as its model dictates, remembering every position it went through.
+pub struct Navigator<M: NavigationModel> {
+    model:              M,
+    start_model:        M,
+    start_state:        NavigationState,
+    positions_traveled: Vec<Position>,
+    state:              NavigationState,
+}
+
+pub type Submarine = Navigator<DirectModel>;
+pub type AimedSubmarine = Navigator<AimedModel>;
+
+/// A word of a course, or a brace, with where it starts for the errors.
 #[derive(Copy, Clone)]
-enum MovementCode { Up, Forward, Down }
-pub struct Submarine {
-    start_position:     Position,
-    positions_traveled: Vec<Position>
+struct Token<'a> {
+    text:       &'a str,
+    line:       usize,
+    column:     usize,
 }
 
-pub struct AimedSubmarine {
-    start_position:     Position,
-    aim:                CoordinateUnit,
-    positions_traveled: Vec<Position>
+/// Reads the tokens of a course, expanding the repeats as it goes.
+struct CourseParser<'a> {
+    tokens:     Vec<Token<'a>>,
+    next:       usize,
 }
 
+// A repeat inside a repeat is enough to fill the memory, courses stop at a sensible length
+const MAX_COMMANDS : usize = 1_000_000;
+const MAX_NESTING : usize = 64;
+
 // ================================================== AUX FUNCTIONS ==================================================
 
-fn convert_str_code(code_str: &str) -> MovementCode {
+fn convert_str_code(code_str: &str) -> AocResult<MovementCode> {
 
     let mut map_str_codes: HashMap<&str, MovementCode> = HashMap::new();
     map_str_codes.insert("up",          MovementCode::Up);
     map_str_codes.insert("down",        MovementCode::Down);
     map_str_codes.insert("forward",     MovementCode::Forward);
+    map_str_codes.insert("back",        MovementCode::Back);
+    map_str_codes.insert("turn",        MovementCode::Turn);
 
-    let code_converted = map_str_codes.get(code_str).unwrap();
-    return code_converted.clone();
+    return map_str_codes.get(code_str).copied()
+        .ok_or_else(|| AocError::malformed(&format!("unknown movement '{}'", code_str)));
 }
 
-// ================================================== IMPLEMENTATIONS ==================================================
+fn error_at(token: Token, message: &str) -> InputError {
+    return InputError::new(InputErrorKind::Malformed(message.to_owned())).at(token.line, token.column);
+}
 
-impl Submarine {
-    pub fn new() -> Submarine {
-        Submarine {
-            start_position:     Position{horizontal: 0, depth: 0},
-            positions_traveled: Vec::new(),
+/// Words and braces of the course, without the comments.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+
+    let mut tokens : Vec<Token> = Vec::new();
+    for (index, line) in aoc_input::lines(text).enumerate() {
+
+        let code = line.split('#').next().unwrap_or("");
+        let mut word_start : Option<usize> = None;
+        // The extra space ends the last word of the line
+        for (offset, characther) in code.char_indices().chain(std::iter::once((code.len(), ' '))) {
+
+            let is_brace = characther == '{' || characther == '}';
+            if !characther.is_whitespace() && !is_brace {
+                if word_start.is_none() { word_start = Some(offset) }
+                continue;
+            }
+
+            if let Some(start) = word_start.take() {
+                tokens.push(Token { text: &code[start..offset], line: index + 1, column: start + 1 });
+            }
+            if is_brace {
+                tokens.push(Token { text: &code[offset..offset + 1], line: index + 1, column: offset + 1 });
+            }
         }
     }
 
-    pub fn update_chart(&mut self, lines: Vec<String>) {
+    return tokens;
+}
+
+/// Every command of a course, with its repeats expanded.
+pub fn parse_course(text: &str) -> Result<Vec<Command>, InputError> {
+    let mut parser = CourseParser { tokens: tokenize(text), next: 0 };
+    return parser.parse_block(None, 0);
+}
 
-        self.positions_traveled = vec!(self.start_position);
-        
-        for line in lines.into_iter() {
+pub fn read_course<P: AsRef<Path>>(filename: P) -> Result<Vec<Command>, InputError> {
+    let text = aoc_input::read_to_string(&filename)?;
+    return parse_course(&text).map_err(|error| error.in_file(filename.as_ref()));
+}
 
-            let line_split: Vec<&str> = line.split(' ').collect();
-            let code = line_split[0];
-            let value: CoordinateUnit = line_split[1].parse().unwrap();
+/// Commands of the lines of a course, they are lines like 'forward 5'.
+pub fn convert_lines_to_commands(lines: &Vec<String>) -> AocResult<Vec<Command>> {
+    return Ok(parse_course(&lines.join("\n"))?);
+}
 
-            let last_position = self.positions_traveled.last().unwrap();
+pub fn in_chart(value: Option<CoordinateUnit>) -> AocResult<CoordinateUnit> {
+    return value.ok_or_else(|| AocError::unsolvable("the submarine went past the limits of the chart"));
+}
 
-            let code_converted = convert_str_code(code);
-            let new_position = match code_converted {
-                MovementCode::Up => Position{horizontal: last_position.horizontal, depth: last_position.depth - value},
-                MovementCode::Forward => Position{horizontal: last_position.horizontal + value, depth: last_position.depth},
-                MovementCode::Down => Position{horizontal: last_position.horizontal, depth: last_position.depth + value},
-            };
+/// Depth where the move from `from` to `to` crosses `horizontal`, moves go in a straight line.
+fn interpolate_depth(from: Position, to: Position, horizontal: CoordinateUnit) -> Option<CoordinateUnit> {
 
-            self.positions_traveled.push(new_position);
+    let (low, high) = (std::cmp::min(from.horizontal, to.horizontal), std::cmp::max(from.horizontal, to.horizontal));
+    if from.horizontal == to.horizontal || horizontal < low || horizontal > high { return None }
+
+    // Coordinates can be anywhere in the chart, their differences need a wider type
+    let (from_horizontal, from_depth) = (from.horizontal as i128, from.depth as i128);
+    let depth = from_depth + (to.depth as i128 - from_depth) * (horizontal as i128 - from_horizontal) / (to.horizontal as i128 - from_horizontal);
+    return CoordinateUnit::try_from(depth).ok();
+}
+
+// ================================================== IMPLEMENTATIONS ==================================================
+
+impl NavigationState {
+    pub fn surface() -> NavigationState {
+        NavigationState {
+            position:   Position{horizontal: 0, depth: 0},
+            aim:        0,
+            heading:    Heading::Ahead,
         }
     }
 
-    pub fn get_last_position(&self) -> Option<&Position> {
-        return self.positions_traveled.last();
+    /// Moves forward, or back for negative distances, along the heading.
+    fn advance(&mut self, distance: CoordinateUnit) -> AocResult<()> {
+        let step = in_chart(self.heading.sign().checked_mul(distance))?;
+        self.position.horizontal = in_chart(self.position.horizontal.checked_add(step))?;
+        return Ok(());
     }
 }
 
-impl AimedSubmarine {
-    pub fn new() -> AimedSubmarine {
-        AimedSubmarine {
-            start_position:     Position{horizontal: 0, depth: 0},
-            aim:                0,
-            positions_traveled: Vec::new(),
+impl fmt::Display for NavigationState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{},{},{},{}", self.position.horizontal, self.position.depth, self.aim, self.heading);
+    }
+}
+
+impl std::str::FromStr for NavigationState {
+    type Err = AocError;
+
+    /// A state saved as `horizontal,depth,aim`, with an optional heading at the end.
+    fn from_str(text: &str) -> AocResult<NavigationState> {
+
+        let fields : Vec<&str> = text.trim().split(',').map(|field| field.trim()).collect();
+        if fields.len() != 3 && fields.len() != 4 {
+            return Err(AocError::malformed(&format!("expected 'horizontal,depth,aim[,heading]' but got '{}'", text)));
         }
+
+        let values : Vec<CoordinateUnit> = fields[..3].iter()
+            .map(|field| field.parse().map_err(|_| AocError::malformed(&format!("invalid coordinate '{}'", field))))
+            .collect::<AocResult<_>>()?;
+        let heading = match fields.get(3) {
+            None | Some(&"ahead") => Heading::Ahead,
+            Some(&"astern") => Heading::Astern,
+            Some(heading) => return Err(AocError::malformed(&format!("unknown heading '{}', expected ahead or astern", heading))),
+        };
+
+        Ok(NavigationState {
+            position:   Position{horizontal: values[0], depth: values[1]},
+            aim:        values[2],
+            heading:    heading,
+        })
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Heading::Ahead => write!(f, "ahead"),
+            Heading::Astern => write!(f, "astern"),
+        };
+    }
+}
+
+impl Heading {
+
+    pub fn sign(&self) -> CoordinateUnit {
+        return match self {
+            Heading::Ahead => 1,
+            Heading::Astern => -1,
+        };
+    }
+
+    pub fn turned(&self) -> Heading {
+        return match self {
+            Heading::Ahead => Heading::Astern,
+            Heading::Astern => Heading::Ahead,
+        };
     }
+}
 
-    pub fn update_chart(&mut self, lines: Vec<String>) {
+impl NavigationModel for DirectModel {
+    fn apply(&mut self, state: &mut NavigationState, (code, value): Command) -> AocResult<()> {
 
-        self.positions_traveled = vec!(self.start_position);
-        
-        for line in lines.into_iter() {
+        match code {
+            MovementCode::Up => state.position.depth = in_chart(state.position.depth.checked_sub(value))?,
+            MovementCode::Forward => state.advance(value)?,
+            MovementCode::Back => state.advance(in_chart(value.checked_neg())?)?,
+            MovementCode::Down => state.position.depth = in_chart(state.position.depth.checked_add(value))?,
+            MovementCode::Turn => state.heading = state.heading.turned(),
+        };
 
-            let line_split: Vec<&str> = line.split(' ').collect();
-            let code = line_split[0];
-            let value: CoordinateUnit = line_split[1].parse().unwrap();
+        return Ok(());
+    }
+}
 
-            let last_position = self.positions_traveled.last().unwrap();
+impl NavigationModel for AimedModel {
+    fn apply(&mut self, state: &mut NavigationState, (code, value): Command) -> AocResult<()> {
 
-            let code_converted = convert_str_code(code);
+        match code {
+            MovementCode::Up => state.aim = in_chart(state.aim.checked_sub(value))?,
+            MovementCode::Down => state.aim = in_chart(state.aim.checked_add(value))?,
+            MovementCode::Forward => {
+                state.position.depth = in_chart(state.aim.checked_mul(value).and_then(|dive| state.position.depth.checked_add(dive)))?;
+                state.advance(value)?;
+            },
+            // Backs out along the aim, undoing a forward of the same value
+            MovementCode::Back => {
+                state.position.depth = in_chart(state.aim.checked_mul(value).and_then(|dive| state.position.depth.checked_sub(dive)))?;
+                state.advance(in_chart(value.checked_neg())?)?;
+            },
+            MovementCode::Turn => state.heading = state.heading.turned(),
+        };
 
-            match code_converted {
-                MovementCode::Up => self.aim = self.aim - value,
-                MovementCode::Down => self.aim = self.aim + value,
-                MovementCode::Forward => {
-                    let new_position = Position{horizontal: last_position.horizontal + value, depth: last_position.depth + self.aim * value};
-                    self.positions_traveled.push(new_position)
+        return Ok(());
+    }
+}
+
+impl<'a> CourseParser<'a> {
+
+    /// Commands until the brace at `opening` is closed, or until the end of the course for the outermost block.
+    fn parse_block(&mut self, opening: Option<Token>, depth: usize) -> Result<Vec<Command>, InputError> {
+
+        let mut commands : Vec<Command> = Vec::new();
+        loop {
+
+            let token = match self.tokens.get(self.next) {
+                Some(&token) => token,
+                None => return match opening {
+                    Some(brace) => Err(error_at(brace, "'{' is never closed")),
+                    None => Ok(commands),
                 },
             };
+            self.next = self.next + 1;
+
+            match token.text {
+                "}" if opening.is_some() => return Ok(commands),
+                "}" => return Err(error_at(token, "'}' closes no repeat")),
+                "{" => return Err(error_at(token, "'{' can only open the body of a repeat")),
+                "repeat" => {
+
+                    if depth >= MAX_NESTING { return Err(error_at(token, &format!("repeats are nested more than {} times", MAX_NESTING))) }
+                    let times : usize = self.parse_value(token)?;
+                    let brace = match self.tokens.get(self.next) {
+                        Some(&brace) if brace.text == "{" => brace,
+                        _ => return Err(error_at(token, "expected '{' after the number of repeats")),
+                    };
+                    self.next = self.next + 1;
+
+                    let body = self.parse_block(Some(brace), depth + 1)?;
+                    let length = body.len().checked_mul(times).and_then(|length| length.checked_add(commands.len()));
+                    if length.is_none_or(|length| length > MAX_COMMANDS) {
+                        return Err(error_at(token, &format!("the course is longer than {} commands", MAX_COMMANDS)));
+                    }
+                    for _ in 0..times { commands.extend_from_slice(&body) }
+                },
+                word => {
+
+                    let code = convert_str_code(word).map_err(|_| error_at(token, &format!("unknown command '{}'", word)))?;
+                    let value = if code == MovementCode::Turn { 0 } else { self.parse_value(token)? };
+                    if commands.len() >= MAX_COMMANDS {
+                        return Err(error_at(token, &format!("the course is longer than {} commands", MAX_COMMANDS)));
+                    }
+                    commands.push((code, value));
+                },
+            };
+        }
+    }
+
+    /// The value after `keyword`, it must be on the same line.
+    fn parse_value<T: FromStr<Err = ParseIntError>>(&mut self, keyword: Token) -> Result<T, InputError> {
+        return match self.tokens.get(self.next) {
+            Some(&token) if token.line == keyword.line && token.text != "{" && token.text != "}" => {
+                self.next = self.next + 1;
+                aoc_input::parse_value(token.text, token.line, token.column)
+            },
+            _ => Err(error_at(keyword, &format!("'{}' expects a value", keyword.text))),
+        };
+    }
+}
+
+impl<M: NavigationModel + Default> Navigator<M> {
+    pub fn new() -> Navigator<M> { Navigator::with_model(M::default()) }
+}
+
+impl<M: NavigationModel> Navigator<M> {
+
+    /// A submarine on the surface, for models that need to be configured first.
+    pub fn with_model(model: M) -> Navigator<M> {
+        Navigator {
+            model:              model.clone(),
+            start_model:        model,
+            start_state:        NavigationState::surface(),
+            positions_traveled: Vec::new(),
+            state:              NavigationState::surface(),
+        }
+    }
+
+    /// Follows the lines of a course from the start, they are lines like 'forward 5'.
+    pub fn update_chart(&mut self, lines: Vec<String>) -> AocResult<()> {
+        return self.follow(&convert_lines_to_commands(&lines)?);
+    }
+
+    /// Follows the commands from the start, recording the position after every command that moved the submarine.
+    pub fn follow(&mut self, commands: &[Command]) -> AocResult<()> {
+
+        self.model = self.start_model.clone();
+        self.state = self.start_state;
+        self.positions_traveled = vec!(self.state.position);
+        for &command in commands.iter() {
+            self.model.apply(&mut self.state, command)?;
+            // Aiming and turning leave the submarine where it was
+            if self.positions_traveled.last() != Some(&self.state.position) { self.positions_traveled.push(self.state.position) }
         }
+
+        return Ok(());
+    }
+
+    /// Courses followed from now on start from `state`, like one saved from an earlier course.
+    pub fn resume_from(&mut self, state: NavigationState) {
+        self.start_state = state;
+        self.state = state;
+        self.positions_traveled = vec!(state.position);
     }
 
+    pub fn get_model(&self) -> &M { &self.model }
+    pub fn get_state(&self) -> NavigationState { self.state }
+    pub fn get_positions(&self) -> &[Position] { &self.positions_traveled }
+
     pub fn get_last_position(&self) -> Option<&Position> {
         return self.positions_traveled.last();
     }
-}
\ No newline at end of file
+
+    pub fn max_depth(&self) -> Option<CoordinateUnit> {
+        return self.positions_traveled.iter().map(|position| position.depth).max();
+    }
+
+    /// Depth the first time the path reaches `horizontal`, between two positions it is on the line joining them.
+    pub fn depth_at(&self, horizontal: CoordinateUnit) -> Option<CoordinateUnit> {
+
+        let start = self.positions_traveled.first()?;
+        if start.horizontal == horizontal { return Some(start.depth) }
+        return self.positions_traveled.windows(2)
+            .find_map(|positions| interpolate_depth(positions[0], positions[1], horizontal));
+    }
+
+    /// Length of the whole path, diving while moving forward goes diagonally.
+    pub fn total_distance(&self) -> f64 {
+        return self.positions_traveled.windows(2)
+            .map(|positions| ((positions[1].horizontal - positions[0].horizontal) as f64).hypot((positions[1].depth - positions[0].depth) as f64))
+            .sum();
+    }
+
+    /// One row per position, the first one is where the course started.
+    pub fn write_csv(&self, out: &mut dyn Write) -> std::io::Result<()> {
+
+        writeln!(out, "step,horizontal,depth")?;
+        for (step, position) in self.positions_traveled.iter().enumerate() {
+            writeln!(out, "{},{},{}", step, position.horizontal, position.depth)?;
+        }
+        return Ok(());
+    }
+
+    /// The path seen from the side, depth grows downwards like in the picture and the surface is a grey line.
+    pub fn write_svg(&self, out: &mut dyn Write) -> std::io::Result<()> {
+
+        let horizontals = self.positions_traveled.iter().map(|position| position.horizontal as f64);
+        let depths = self.positions_traveled.iter().map(|position| position.depth as f64);
+        let (left, right) = horizontals.fold((0.0, 0.0), |(low, high) : (f64, f64), horizontal| (low.min(horizontal), high.max(horizontal)));
+        let (top, bottom) = depths.fold((0.0, 0.0), |(low, high) : (f64, f64), depth| (low.min(depth), high.max(depth)));
+        let margin = ((right - left).max(bottom - top) / 20.0).max(1.0);
+
+        writeln!(out, "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">",
+            left - margin, top - margin, right - left + 2.0 * margin, bottom - top + 2.0 * margin)?;
+        writeln!(out, "  <line x1=\"{}\" y1=\"0\" x2=\"{}\" y2=\"0\" stroke=\"lightgrey\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+            left - margin, right + margin)?;
+
+        let points : Vec<String> = self.positions_traveled.iter()
+            .map(|position| format!("{},{}", position.horizontal, position.depth))
+            .collect();
+        writeln!(out, "  <polyline points=\"{}\" fill=\"none\" stroke=\"steelblue\" stroke-width=\"2\" vector-effect=\"non-scaling-stroke\"/>", points.join(" "))?;
+        return writeln!(out, "</svg>");
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Both parts multiply the final horizontal position by the final depth.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Answer1 = CoordinateUnit;
+    type Answer2 = CoordinateUnit;
+
+    fn parse(input: &str) -> Result<Vec<Command>, InputError> {
+        return parse_course(input);
+    }
+
+    fn part1(commands: &Vec<Command>) -> AocResult<CoordinateUnit> {
+        let mut submarine = Submarine::new();
+        submarine.follow(commands)?;
+        let last_position = submarine.get_last_position().unwrap();
+        return in_chart(last_position.horizontal.checked_mul(last_position.depth));
+    }
+
+    fn part2(commands: &Vec<Command>) -> AocResult<CoordinateUnit> {
+        let mut submarine = AimedSubmarine::new();
+        submarine.follow(commands)?;
+        let last_position = submarine.get_last_position().unwrap();
+        return in_chart(last_position.horizontal.checked_mul(last_position.depth));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn converts_movement_codes() {
+        assert!(matches!(convert_str_code("up"), Ok(MovementCode::Up)));
+        assert!(matches!(convert_str_code("down"), Ok(MovementCode::Down)));
+        assert!(matches!(convert_str_code("forward"), Ok(MovementCode::Forward)));
+        assert!(matches!(convert_str_code("back"), Ok(MovementCode::Back)));
+        assert!(matches!(convert_str_code("turn"), Ok(MovementCode::Turn)));
+        assert!(convert_str_code("backward").is_err());
+    }
+
+    #[test]
+    fn submarines_start_at_surface() {
+        let mut submarine = Submarine::new();
+        submarine.update_chart(Vec::new()).unwrap();
+        let last_position = submarine.get_last_position().unwrap();
+        assert_eq!((last_position.horizontal, last_position.depth), (0, 0));
+    }
+
+    #[test]
+    fn aimed_submarine_only_moves_forward() {
+        let mut submarine = AimedSubmarine::new();
+        submarine.update_chart(vec!("down 5".to_owned(), "forward 2".to_owned(), "up 1".to_owned())).unwrap();
+        let last_position = submarine.get_last_position().unwrap();
+        assert_eq!((last_position.horizontal, last_position.depth), (2, 10));
+    }
+
+    #[test]
+    fn leaving_the_chart_is_unsolvable() {
+        let lines = vec!(format!("down {}", CoordinateUnit::MAX), "forward 2".to_owned());
+        assert!(matches!(AimedSubmarine::new().update_chart(lines), Err(AocError::Unsolvable(_))));
+        let commands = vec!((MovementCode::Down, CoordinateUnit::MAX), (MovementCode::Down, 1));
+        assert!(matches!(Day02::part1(&commands), Err(AocError::Unsolvable(_))));
+    }
+
+    #[test]
+    fn courses_expand_their_repeats() {
+        let course = "# A square, twice\n\nrepeat 2 {\n    repeat 2 { forward 3 down 1 }  # one side\n    back 1\n}\nturn\n";
+        let commands = parse_course(course).unwrap();
+        assert_eq!(commands.len(), 11);
+        assert_eq!(commands[..5], [(MovementCode::Forward, 3), (MovementCode::Down, 1), (MovementCode::Forward, 3), (MovementCode::Down, 1), (MovementCode::Back, 1)]);
+        assert_eq!(commands[10], (MovementCode::Turn, 0));
+    }
+
+    #[test]
+    fn course_errors_point_at_the_mistake() {
+        let position = |course: &str| parse_course(course).map(|_| ()).map_err(|error| (error.line, error.column));
+        assert_eq!(position("forward 5\n  sideways 2\n"), Err((Some(2), Some(3))));
+        assert_eq!(position("forward\n5\n"), Err((Some(1), Some(1))));
+        assert_eq!(position("down 1\nup x2"), Err((Some(2), Some(4))));
+        assert_eq!(position("repeat 2 {\n  forward 1\n"), Err((Some(1), Some(10))));
+        assert_eq!(position("forward 1 }"), Err((Some(1), Some(11))));
+        assert_eq!(position("repeat 1000 { repeat 1000 { repeat 1000 { up 1 } } }"), Err((Some(1), Some(1))));
+        assert!(parse_course("# nothing but comments\n\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn turning_swaps_forward_and_back() {
+        let commands = parse_course("forward 5\nturn\nforward 2\nback 4\nturn\nback 1").unwrap();
+        let mut submarine = Submarine::new();
+        submarine.follow(&commands).unwrap();
+        assert_eq!(submarine.get_last_position(), Some(&Position { horizontal: 6, depth: 0 }));
+        assert_eq!(submarine.get_state().heading, Heading::Ahead);
+    }
+
+    #[test]
+    fn paths_can_be_queried() {
+        let mut submarine = AimedSubmarine::new();
+        submarine.follow(&parse_course(include_str!("../../inputs/day02.example.txt")).unwrap()).unwrap();
+        assert_eq!(submarine.get_positions().len(), 4);
+        assert_eq!(submarine.max_depth(), Some(60));
+        assert_eq!(submarine.depth_at(5), Some(0));
+        assert_eq!(submarine.depth_at(9), Some(20));
+        assert_eq!(submarine.depth_at(16), None);
+        assert!((submarine.total_distance() - (5.0 + 8.0f64.hypot(40.0) + 2.0f64.hypot(20.0))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn paths_are_exported() {
+        let mut submarine = Submarine::new();
+        submarine.follow(&parse_course("forward 2\ndown 3").unwrap()).unwrap();
+
+        let mut csv : Vec<u8> = Vec::new();
+        submarine.write_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "step,horizontal,depth\n0,0,0\n1,2,0\n2,2,3\n");
+
+        let mut svg : Vec<u8> = Vec::new();
+        submarine.write_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("points=\"0,0 2,0 2,3\""));
+    }
+
+    #[test]
+    fn courses_resume_from_a_saved_state() {
+        let commands = parse_course(include_str!("../../inputs/day02.example.txt")).unwrap();
+        let mut whole = AimedSubmarine::new();
+        whole.follow(&commands).unwrap();
+
+        let mut first_half = AimedSubmarine::new();
+        first_half.follow(&commands[..3]).unwrap();
+        let saved = first_half.get_state().to_string();
+        let mut second_half = AimedSubmarine::new();
+        second_half.resume_from(saved.parse().unwrap());
+        second_half.follow(&commands[3..]).unwrap();
+
+        assert_eq!(second_half.get_state(), whole.get_state());
+        assert_eq!(second_half.get_positions().first(), first_half.get_last_position());
+        assert!("1,2".parse::<NavigationState>().is_err());
+        assert_eq!("1,2,3".parse::<NavigationState>().unwrap().heading, Heading::Ahead);
+        assert!("1,2,3,sideways".parse::<NavigationState>().is_err());
+    }
+
+    #[test]
+    fn aimed_submarine_backs_out_along_its_aim() {
+        let mut submarine = AimedSubmarine::new();
+        submarine.follow(&parse_course("down 2\nforward 5\nback 3").unwrap()).unwrap();
+        assert_eq!(submarine.get_last_position(), Some(&Position { horizontal: 2, depth: 4 }));
+    }
+
+    /// Keeps part of its speed from one forward command to the next.
+    #[derive(Clone, Default)]
+    struct MomentumModel { speed: CoordinateUnit }
+
+    impl NavigationModel for MomentumModel {
+        fn apply(&mut self, state: &mut NavigationState, (code, value): Command) -> AocResult<()> {
+            if code != MovementCode::Forward { return DirectModel.apply(state, (code, value)) }
+            self.speed = self.speed / 2 + value;
+            return DirectModel.apply(state, (code, self.speed));
+        }
+    }
+
+    #[test]
+    fn new_models_reuse_the_traversal() {
+        let mut submarine : Navigator<MomentumModel> = Navigator::new();
+        submarine.update_chart(vec!("forward 4".to_owned(), "down 1".to_owned(), "forward 4".to_owned())).unwrap();
+        assert_eq!(submarine.get_last_position(), Some(&Position { horizontal: 10, depth: 1 }));
+        assert_eq!(submarine.get_model().speed, 6);
+
+        // The speed left by the first course is not carried into the next one
+        submarine.update_chart(vec!("forward 4".to_owned())).unwrap();
+        assert_eq!(submarine.get_last_position(), Some(&Position { horizontal: 4, depth: 0 }));
+    }
+
+    #[test]
+    fn aiming_does_not_add_points_to_the_path() {
+        let mut submarine = AimedSubmarine::new();
+        submarine.follow(&parse_course("down 5\nforward 2\nup 1\ndown 3\nturn\nforward 1").unwrap()).unwrap();
+        assert_eq!(submarine.get_positions(), &[Position { horizontal: 0, depth: 0 }, Position { horizontal: 2, depth: 10 }, Position { horizontal: 1, depth: 17 }]);
+    }
+}
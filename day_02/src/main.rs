@@ -1,26 +1,82 @@
-mod read;
-mod lib;
+use aoc_common::{AocError, AocResult};
+use day_02::{Command, CoordinateUnit, NavigationModel, NavigationState, Navigator, Submarine, AimedSubmarine};
 
-use lib::Submarine;
-use lib::AimedSubmarine;
+/// Value given after `--name`, if the option is there at all.
+fn option_value<'a>(arguments: &'a [String], name: &str) -> Result<Option<&'a str>, String> {
+    return match arguments.iter().position(|argument| argument == name) {
+        None => Ok(None),
+        Some(index) => arguments.get(index + 1).map(|value| Some(value.as_str())).ok_or(format!("{} needs a value", name)),
+    };
+}
 
-fn main() {
+fn export<M: NavigationModel>(submarine: &Navigator<M>, arguments: &[String]) -> Result<(), String> {
+
+    if let Some(file) = option_value(arguments, "--csv")? {
+        let mut out = std::fs::File::create(file).map_err(|error| format!("{}: {}", file, error))?;
+        submarine.write_csv(&mut out).map_err(|error| format!("{}: {}", file, error))?;
+    }
+    if let Some(file) = option_value(arguments, "--svg")? {
+        let mut out = std::fs::File::create(file).map_err(|error| format!("{}: {}", file, error))?;
+        submarine.write_svg(&mut out).map_err(|error| format!("{}: {}", file, error))?;
+    }
+    return Ok(());
+}
+
+/// Follows the course and prints where it led, the submarine is given back for the exports.
+fn report<M: NavigationModel>(name: &str, part: u8, mut submarine: Navigator<M>, commands: &[Command], arguments: &[String]) -> AocResult<Navigator<M>> {
 
-    let data = read::read_lines("input.txt".to_owned());
-    let mut submarine : Submarine = Submarine::new();
-    let mut aimed_submarine : AimedSubmarine = AimedSubmarine::new();
+    let option = |name: &str| option_value(arguments, name).map_err(|error| AocError::malformed(&error));
+    if let Some(state) = option("--from")? {
+        submarine.resume_from(state.parse::<NavigationState>()?);
+    }
+    submarine.follow(commands)?;
 
-    // Part 1
-    submarine.update_chart(data.clone());
     let last_position = submarine.get_last_position().unwrap();
-    println!("Last position for submarine: (horizontal: {}, depth: {})", last_position.horizontal, last_position.depth);
-    println!("Result (Part 1): {}", last_position.horizontal * last_position.depth);
+    let result = day_02::in_chart(last_position.horizontal.checked_mul(last_position.depth))?;
+    println!("🚢  Last position for {}: (horizontal: {}, depth: {})", name, last_position.horizontal, last_position.depth);
+    println!("📈  Deepest point at {}, {:.1} traveled", submarine.max_depth().unwrap(), submarine.total_distance());
+    if let Some(horizontal) = option("--depth-at")? {
+        let horizontal : CoordinateUnit = horizontal.parse().map_err(|_| AocError::malformed(&format!("'{}' is not a horizontal position", horizontal)))?;
+        match submarine.depth_at(horizontal) {
+            Some(depth) => println!("📈  Depth '{}' when reaching horizontal '{}'", depth, horizontal),
+            None => println!("📈  Horizontal '{}' is never reached", horizontal),
+        }
+    }
+    println!("💾  Final state, to resume with --from: {}", submarine.get_state());
+    println!("🚢  The result is '{}' (Part {})", result, part);
+    return Ok(submarine);
+}
+
+fn main() {
+
+    // `[COURSE] [--from STATE] [--depth-at H] [--model direct|aimed] [--csv FILE] [--svg FILE]` follows any course file
+    // instead of the puzzle input, optionally from a saved state, and exports the path of one of the submarines
+    let arguments : Vec<String> = std::env::args().skip(1).collect();
+    let course = match arguments.first() {
+        Some(argument) if !argument.starts_with("--") => argument.as_str(),
+        _ => "../inputs/day02.txt",
+    };
+
+    let result = day_02::read_course(course).map_err(|error| error.to_string()).and_then(|commands| {
+
+        let exports_aimed = match option_value(&arguments, "--model")? {
+            Some("direct") => false,
+            Some("aimed") | None => true,
+            Some(model) => return Err(format!("unknown model '{}', expected direct or aimed", model)),
+        };
+
+        // Part 1
+        let submarine : Submarine = report("submarine", 1, Submarine::new(), &commands, &arguments).map_err(|error| error.to_string())?;
+        println!();
+        // Part 2
+        let aimed_submarine : AimedSubmarine = report("aimed submarine", 2, AimedSubmarine::new(), &commands, &arguments)
+            .map_err(|error| error.to_string())?;
 
-    println!();
+        return if exports_aimed { export(&aimed_submarine, &arguments) } else { export(&submarine, &arguments) };
+    });
 
-    // Part 1
-    aimed_submarine.update_chart(data);
-    let last_position = aimed_submarine.get_last_position().unwrap();
-    println!("Last position for aimed submarine: (horizontal: {}, depth: {})", last_position.horizontal, last_position.depth);
-    println!("Result (Part 2): {}", last_position.horizontal * last_position.depth);
+    if let Err(error) = result {
+        eprintln!("🚨  {}", error);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,38 @@
+use aoc_common::Solution;
+use day_02::Day02;
+
+const EXAMPLE : &str = include_str!("../../inputs/day02.example.txt");
+const INPUT : &str = include_str!("../../inputs/day02.txt");
+const COURSE : &str = include_str!("../../inputs/day02.course.txt");
+
+fn parse(input: &str) -> <Day02 as Solution>::Input { Day02::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day02::part1(&parse(EXAMPLE)).unwrap(), 150);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day02::part2(&parse(EXAMPLE)).unwrap(), 900);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day02::part1(&parse(INPUT)).unwrap(), 2039912);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day02::part2(&parse(INPUT)).unwrap(), 1942068080);
+}
+
+#[test]
+fn course_part1() {
+    assert_eq!(Day02::part1(&parse(COURSE)).unwrap(), 740);
+}
+
+#[test]
+fn course_part2() {
+    assert_eq!(Day02::part2(&parse(COURSE)).unwrap(), 51320);
+}
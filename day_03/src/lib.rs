@@ -1,18 +1,27 @@
+//! Day 3: Binary Diagnostic, reading the power and life support ratings off the report.
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
+// Rates are kept as positive i64 values
+const MAX_BITS : usize = 63;
+
 struct InfoPosition {
     zeros:      usize,
     ones:       usize,
 }
 
+/// A rating both as the binary number of the report and as its value.
 #[derive(Clone)]
 pub struct Rate {
     pub binary:     String,
     pub decimal:    i64
 }
 
+/// The diagnostic report, every line a binary number of the same size.
 pub struct Diagnostic {
     lines:          Vec<String>,
     gamma_rate:     Option<Rate>,
@@ -36,8 +45,8 @@ fn calculate_position_map(lines: &Vec<String>) -> HashMap<usize, InfoPosition> {
                 Some(current_info) if characther == '1' => current_info.ones += 1,
                 None if characther == '0' => { position_map.insert(index_position, InfoPosition{ zeros: 1, ones: 0 }); },
                 None if characther == '1' => { position_map.insert(index_position, InfoPosition{ zeros: 0, ones: 1 }); },
-                Some(_) => panic!("Characther not expected!"), 
-                None => panic!("Characther not expected!"),
+                // Lines are checked to be binary when the diagnostic is built
+                _ => unreachable!("Characther not expected!"),
             }
         }
     }
@@ -49,8 +58,17 @@ fn calculate_position_map(lines: &Vec<String>) -> HashMap<usize, InfoPosition> {
 
 impl Diagnostic {
 
-    pub fn new(lines: Vec<String>) -> Diagnostic {
-        Diagnostic {
+    pub fn new(lines: Vec<String>) -> AocResult<Diagnostic> {
+
+        if lines.len() == 0 { return Err(AocError::malformed("no diagnostic lines")) }
+        if lines[0].len() > MAX_BITS { return Err(AocError::malformed(&format!("diagnostic numbers cannot have more than {} bits", MAX_BITS))) }
+        for line in lines.iter() {
+            if line.len() != lines[0].len() || line.chars().any(|characther| characther != '0' && characther != '1') {
+                return Err(AocError::malformed(&format!("expected binary numbers of the same size but got '{}'", line)));
+            }
+        }
+
+        Ok(Diagnostic {
             lines:          lines,
             // Combustion
             gamma_rate:     None,
@@ -58,15 +76,15 @@ impl Diagnostic {
             // Life support
             oxygen_rate:    None,
             dyoxide_rate:   None,
-        }
+        })
     }
 
-    pub fn calculate_rates(&mut self) {
+    pub fn calculate_rates(&mut self) -> AocResult<()> {
 
         // Combustion Rates
         self.calculate_combustion_rates();
         // Support Rates
-        self.calculate_support_rates();
+        return self.calculate_support_rates();
     }
 
     fn calculate_combustion_rates(&mut self) {
@@ -93,7 +111,7 @@ impl Diagnostic {
         self.epsilon_rate = Some(Rate{binary: epsilon_string.clone(), decimal: i64::from_str_radix(&epsilon_string, 2).unwrap()});
     }
 
-    fn calculate_support_rates(&mut self) {
+    fn calculate_support_rates(&mut self) -> AocResult<()> {
 
         let mut valid_lines_oxygen = self.lines.clone();
         let mut valid_lines_dyoxide = self.lines.clone();
@@ -101,6 +119,9 @@ impl Diagnostic {
         let mut count_position : usize = 0;
         while valid_lines_oxygen.len() != 1 {
 
+            // Repeated lines can never be told apart
+            if count_position == self.lines[0].len() { return Err(AocError::unsolvable("the diagnostic has repeated lines")) }
+
             let position_map = calculate_position_map(&valid_lines_oxygen);
             let info_position = position_map.get(&count_position).unwrap();
             let mut indexes_to_remove: Vec<usize> = Vec::new();
@@ -118,6 +139,9 @@ impl Diagnostic {
         
         let mut count_position : usize = 0;
         while valid_lines_dyoxide.len() != 1 {
+
+            // Repeated lines can never be told apart
+            if count_position == self.lines[0].len() { return Err(AocError::unsolvable("the diagnostic has repeated lines")) }
             
             let position_map = calculate_position_map(&valid_lines_dyoxide);
             let info_position = position_map.get(&count_position).unwrap();
@@ -130,6 +154,8 @@ impl Diagnostic {
                 }
             }
 
+            // When every line shares the bit the least common one is missing, nothing can be filtered out
+            if indexes_to_remove.len() == valid_lines_dyoxide.len() { indexes_to_remove.clear() }
             for index in indexes_to_remove.into_iter().rev() { valid_lines_dyoxide.remove(index); }
             count_position += 1;
         }
@@ -138,8 +164,10 @@ impl Diagnostic {
         let dyoxide_rate_string = valid_lines_dyoxide.first().unwrap().clone();
         self.oxygen_rate = Some(Rate{binary: oxygen_rate_string.clone(), decimal: i64::from_str_radix(&oxygen_rate_string, 2).unwrap()});
         self.dyoxide_rate = Some(Rate{binary: dyoxide_rate_string.clone(), decimal: i64::from_str_radix(&dyoxide_rate_string, 2).unwrap()});
+        return Ok(());
     }
 
+    /// Gamma and epsilon rates, once calculated.
     pub fn get_combustion_rates(&self) -> Option<(Rate, Rate)> {
 
         return match (self.gamma_rate.clone(), self.epsilon_rate.clone()) {
@@ -148,6 +176,7 @@ impl Diagnostic {
         }
     }
 
+    /// Oxygen generator and CO2 scrubber ratings, once calculated.
     pub fn get_support_rates(&self) -> Option<(Rate, Rate)> {
 
         return match (self.oxygen_rate.clone(), self.dyoxide_rate.clone()) {
@@ -155,4 +184,81 @@ impl Diagnostic {
             (_, _) => None,
         }
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 multiplies the gamma and epsilon rates, part 2 the oxygen and CO2 ratings.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<String>, InputError> {
+
+        let lines = aoc_input::read_lines_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+            if line.len() == 0 || line.chars().any(|characther| characther != '0' && characther != '1') {
+                return Err(InputError::malformed("expected a binary number", index + 1));
+            }
+        }
+
+        return Ok(lines);
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<i64> {
+        let mut diagnostic = Diagnostic::new(lines.clone())?;
+        diagnostic.calculate_rates()?;
+        let (gamma_rate, epsilon_rate) = diagnostic.get_combustion_rates().unwrap();
+        return Ok(gamma_rate.decimal * epsilon_rate.decimal);
+    }
+
+    fn part2(lines: &Vec<String>) -> AocResult<i64> {
+        let mut diagnostic = Diagnostic::new(lines.clone())?;
+        diagnostic.calculate_rates()?;
+        let (oxygen_rate, dyoxide_rate) = diagnostic.get_support_rates().unwrap();
+        return Ok(oxygen_rate.decimal * dyoxide_rate.decimal);
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn counts_bits_per_position() {
+        let position_map = calculate_position_map(&vec!("10".to_owned(), "11".to_owned(), "00".to_owned()));
+        assert_eq!((position_map[&0].zeros, position_map[&0].ones), (1, 2));
+        assert_eq!((position_map[&1].zeros, position_map[&1].ones), (2, 1));
+    }
+
+    #[test]
+    fn repeated_lines_have_no_support_rating() {
+        assert!(matches!(Diagnostic::new(vec!("10".to_owned(), "1".to_owned())), Err(AocError::Input(_))));
+
+        let mut diagnostic = Diagnostic::new(vec!("10".to_owned(), "10".to_owned())).unwrap();
+        assert!(matches!(diagnostic.calculate_rates(), Err(AocError::Unsolvable(_))));
+    }
+
+    #[test]
+    fn rates_must_fit_in_a_value() {
+        assert!(matches!(Diagnostic::new(vec!("1".repeat(64))), Err(AocError::Input(_))));
+        assert!(Diagnostic::new(vec!("1".repeat(63))).unwrap().calculate_rates().is_ok());
+    }
+
+    #[test]
+    fn shared_bits_do_not_empty_the_co2_rating() {
+        // After the first bit '000' and '001' are left, both with a '0' in the middle
+        let lines = vec!("100", "101", "110", "000", "001").into_iter().map(|line| line.to_owned()).collect();
+        let mut diagnostic = Diagnostic::new(lines).unwrap();
+        diagnostic.calculate_rates().unwrap();
+
+        let (oxygen_rate, dyoxide_rate) = diagnostic.get_support_rates().unwrap();
+        assert_eq!((oxygen_rate.decimal, dyoxide_rate.decimal), (5, 0));
+    }
+}
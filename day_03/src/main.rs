@@ -1,13 +1,11 @@
-mod read;
-mod lib;
-
-use lib::Diagnostic;
+use aoc_common::Solution;
+use day_03::{Diagnostic, Day03};
 
 fn main() {
 
-    let data = read::read_lines("input.txt".to_owned());
-    let mut diagnostic = Diagnostic::new(data);
-    diagnostic.calculate_rates();
+    let data = Day03::parse(&aoc_input::read_to_string("../inputs/day03.txt").unwrap()).unwrap();
+    let mut diagnostic = Diagnostic::new(data).unwrap();
+    diagnostic.calculate_rates().unwrap();
 
     // Part 1
     let (gamma_rate, epsilon_rate) = diagnostic.get_combustion_rates().unwrap();
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_03::Day03;
+
+const EXAMPLE : &str = include_str!("../../inputs/day03.example.txt");
+const INPUT : &str = include_str!("../../inputs/day03.txt");
+
+fn parse(input: &str) -> <Day03 as Solution>::Input { Day03::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day03::part1(&parse(EXAMPLE)).unwrap(), 198);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day03::part2(&parse(EXAMPLE)).unwrap(), 230);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day03::part1(&parse(INPUT)).unwrap(), 3847100);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day03::part2(&parse(INPUT)).unwrap(), 4105235);
+}
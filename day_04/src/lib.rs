@@ -1,11 +1,15 @@
+//! Day 4: Giant Squid, playing bingo against a giant squid.
+
 use std::fmt;
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
 type PositionUnit = usize;
-type Number = i64;
-type Score = Number;
+pub type Number = i64;
+pub type Score = Number;
 
 struct BingoSpot {
     number:     Number,
@@ -19,6 +23,7 @@ struct BingoCard  {
     won:            bool,
 }
 
+/// The called numbers and the cards playing them, each card can be of any rectangular size.
 pub struct Game {
     calls:          Vec<Number>,
     called_index:   usize,
@@ -88,7 +93,7 @@ impl BingoCard {
     fn compute_score(&self) -> Option<Score> {
 
         if !self.won { return None; }
-        if self.last_marked.is_none() { return None; }
+        self.last_marked?;
 
         let mut sum_score : Score = 0;
         for (_, spot) in self.slots.iter() {
@@ -122,21 +127,26 @@ impl fmt::Display for BingoCard {
 
 impl Game {
 
-    pub fn new(called_numbers: Vec<Number>, cards_numbers: Vec<Vec<Vec<Number>>>) -> Game {
+    pub fn new(called_numbers: Vec<Number>, cards_numbers: Vec<Vec<Vec<Number>>>) -> AocResult<Game> {
 
         let mut cards : Vec<BingoCard> = Vec::new();
-        for card_numbers in cards_numbers.into_iter() {
+        for (card_index, card_numbers) in cards_numbers.into_iter().enumerate() {
+
+            if card_numbers.len() == 0 || card_numbers.iter().any(|row| row.len() == 0 || row.len() != card_numbers[0].len()) {
+                return Err(AocError::malformed(&format!("card {} is not a rectangle of numbers", card_index + 1)));
+            }
             cards.push(BingoCard::new(card_numbers))
         }
 
-        Game {
+        Ok(Game {
             calls: called_numbers,
             called_index: 0,
             boards: cards,
-        }
+        })
     }
 
-    pub fn do_iterations_until_one_won(&mut self, debug: bool) -> Option<Score> {
+    /// Calls numbers until a card wins and returns its score, every card is traced after each call.
+    pub fn do_iterations_until_one_won(&mut self) -> AocResult<Score> {
 
         let mut board_won_index : Option<usize> = None;
         for call_index in self.called_index..self.calls.len() {
@@ -144,31 +154,26 @@ impl Game {
             let call = self.calls[call_index];
             self.called_index = call_index + 1;
 
-            if debug {
-                println!("================================ CALLING {} ================================", call);
-                println!();
-            }
+            log::debug!("🎱  Calling {}", call);
 
             for (board_index, board) in self.boards.iter_mut().enumerate() {
 
                 board.mark_number(call);
 
-                if debug {
-                    println!("{}", board);
-                    println!();
-                }
+                log::trace!("\n{}", board);
 
                 board.verify_won();
                 if board.won { board_won_index = Some(board_index) }
             }
 
-            if board_won_index.is_some() { return self.boards[board_won_index.unwrap()].compute_score() }
+            if board_won_index.is_some() { return self.boards[board_won_index.unwrap()].compute_score().ok_or_else(|| AocError::unsolvable("the winning card has no score")) }
         }
 
-        return None;
+        return Err(AocError::unsolvable("no card wins with the called numbers"));
     }
 
-    pub fn do_iterations_until_all_but_one_won(&mut self, debug: bool) -> Option<Score> {
+    /// Calls numbers until the last card left wins and returns its score.
+    pub fn do_iterations_until_all_but_one_won(&mut self) -> AocResult<Score> {
 
         let mut last_to_win : Option<usize> = None;
         for call_index in self.called_index..self.calls.len() {
@@ -176,10 +181,7 @@ impl Game {
             let call = self.calls[call_index];
             self.called_index = call_index + 1;
 
-            if debug {
-                println!("================================ CALLING {} ================================", call);
-                println!();
-            }
+            log::debug!("🎱  Calling {}", call);
 
             let mut board_not_won_indexes : Vec<usize> = Vec::new();
 
@@ -187,19 +189,100 @@ impl Game {
 
                 board.mark_number(call);
 
-                if debug {
-                    println!("{}", board);
-                    println!();
-                }
+                log::trace!("\n{}", board);
 
                 board.verify_won();
                 if !board.won && last_to_win.is_none() { board_not_won_indexes.push(board_index); }
-                if board.won && last_to_win.is_some() && board_index == last_to_win.unwrap() { return board.compute_score(); }
+                if board.won && last_to_win.is_some() && board_index == last_to_win.unwrap() { return board.compute_score().ok_or_else(|| AocError::unsolvable("the last card has no score")); }
             }
 
             if board_not_won_indexes.len() == 1 { last_to_win = Some(board_not_won_indexes[0]); }
         }
 
-        return None;
+        return Err(AocError::unsolvable("not every card wins with the called numbers"));
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 scores the first card to win, part 2 the last one.
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = (Vec<Number>, Vec<Vec<Vec<Number>>>);
+    type Answer1 = Score;
+    type Answer2 = Score;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let mut lines = aoc_input::lines(input).enumerate();
+        let called_numbers : Vec<Number> = match lines.next() {
+            Some((index, line)) => aoc_input::parse_list(line, ",", index + 1)?,
+            None => return Err(InputError::malformed("missing called numbers", 1)),
+        };
+
+        let mut cards_numbers : Vec<Vec<Vec<Number>>> = Vec::new();
+        let mut card_numbers : Vec<Vec<Number>> = Vec::new();
+        for (index, line) in lines {
+
+            if line.trim() == "" {
+                if card_numbers.len() != 0 { cards_numbers.push(card_numbers) }
+                card_numbers = Vec::new();
+            } else {
+                card_numbers.push(aoc_input::parse_whitespace_list(line, index + 1)?);
+            }
+        }
+        if card_numbers.len() != 0 { cards_numbers.push(card_numbers) }
+
+        return Ok((called_numbers, cards_numbers));
     }
-}
\ No newline at end of file
+
+    fn part1(input: &Self::Input) -> AocResult<Score> {
+        let mut game = Game::new(input.0.clone(), input.1.clone())?;
+        return game.do_iterations_until_one_won();
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Score> {
+        let mut game = Game::new(input.0.clone(), input.1.clone())?;
+        return game.do_iterations_until_all_but_one_won();
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn card_wins_by_row_and_column() {
+        let mut card = BingoCard::new(vec!(vec!(1, 2), vec!(3, 4)));
+        card.mark_number(1);
+        card.verify_won();
+        assert!(!card.won);
+
+        card.mark_number(3);
+        card.verify_won();
+        assert!(card.won);
+    }
+
+    #[test]
+    fn card_score_uses_unmarked_numbers_and_last_call() {
+        let mut card = BingoCard::new(vec!(vec!(1, 2), vec!(3, 4)));
+        assert_eq!(card.compute_score(), None);
+
+        card.mark_number(2);
+        card.mark_number(4);
+        card.verify_won();
+        assert_eq!(card.compute_score(), Some((1 + 3) * 4));
+    }
+
+    #[test]
+    fn game_without_winner_is_unsolvable() {
+        assert!(matches!(Game::new(vec!(1), vec!(vec!(vec!(1, 2), vec!(3)))), Err(AocError::Input(_))));
+
+        let mut game = Game::new(vec!(1, 4), vec!(vec!(vec!(1, 2), vec!(3, 4)))).unwrap();
+        assert!(matches!(game.do_iterations_until_one_won(), Err(AocError::Unsolvable(_))));
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_04::Day04;
+
+const EXAMPLE : &str = include_str!("../../inputs/day04.example.txt");
+const INPUT : &str = include_str!("../../inputs/day04.txt");
+
+fn parse(input: &str) -> <Day04 as Solution>::Input { Day04::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day04::part1(&parse(EXAMPLE)).unwrap(), 4512);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day04::part2(&parse(EXAMPLE)).unwrap(), 1924);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day04::part1(&parse(INPUT)).unwrap(), 58838);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day04::part2(&parse(INPUT)).unwrap(), 6256);
+}
@@ -1,10 +1,14 @@
+//! Day 5: Hydrothermal Venture, finding where the lines of vents overlap.
+
 use gcd::Gcd;
 use std::fmt;
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
-type CoordinateUnit = i64;
+pub type CoordinateUnit = i64;
 
 #[derive(Clone)]
 struct Point {
@@ -14,6 +18,7 @@ struct Point {
 
 #[derive(PartialEq)]
 enum LineType { Invalid, Horizontal, Vertical, Diagonal }
+/// A line of vents from one point to another, both ends included.
 #[derive(Clone)]
 pub struct Line {
     point1:         Point,
@@ -21,6 +26,7 @@ pub struct Line {
 }
 
 type PointInfo = Vec<usize>;
+/// The ocean floor, counting how many lines go through every point it has marked.
 pub struct Map {
     diagonal:       bool,
     lines:          Vec<Line>,
@@ -28,6 +34,9 @@ pub struct Map {
     grid:           HashMap<(usize, usize), PointInfo>
 }
 
+/// Official floors stay under 1000 points wide, a line across a far bigger one would not fit in memory.
+pub const MAX_COORDINATE : CoordinateUnit = 10_000;
+
 // ================================================== AUX FUNCTIONS ==================================================
 
 // ================================================== IMPLEMENTATIONS ==================================================
@@ -82,7 +91,7 @@ impl Line {
 
         // Diagonal
         let mut diff : Point = Point{x: self.point2.x - self.point1.x, y: self.point2.y - self.point1.y};
-        let gcd = (diff.x.abs() as u64).gcd(diff.y.abs() as u64);
+        let gcd = diff.x.unsigned_abs().gcd(diff.y.unsigned_abs());
         diff.x = diff.x / gcd as CoordinateUnit;
         diff.y = diff.y / gcd as CoordinateUnit;
 
@@ -101,29 +110,30 @@ impl Line {
 
 impl Map {
 
-    pub fn new(lines: Vec<Line>, count_diagonal: bool) -> Map {
+    /// A floor as big as the furthest vent, diagonal lines are ignored unless `count_diagonal`.
+    ///
+    /// Every coordinate must lie in `0..=MAX_COORDINATE`, the error points at the first line that does not.
+    pub fn new(lines: Vec<Line>, count_diagonal: bool) -> AocResult<Map> {
 
         let (mut max_x, mut max_y) = (0, 0);
-        for line in lines.iter() {
-            max_x = std::cmp::max(max_x, line.point1.x);
-            max_x = std::cmp::max(max_x, line.point2.x);
-            max_y = std::cmp::max(max_y, line.point1.y);
-            max_y = std::cmp::max(max_y, line.point2.y);
-        }
+        for (line_index, line) in lines.iter().enumerate() {
 
-        let mut grid : HashMap<(usize, usize), PointInfo> = HashMap::new();
-        for x_value in 0..(max_x + 1) {
-            for y_value in 0..(max_y + 1) {
-                grid.insert((x_value as usize, y_value as usize), PointInfo::new());
+            let coordinates = [line.point1.x, line.point1.y, line.point2.x, line.point2.y];
+            if coordinates.iter().any(|coordinate| !(0..=MAX_COORDINATE).contains(coordinate)) {
+                let message = format!("coordinates must lie between 0 and {}", MAX_COORDINATE);
+                return Err(AocError::Input(InputError::malformed(&message, line_index + 1)));
             }
+
+            max_x = std::cmp::max(max_x, std::cmp::max(line.point1.x, line.point2.x));
+            max_y = std::cmp::max(max_y, std::cmp::max(line.point1.y, line.point2.y));
         }
 
-        Map {
+        Ok(Map {
             diagonal: count_diagonal,
             lines: lines,
             grid_size: (max_x as usize, max_y as usize),
-            grid: grid,
-        }
+            grid: HashMap::new(),
+        })
     }
 
     pub fn mark_lines(&mut self) {
@@ -136,12 +146,12 @@ impl Map {
             let points = line.get_points();
             for point in points.into_iter() {
                 
-                let point_info = self.grid.get_mut(&(point.x as usize, point.y as usize)).unwrap();
-                point_info.push(line_index);
+                self.grid.entry((point.x as usize, point.y as usize)).or_default().push(line_index);
             }
         }
     }
 
+    /// Number of points with at least `threshold` lines going through them.
     pub fn count_positions_with_me(&self, threshold: usize) -> usize {
 
         let mut count : usize = 0;
@@ -161,9 +171,10 @@ impl fmt::Display for Map {
 
             for x_value in 0..(self.grid_size.0 + 1) {
 
-                let info = self.grid.get(&(x_value, y_value)).unwrap();
-                if info.len() == 0 { line = format!("{} .", line) }
-                else { line = format!("{} {}", line, info.len()) }
+                match self.grid.get(&(x_value, y_value)) {
+                    Some(info) => line = format!("{} {}", line, info.len()),
+                    None => line = format!("{} .", line),
+                }
             }
 
             line = format!("{}\n", line);
@@ -172,4 +183,85 @@ impl fmt::Display for Map {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 only counts horizontal and vertical lines, part 2 diagonals too.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>, InputError> {
+        return aoc_input::lines(input).enumerate()
+            .map(|(index, line)| {
+
+                let pos_split : Vec<&str> = line.split(" -> ").collect();
+                if pos_split.len() != 2 { return Err(InputError::malformed("expected 'x1,y1 -> x2,y2'", index + 1)) }
+
+                let pos1 : Vec<CoordinateUnit> = aoc_input::parse_list(pos_split[0], ",", index + 1)?;
+                let pos2 : Vec<CoordinateUnit> = aoc_input::parse_list(pos_split[1], ",", index + 1)?;
+                if pos1.len() != 2 || pos2.len() != 2 { return Err(InputError::malformed("expected 'x1,y1 -> x2,y2'", index + 1)) }
+
+                return Ok(Line::new(pos1[0], pos1[1], pos2[0], pos2[1]));
+            }).collect();
+    }
+
+    fn part1(lines: &Vec<Line>) -> AocResult<usize> {
+        let mut map = Map::new(lines.clone(), false)?;
+        map.mark_lines();
+        return Ok(map.count_positions_with_me(2));
+    }
+
+    fn part2(lines: &Vec<Line>) -> AocResult<usize> {
+        let mut map = Map::new(lines.clone(), true)?;
+        map.mark_lines();
+        return Ok(map.count_positions_with_me(2));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn classifies_lines() {
+        assert!(Line::new(1, 1, 1, 1).get_type() == LineType::Invalid);
+        assert!(Line::new(0, 9, 5, 9).get_type() == LineType::Horizontal);
+        assert!(Line::new(7, 0, 7, 4).get_type() == LineType::Vertical);
+        assert!(Line::new(1, 1, 3, 3).get_type() == LineType::Diagonal);
+    }
+
+    #[test]
+    fn walks_every_point_of_a_line() {
+        let points : Vec<(CoordinateUnit, CoordinateUnit)> = Line::new(9, 7, 7, 9).get_points().into_iter()
+            .map(|point| (point.x, point.y))
+            .collect();
+        assert_eq!(points, vec!((9, 7), (8, 8), (7, 9)));
+
+        assert_eq!(Line::new(3, 4, 1, 4).get_points().len(), 3);
+    }
+
+    #[test]
+    fn coordinates_off_the_floor_are_rejected() {
+        let lines : Vec<Line> = Day05::parse("0,0 -> 5,0\n-1,0 -> 5,0").unwrap();
+        assert!(matches!(Map::new(lines, false), Err(AocError::Input(error)) if error.line == Some(2)));
+
+        let lines : Vec<Line> = Day05::parse("0,0 -> 9999999999999,0").unwrap();
+        assert!(Day05::part1(&lines).is_err());
+    }
+
+    #[test]
+    fn only_covered_points_are_stored() {
+        let mut map = Map::new(vec!(Line::new(0, 0, 0, 3), Line::new(0, 2, MAX_COORDINATE, 2)), false).unwrap();
+        map.mark_lines();
+        assert_eq!(map.grid.len(), 4 + MAX_COORDINATE as usize);
+        assert_eq!(map.count_positions_with_me(2), 1);
+    }
+}
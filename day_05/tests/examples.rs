@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_05::Day05;
+
+const EXAMPLE : &str = include_str!("../../inputs/day05.example.txt");
+const INPUT : &str = include_str!("../../inputs/day05.txt");
+
+fn parse(input: &str) -> <Day05 as Solution>::Input { Day05::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day05::part1(&parse(EXAMPLE)).unwrap(), 5);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day05::part2(&parse(EXAMPLE)).unwrap(), 12);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day05::part1(&parse(INPUT)).unwrap(), 6005);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day05::part2(&parse(INPUT)).unwrap(), 23864);
+}
@@ -1,11 +1,15 @@
+//! Day 6: Lanternfish, modelling how fast a school of lanternfish grows.
+
 
 use std::fmt;
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, InputErrorKind, Solution};
+
 // ================================================== STRUCTS ==================================================
 
-type LifePoints = u64;
-type Day = u64;
+pub type LifePoints = u64;
+pub type Day = u64;
 
 const LIFEPOINTS_AFTER_SPAWN : u64 = 8;
 const LIFEPOINTS_AFTER_RESET : u64 = 6;
@@ -15,11 +19,13 @@ struct LanternFish {
     life:       LifePoints,
 }
 
+/// Keeps every fish on its own, simple to follow but it grows exponentially with the days.
 pub struct SeaFloor {
     days_done:  Day,
     fishes:     Vec<LanternFish>,
 }
 
+/// Only counts how many fishes share each life value, so any number of days is cheap.
 pub struct ExponentialSeaFloor {
     days_done:  Day,
     fishes:     HashMap<usize, usize>,
@@ -97,7 +103,12 @@ impl SeaFloor {
 
 impl ExponentialSeaFloor {
 
-    pub fn new(fish_lives: Vec<LifePoints>) -> ExponentialSeaFloor {
+    /// Fails when a fish has more life than a freshly reset one.
+    pub fn new(fish_lives: Vec<LifePoints>) -> AocResult<ExponentialSeaFloor> {
+
+        if let Some(fish_life) = fish_lives.iter().find(|&&fish_life| fish_life > LIFEPOINTS_AFTER_RESET) {
+            return Err(AocError::malformed(&format!("fish life '{}' is over the reset value of {}", fish_life, LIFEPOINTS_AFTER_RESET)));
+        }
 
         let mut fishes : HashMap<usize, usize> = HashMap::new();
         for life_value in 0..(LIFEPOINTS_AFTER_RESET + 1) { fishes.insert(life_value as usize, 0); }
@@ -105,11 +116,11 @@ impl ExponentialSeaFloor {
         let mut fishes_incubating : HashMap<usize, usize> = HashMap::new();
         for life_value in 1..(LIFEPOINTS_AFTER_SPAWN - LIFEPOINTS_AFTER_RESET + 1) { fishes_incubating.insert(life_value as usize, 0); }
 
-        ExponentialSeaFloor {
+        Ok(ExponentialSeaFloor {
             days_done: 0,
             fishes: fishes,
             incubating: fishes_incubating,
-        }
+        })
     }
 
     pub fn get_days_done(&self) -> Day { return self.days_done; }
@@ -121,6 +132,20 @@ impl ExponentialSeaFloor {
         return count;
     }
 
+    /// How many fishes have each life value, from 0 up to a newborn's 8.
+    pub fn get_fishes_by_life(&self) -> Vec<usize> {
+
+        // Lifes rotate instead of moving fishes around, the ones at zero are always on this day's turn
+        let cycle : u64 = LIFEPOINTS_AFTER_RESET + 1;
+        let mut fishes_by_life : Vec<usize> = (0..cycle)
+            .map(|life| *self.fishes.get(&(((life + self.days_done) % cycle) as usize)).unwrap())
+            .collect();
+        fishes_by_life.push(*self.incubating.get(&1).unwrap());
+        fishes_by_life.push(*self.incubating.get(&2).unwrap());
+
+        return fishes_by_life;
+    }
+
     pub fn do_iteration(&mut self) {
 
         let turn = self.days_done % ( LIFEPOINTS_AFTER_RESET + 1);
@@ -137,9 +162,8 @@ impl ExponentialSeaFloor {
 impl fmt::Display for SeaFloor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
-        let mut line : String = String::new();
-        if self.days_done == 0 { line = format!("Initial state: ") }
-        else { line = format!("After {: >2} day: ", self.days_done) }
+        let mut line : String = if self.days_done == 0 { "Initial state: ".to_string() }
+            else { format!("After {: >2} day: ", self.days_done) };
 
         let lifes_part = self.fishes.iter()
             .map(|fish| fish.get_lifepoints().to_string())
@@ -148,4 +172,88 @@ impl fmt::Display for SeaFloor {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Number of fishes after 80 days in part 1 and after 256 days in part 2.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<LifePoints>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// Both sea floors need the lives to be at most the reset value, they are checked once for both parts.
+    fn parse(input: &str) -> Result<Vec<LifePoints>, InputError> {
+
+        let line = aoc_input::lines(input).next().ok_or_else(|| InputError::malformed("missing fish lives", 1))?;
+        let lives : Vec<LifePoints> = aoc_input::parse_list(line, ",", 1)?;
+        if let Some(index) = lives.iter().position(|&life| life > LIFEPOINTS_AFTER_RESET) {
+            let column : usize = line.split(',').take(index).map(|value| value.len() + 1).sum::<usize>() + 1;
+            let message = format!("fish life '{}' is over the reset value of {}", lives[index], LIFEPOINTS_AFTER_RESET);
+            return Err(InputError::new(InputErrorKind::Malformed(message)).at(1, column));
+        }
+
+        return Ok(lives);
+    }
+
+    fn part1(lifes: &Vec<LifePoints>) -> AocResult<usize> {
+        let mut sea_floor = SeaFloor::new(lifes.clone());
+        while sea_floor.get_days_done() != 80 { sea_floor.do_iteration() }
+        return Ok(sea_floor.number_of_fishes());
+    }
+
+    fn part2(lifes: &Vec<LifePoints>) -> AocResult<usize> {
+        let mut sea_floor = ExponentialSeaFloor::new(lifes.clone())?;
+        while sea_floor.get_days_done() != 256 { sea_floor.do_iteration() }
+        return Ok(sea_floor.number_of_fishes());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn lantern_fish_spawns_after_reaching_zero() {
+        let mut fish = LanternFish::new(1);
+        assert!(matches!(fish.do_step(), FishIteration::Nothing));
+        assert!(matches!(fish.do_step(), FishIteration::Spawn));
+        assert_eq!(fish.get_lifepoints(), LIFEPOINTS_AFTER_RESET);
+        assert_eq!(LanternFish::new_default().get_lifepoints(), LIFEPOINTS_AFTER_SPAWN);
+    }
+
+    #[test]
+    fn both_sea_floors_agree() {
+        let mut sea_floor = SeaFloor::new(vec!(3, 4, 3, 1, 2));
+        let mut exponential_sea_floor = ExponentialSeaFloor::new(vec!(3, 4, 3, 1, 2)).unwrap();
+        while sea_floor.get_days_done() != 18 { sea_floor.do_iteration() }
+        while exponential_sea_floor.get_days_done() != 18 { exponential_sea_floor.do_iteration() }
+        assert_eq!(sea_floor.number_of_fishes(), 26);
+        assert_eq!(exponential_sea_floor.number_of_fishes(), 26);
+    }
+
+    #[test]
+    fn fishes_by_life_follow_the_rotation() {
+        // After 3 days the example is 0,1,0,5,6,7,8
+        let mut sea_floor = ExponentialSeaFloor::new(vec!(3, 4, 3, 1, 2)).unwrap();
+        for _ in 0..3 { sea_floor.do_iteration() }
+        assert_eq!(sea_floor.get_fishes_by_life(), vec!(2, 1, 0, 0, 0, 1, 1, 1, 1));
+    }
+
+    #[test]
+    fn exponential_sea_floor_rejects_unknown_lives() {
+        assert!(matches!(ExponentialSeaFloor::new(vec!(3, 7)), Err(AocError::Input(_))));
+    }
+
+    #[test]
+    fn lives_over_the_reset_value_are_rejected_for_both_parts() {
+        let error = Day06::parse("3,4,9,1").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(5)));
+        assert!(Day06::parse("3,4,6,1").is_ok());
+    }
+}
@@ -1,21 +1,13 @@
-use std::io::{BufRead, BufReader, Error, ErrorKind};
-
-mod read;
-mod lib;
-
-use lib::{SeaFloor, ExponentialSeaFloor};
+use aoc_common::Solution;
+use day_06::{SeaFloor, ExponentialSeaFloor, Day06};
 
 fn main() {
 
-    let input_lines = read::read_lines("input.txt".to_owned());
-    // Sepparate Input
-    let mut lifes : Vec<u64> = input_lines[0].split(',')
-        .map(|life_str| life_str.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
-        .collect::<Result<_, _>>().unwrap();
+    let lifes = Day06::parse(&aoc_input::read_to_string("../inputs/day06.txt").unwrap()).unwrap();
 
     // Create Sea Floor
     let mut sea_floor = SeaFloor::new(lifes.clone());
-    let mut exponential_sea_floor = ExponentialSeaFloor::new(lifes.clone());
+    let mut exponential_sea_floor = ExponentialSeaFloor::new(lifes.clone()).unwrap();
 
     // Part 1
     while sea_floor.get_days_done() != 80 {
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_06::Day06;
+
+const EXAMPLE : &str = include_str!("../../inputs/day06.example.txt");
+const INPUT : &str = include_str!("../../inputs/day06.txt");
+
+fn parse(input: &str) -> <Day06 as Solution>::Input { Day06::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day06::part1(&parse(EXAMPLE)).unwrap(), 5934);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day06::part2(&parse(EXAMPLE)).unwrap(), 26984457539);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day06::part1(&parse(INPUT)).unwrap(), 391671);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day06::part2(&parse(INPUT)).unwrap(), 1754000560399);
+}
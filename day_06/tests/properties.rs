@@ -0,0 +1,32 @@
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+use day_06::{Day06, ExponentialSeaFloor, LifePoints, SeaFloor};
+
+// Lives go up to 6 in a valid input, the ones above it must be turned down by both parts alike
+const MAX_LIFE : LifePoints = 6;
+
+proptest! {
+
+    #[test]
+    fn both_sea_floors_grow_alike(lives in prop::collection::vec(prop_oneof![19 => 0..=MAX_LIFE, 1 => (MAX_LIFE + 1)..=9], 0..20), days in 0..50u64) {
+
+        let input = lives.iter().map(|life| life.to_string()).collect::<Vec<String>>().join(",");
+        if lives.iter().any(|&life| life > MAX_LIFE) {
+            prop_assert!(Day06::parse(&input).is_err());
+            prop_assert!(ExponentialSeaFloor::new(lives).is_err());
+            return Ok(());
+        }
+
+        let mut sea_floor = SeaFloor::new(lives.clone());
+        let mut exponential_sea_floor = ExponentialSeaFloor::new(lives).unwrap();
+        while sea_floor.get_days_done() != days {
+
+            sea_floor.do_iteration();
+            exponential_sea_floor.do_iteration();
+            prop_assert_eq!(sea_floor.number_of_fishes(), exponential_sea_floor.number_of_fishes(), "day {}", sea_floor.get_days_done());
+        }
+
+        prop_assert_eq!(exponential_sea_floor.get_fishes_by_life().iter().sum::<usize>(), sea_floor.number_of_fishes());
+    }
+}
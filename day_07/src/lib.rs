@@ -1,10 +1,16 @@
+//! Day 7: The Treachery of Whales, aligning the crab submarines with the least fuel.
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 
 // ================================================== STRUCTS ==================================================
 
-type HorizontalPosition = i64;
-type Fuel = f64;
+pub type HorizontalPosition = i64;
+pub type Fuel = f64;
 
+/// Whether every step costs one unit of fuel or one more than the step before.
 pub enum CrabEngineering { Constant, Incremental }
+/// The horizontal positions of the crabs.
 pub struct CrabArmy {
     engineering:        CrabEngineering,
     crab_positions:     Vec<HorizontalPosition>,
@@ -45,8 +51,10 @@ impl CrabArmy {
         return fuel;
     }
 
-    pub fn minimum_align_position(&self) -> Option<(HorizontalPosition, Fuel)> {
+    /// The position that takes the least fuel to align on, with that fuel.
+    pub fn minimum_align_position(&self) -> AocResult<(HorizontalPosition, Fuel)> {
 
+        if self.crab_positions.len() == 0 { return Err(AocError::unsolvable("there are no crabs to align")) }
         let (mut minimum_position, mut maximum_position) = (self.crab_positions[0], self.crab_positions[0]);
         for &crab_position in self.crab_positions.iter() {
             minimum_position = std::cmp::min(minimum_position, crab_position);
@@ -62,6 +70,47 @@ impl CrabArmy {
             }
         }
 
-        return minimum_info;
+        return Ok(minimum_info.unwrap());
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 moves crabs at a constant cost, part 2 at an incremental one.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<HorizontalPosition>;
+    type Answer1 = Fuel;
+    type Answer2 = Fuel;
+
+    fn parse(input: &str) -> Result<Vec<HorizontalPosition>, InputError> {
+        let line = aoc_input::lines(input).next().ok_or_else(|| InputError::malformed("missing crab positions", 1))?;
+        return aoc_input::parse_list(line, ",", 1);
+    }
+
+    fn part1(positions: &Vec<HorizontalPosition>) -> AocResult<Fuel> {
+        let army = CrabArmy::new(CrabEngineering::Constant, positions);
+        return Ok(army.minimum_align_position()?.1);
     }
-}
\ No newline at end of file
+
+    fn part2(positions: &Vec<HorizontalPosition>) -> AocResult<Fuel> {
+        let army = CrabArmy::new(CrabEngineering::Incremental, positions);
+        return Ok(army.minimum_align_position()?.1);
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn computes_fuel_to_align() {
+        let positions = vec!(16, 1, 2, 0, 4, 2, 7, 1, 2, 14);
+        assert_eq!(CrabArmy::new(CrabEngineering::Constant, &positions).fuel_used_to_align(1), 41.0);
+        assert_eq!(CrabArmy::new(CrabEngineering::Incremental, &positions).fuel_used_to_align(2), 206.0);
+    }
+}
@@ -1,14 +1,11 @@
-mod read;
-mod lib;
-
-use lib::{CrabArmy, CrabEngineering};
+use aoc_common::Solution;
+use day_07::{CrabArmy, CrabEngineering, Day07};
 
 fn main() {
 
-    let lines = read::read_list_int_lines("input.txt".to_owned());
-    let positions = &lines[0];
-    let constant_army : CrabArmy = CrabArmy::new(CrabEngineering::Constant, positions);
-    let increm_army : CrabArmy = CrabArmy::new(CrabEngineering::Incremental, positions);
+    let positions = Day07::parse(&aoc_input::read_to_string("../inputs/day07.txt").unwrap()).unwrap();
+    let constant_army : CrabArmy = CrabArmy::new(CrabEngineering::Constant, &positions);
+    let increm_army : CrabArmy = CrabArmy::new(CrabEngineering::Incremental, &positions);
 
     // Part 1
     let constant_info = constant_army.minimum_align_position().unwrap();
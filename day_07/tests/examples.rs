@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_07::Day07;
+
+const EXAMPLE : &str = include_str!("../../inputs/day07.example.txt");
+const INPUT : &str = include_str!("../../inputs/day07.txt");
+
+fn parse(input: &str) -> <Day07 as Solution>::Input { Day07::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day07::part1(&parse(EXAMPLE)).unwrap(), 37.0);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day07::part2(&parse(EXAMPLE)).unwrap(), 168.0);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day07::part1(&parse(INPUT)).unwrap(), 337833.0);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day07::part2(&parse(INPUT)).unwrap(), 96678050.0);
+}
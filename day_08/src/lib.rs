@@ -1,16 +1,25 @@
+//! Day 8: Seven Segment Search, untangling the wires of the scrambled displays.
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
-type Code = String;
-type Digit = u64;
+pub type Code = String;
+pub type Digit = u64;
+
+const NUMBER_OF_PATTERNS : usize = 10;
+const NUMBER_OF_OUTPUTS : usize = 4;
 
+/// The ten unique patterns of a display and the four digits of its output.
 pub struct EntryProblem {
     map_codes:      HashMap<Code, Vec<Digit>>,
     output_codes:   Vec<Code>,
     output:         Vec<Option<Digit>>,
 }
 
+/// Every entry of the notes.
 pub struct Display {
     entry_problems:     Vec<EntryProblem>,
 }
@@ -63,7 +72,7 @@ impl EntryProblem {
         for (index_digit, digit) in self.output.iter().rev().enumerate() {
 
             if digit.is_none() { return None }
-            number = number + (10 as u64).pow(index_digit as u32) * digit.unwrap();
+            number = number + 10_u64.pow(index_digit as u32) * digit.unwrap();
         }
 
         return Some(number);
@@ -129,7 +138,11 @@ impl EntryProblem {
 
         for commonality in know_commonalities {
 
-            let known_code = mapping_digits.get(&commonality.know).unwrap();
+            // Without a pattern of the right size nothing can be compared, the digits stay undecoded
+            let known_code = match mapping_digits.get(&commonality.know) {
+                Some(known_code) => known_code,
+                None => continue,
+            };
             for (code, digits) in self.map_codes.iter_mut() {
 
                 let common_chars = common_characthers(known_code.clone(), code.clone());
@@ -149,15 +162,18 @@ impl EntryProblem {
         self.solve_mapping_by_commonalities();
     }
 
-    fn solve_output(&mut self) {
+    fn solve_output(&mut self) -> AocResult<()> {
 
         self.output = Vec::new();
         for output_code in self.output_codes.iter() {
 
-            let possible_digits = self.map_codes.get(output_code).unwrap();
+            let possible_digits = self.map_codes.get(output_code)
+                .ok_or_else(|| AocError::malformed(&format!("output '{}' is not one of the patterns", output_code)))?;
             if possible_digits.len() == 1 { self.output.push(Some(possible_digits[0])); }
             else { self.output.push(None); }
         }
+
+        return Ok(());
     }
 
 }
@@ -170,15 +186,19 @@ impl Display {
         }
     }
 
-    pub fn solve_entries(&mut self) {
+    /// Works out which pattern is which digit and decodes the outputs with it.
+    pub fn solve_entries(&mut self) -> AocResult<()> {
 
         for entry in self.entry_problems.iter_mut() {
 
             entry.solve_mapping();
-            entry.solve_output();
+            entry.solve_output()?;
         }
+
+        return Ok(());
     }
 
+    /// How many of the decoded output digits are one of `digits_to_count`.
     pub fn number_of_digits(&self, digits_to_count: Vec<Digit>) -> usize {
 
         let mut count: usize = 0;
@@ -196,13 +216,104 @@ impl Display {
         return count;
     }
 
-    pub fn sum_outputs(&self) -> u64 {
+    /// Sum of every output value, fails if any of their digits was left undecoded.
+    pub fn sum_outputs(&self) -> AocResult<u64> {
 
         let mut sum : u64 = 0;
         for entry in self.entry_problems.iter() {
-            sum = sum + entry.get_output_number().unwrap();
+            sum = sum + entry.get_output_number().ok_or_else(|| AocError::unsolvable("some output digits could not be decoded"))?;
         }
 
-        return sum;
+        return Ok(sum);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+fn build_display(entries: &Vec<(Vec<Code>, Vec<Code>)>) -> Display {
+    let entry_problems : Vec<EntryProblem> = entries.iter()
+        .map(|(patterns, output_codes)| EntryProblem::new(patterns.clone(), output_codes.clone()))
+        .collect();
+
+    return Display::new(entry_problems);
+}
+
+/// Part 1 counts the easy digits in the outputs, part 2 adds up the output values.
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<(Vec<Code>, Vec<Code>)>;
+    type Answer1 = usize;
+    type Answer2 = Digit;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        return aoc_input::lines(input).enumerate()
+            .map(|(index, line)| {
+
+                let line_split : Vec<&str> = line.split(" | ").collect();
+                if line_split.len() != 2 { return Err(InputError::malformed("expected '<patterns> | <outputs>'", index + 1)) }
+
+                let patterns : Vec<Code> = line_split[0].split_whitespace().map(|code| code.to_owned()).collect();
+                let output_codes : Vec<Code> = line_split[1].split_whitespace().map(|code| code.to_owned()).collect();
+                if patterns.len() != NUMBER_OF_PATTERNS || output_codes.len() != NUMBER_OF_OUTPUTS {
+                    let message = format!("expected {} patterns and {} outputs, found {} and {}", NUMBER_OF_PATTERNS, NUMBER_OF_OUTPUTS, patterns.len(), output_codes.len());
+                    return Err(InputError::malformed(&message, index + 1));
+                }
+
+                return Ok((patterns, output_codes));
+            }).collect();
+    }
+
+    fn part1(entries: &Self::Input) -> AocResult<usize> {
+        let mut display = build_display(entries);
+        display.solve_entries()?;
+        return Ok(display.number_of_digits(vec!(1, 4, 7, 8)));
+    }
+
+    fn part2(entries: &Self::Input) -> AocResult<Digit> {
+        let mut display = build_display(entries);
+        display.solve_entries()?;
+        return display.sum_outputs();
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sorts_codes_consistently() {
+        assert_eq!(sort_string("cfbegad".to_owned()), sort_string("abcdefg".to_owned()));
+    }
+
+    #[test]
+    fn counts_common_segments() {
+        assert_eq!(common_characthers("ab".to_owned(), "dab".to_owned()), 2);
+        assert_eq!(common_characthers("cf".to_owned(), "eafb".to_owned()), 1);
+    }
+
+    #[test]
+    fn missing_known_digits_leave_outputs_undecoded() {
+        // No two segment pattern, so the one is never found
+        let patterns : Vec<Code> = vec!("abc", "abcd", "abcdefg").into_iter().map(|code| code.to_owned()).collect();
+        let mut display = Display::new(vec!(EntryProblem::new(patterns, vec!("abc".to_owned(), "abcd".to_owned()))));
+        display.solve_entries().unwrap();
+
+        assert_eq!(display.number_of_digits(vec!(7, 4)), 2);
+        assert!(matches!(display.sum_outputs(), Ok(74)));
+    }
+
+    #[test]
+    fn entries_need_ten_patterns_and_four_outputs() {
+        let patterns : &str = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb";
+        assert!(Day08::parse(&format!("{} | fdgacbe cefdb cefbgd gcbe", patterns)).is_ok());
+
+        let long_output : String = vec!("gcbe"; 25).join(" ");
+        let error = Day08::parse(&format!("{} | fdgacbe cefdb cefbgd gcbe\n{} | {}", patterns, patterns, long_output)).err().unwrap();
+        assert_eq!(error.line, Some(2));
+        assert!(Day08::parse("be cfbegad | fdgacbe cefdb cefbgd gcbe").is_err());
+    }
+}
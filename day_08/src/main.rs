@@ -1,33 +1,15 @@
-mod read;
-mod lib;
-
-use lib::{EntryProblem, Display};
+use aoc_common::Solution;
+use day_08::Day08;
 
 fn main() {
 
-    let mut entry_problems : Vec<EntryProblem> = Vec::new();
-
-    let lines = read::read_lines("input.txt".to_owned());
-    for line in lines {
-
-        let line_split : Vec<&str> = line.split(" | ").collect();
-        let input = line_split[0];
-        let output = line_split[1];
-
-        let patterns : Vec<String> = input.split_whitespace().map(|code| code.to_owned()).collect();
-        let output_coddes : Vec<String> = output.split_whitespace().map(|code| code.to_owned()).collect();
-
-        entry_problems.push(EntryProblem::new(patterns, output_coddes));
-    }
-
-    let mut display : Display = Display::new(entry_problems);
-    display.solve_entries();
+    let entries = Day08::parse(&aoc_input::read_to_string("../inputs/day08.txt").unwrap()).unwrap();
 
     // Part 1
-    let count_1 : usize = display.number_of_digits(vec!(1, 4, 7, 8));
+    let count_1 : usize = Day08::part1(&entries).unwrap();
     println!("🖵  The number of {{1, 4, 7, 8}}s is '{}' (Part 1)", count_1);
     
     // Part 2
-    let count_2 : u64 = display.sum_outputs();
+    let count_2 : u64 = Day08::part2(&entries).unwrap();
     println!("🖵  The sum of all outputs is '{}' (Part 2)", count_2);
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_08::Day08;
+
+const EXAMPLE : &str = include_str!("../../inputs/day08.example.txt");
+const INPUT : &str = include_str!("../../inputs/day08.txt");
+
+fn parse(input: &str) -> <Day08 as Solution>::Input { Day08::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day08::part1(&parse(EXAMPLE)).unwrap(), 26);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day08::part2(&parse(EXAMPLE)).unwrap(), 61229);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day08::part1(&parse(INPUT)).unwrap(), 514);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day08::part2(&parse(INPUT)).unwrap(), 1012272);
+}
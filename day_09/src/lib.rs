@@ -1,8 +1,11 @@
-use std::collections::HashMap;
+//! Day 9: Smoke Basin, finding the low points of the cave floor and the basins around them.
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::{Grid, Position};
 
 // ================================================== STRUCTS ==================================================
 
-type Digit = u32;
+pub type Digit = u32;
 
 #[derive(PartialEq, Copy, Clone)]
 enum CellType { LowPoint, Other }
@@ -12,13 +15,12 @@ struct Cell {
 }
 
 struct Basin {
-    center:         (usize, usize),
-    basin_members:  Vec<(usize, usize)>
+    basin_members:  Vec<Position>
 }
 
+/// The height map of the cave floor.
 pub struct Map {
-    size:       (usize, usize),
-    cell_map:   HashMap<(i64, i64), Cell>,
+    cell_map:   Grid<Cell>,
 }
 
 // ================================================== AUX FUNCTIONS ==================================================
@@ -51,7 +53,7 @@ impl Cell {
 
     fn get_risk_level(&self) -> Option<u32> {
 
-        if self.cell_type.is_none() { return None; }
+        self.cell_type?;
         if self.cell_type.unwrap() == CellType::LowPoint { return Some(self.value + 1); }
         else { return None; }
     }
@@ -59,55 +61,34 @@ impl Cell {
 
 impl Map {
 
-    pub fn new(cell_values: Vec<Vec<Digit>>) -> Map {
-
-        let mut size : (usize, usize) = (0, 0);
-        let mut map : HashMap<(i64, i64), Cell> = HashMap::new();
-
-        size.0 = cell_values.len();
-        for (index_row, cell_row) in cell_values.into_iter().enumerate() {
+    pub fn new(cell_values: Vec<Vec<Digit>>) -> AocResult<Map> {
 
-            size.1 = std::cmp::max(size.1, cell_row.len());
-            for (index_col, cell_value) in cell_row.into_iter().enumerate() {
-
-                let new_cell : Cell = Cell::new(cell_value);
-                map.insert((index_row as i64, index_col as i64), new_cell);
-            }
-        }
+        let cells : Vec<Vec<Cell>> = cell_values.into_iter()
+            .map(|cell_row| cell_row.into_iter().map(Cell::new).collect())
+            .collect();
 
-        Map {
-            size: size,
-            cell_map: map,
-        }
+        Ok(Map {
+            cell_map: Grid::from_rows(cells)?,
+        })
     }
 
+    /// Marks the cells lower than all of their neighbours, needed before the sums below.
     pub fn find_low_points(&mut self) {
 
-        for row_index in 0..self.size.0 {
-            for column_index in 0..self.size.1 {
+        let positions : Vec<Position> = self.cell_map.positions().collect();
+        for position in positions.into_iter() {
 
-                let mut cell_values : Vec<Digit> = Vec::new();
-                // Check neighbours
-                for row_variation in vec![-1, 1] {
-                    let neighbour_cell = self.cell_map.get(&(row_index as i64 + row_variation, column_index as i64));
-                    if neighbour_cell.is_some() { cell_values.push(neighbour_cell.unwrap().get_value()) }
-                }
-                for col_variation in vec![-1, 1] {
-                    let neighbour_cell = self.cell_map.get(&(row_index as i64, column_index as i64 + col_variation));
-                    if neighbour_cell.is_some() { cell_values.push(neighbour_cell.unwrap().get_value()) }
-                }
+            // Check neighbours
+            let cell_values : Vec<Digit> = self.cell_map.neighbours(position).into_iter()
+                .map(|neighbour_position| self.cell_map[neighbour_position].get_value())
+                .collect();
 
-                // Update current cell
-                let cell_option = self.cell_map.get_mut(&(row_index as i64, column_index as i64));
-                if cell_option.is_none() { continue }
-
-                let cell = cell_option.unwrap();
-                cell.set_cell_type(cell_values);
-
-            }
+            // Update current cell
+            self.cell_map[position].set_cell_type(cell_values);
         }
     }
 
+    /// Sum of the risk levels of every low point, their height plus one.
     pub fn find_sum_risk_levels(&self) -> u32 {
 
         let mut sum_risk : u32 = 0;
@@ -120,15 +101,15 @@ impl Map {
         return sum_risk;
     }
 
-    pub fn find_basins_value(&self) -> u32 {
+    /// Product of the sizes of the three largest basins, there must be at least three of them.
+    pub fn find_basins_value(&self) -> AocResult<u32> {
 
         let mut basins : Vec<Basin> = Vec::new();
-        for (&(row_index, col_index), cell) in self.cell_map.iter() {
+        for (initial_position, cell) in self.cell_map.iter() {
 
             if cell.cell_type.unwrap() == CellType::Other { continue; }
 
-            let initial_position = (row_index as usize, col_index as usize);
-            let mut basin : Basin = Basin{center: initial_position, basin_members: vec!(initial_position)};
+            let mut basin : Basin = Basin{basin_members: vec!(initial_position)};
             let cell_value = cell.get_value();
 
             let mut still_to_check = vec!(initial_position);
@@ -137,43 +118,75 @@ impl Map {
                 let check_position = still_to_check.pop().unwrap();
 
                 // Check neighbours
-                for row_variation in vec![-1, 1] {
-                    let neighbour_position = (check_position.0 as i64 + row_variation, check_position.1 as i64);
-                    let neighbour_cell = self.cell_map.get(&neighbour_position);
-                    if neighbour_cell.is_some() {
-
-                        let neighbour_position_corrected = (neighbour_position.0 as usize, neighbour_position.1 as usize);
-                        let neighbour_value = neighbour_cell.unwrap().get_value();
-                        if neighbour_value > cell_value && neighbour_value != 9 && !basin.basin_members.contains(&neighbour_position_corrected) {
-
-                            still_to_check.push(neighbour_position_corrected);
-                            basin.basin_members.push(neighbour_position_corrected);
-                        }
-                    }
-                }
-                for col_variation in vec![-1, 1] {
-                    let neighbour_position = (check_position.0 as i64, check_position.1 as i64 + col_variation);
-                    let neighbour_cell = self.cell_map.get(&neighbour_position);
-                    if neighbour_cell.is_some() {
-
-                        let neighbour_position_corrected = (neighbour_position.0 as usize, neighbour_position.1 as usize);
-                        let neighbour_value = neighbour_cell.unwrap().get_value();
-                        if neighbour_value > cell_value && neighbour_value != 9 && !basin.basin_members.contains(&neighbour_position_corrected) {
-
-                            still_to_check.push(neighbour_position_corrected);
-                            basin.basin_members.push(neighbour_position_corrected);
-                        }
+                for neighbour_position in self.cell_map.neighbours(check_position) {
+
+                    let neighbour_value = self.cell_map[neighbour_position].get_value();
+                    if neighbour_value > cell_value && neighbour_value != 9 && !basin.basin_members.contains(&neighbour_position) {
+
+                        still_to_check.push(neighbour_position);
+                        basin.basin_members.push(neighbour_position);
                     }
                 }
             }
 
-            //println!("Basin center '({}, {})' has size of '{}'", basin.center.0, basin.center.1, basin.basin_members.len());
             basins.push(basin);
         }
 
         basins.sort_by_key(|basin| basin.basin_members.len());
         basins.reverse();
 
-        return basins[0..3].iter().fold(1u32, |value, basin| value * basin.basin_members.len() as u32);
+        if basins.len() < 3 { return Err(AocError::unsolvable("fewer than three basins")) }
+        return Ok(basins[0..3].iter().fold(1u32, |value, basin| value * basin.basin_members.len() as u32));
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 adds up the risk levels, part 2 multiplies the three largest basins.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<Digit>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Digit>>, InputError> { aoc_input::read_digits_from(input.as_bytes()) }
+
+    fn part1(digits: &Vec<Vec<Digit>>) -> AocResult<u32> {
+        let mut map = Map::new(digits.clone())?;
+        map.find_low_points();
+        return Ok(map.find_sum_risk_levels());
+    }
+
+    fn part2(digits: &Vec<Vec<Digit>>) -> AocResult<u32> {
+        let mut map = Map::new(digits.clone())?;
+        map.find_low_points();
+        return map.find_basins_value();
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn only_low_points_have_risk() {
+        let mut cell = Cell::new(1);
+        assert_eq!(cell.get_risk_level(), None);
+
+        cell.set_cell_type(vec!(2, 9, 3));
+        assert_eq!(cell.get_risk_level(), Some(2));
+
+        cell.set_cell_type(vec!(2, 1));
+        assert_eq!(cell.get_risk_level(), None);
+    }
+
+    #[test]
+    fn fewer_than_three_basins_is_unsolvable() {
+        assert!(matches!(Day09::part2(&vec!(vec!(1))), Err(AocError::Unsolvable(_))));
+        assert!(matches!(Day09::part2(&vec!(vec!(1, 9, 1, 9, 1))), Ok(1)));
+    }
+}
@@ -1,12 +1,10 @@
-mod read;
-mod lib;
-
-use lib::Map;
+use aoc_common::Solution;
+use day_09::{Map, Day09};
 
 fn main() {
 
-    let digits : Vec<Vec<u32>> = read::read_digits("input.txt".to_owned());
-    let mut map : Map = Map::new(digits);
+    let digits = Day09::parse(&aoc_input::read_to_string("../inputs/day09.txt").unwrap()).unwrap();
+    let mut map : Map = Map::new(digits).unwrap();
     map.find_low_points();
 
     // Part 1
@@ -14,6 +12,6 @@ fn main() {
     println!("🌋  The risk level is '{}' (Part 1)", count_1);
     
     // Part 2
-    let count_2 : u32 = map.find_basins_value();
+    let count_2 : u32 = map.find_basins_value().unwrap();
     println!("🌋  The value for the basins '{}' (Part 2)", count_2);
 }
\ No newline at end of file
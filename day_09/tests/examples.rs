@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_09::Day09;
+
+const EXAMPLE : &str = include_str!("../../inputs/day09.example.txt");
+const INPUT : &str = include_str!("../../inputs/day09.txt");
+
+fn parse(input: &str) -> <Day09 as Solution>::Input { Day09::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day09::part1(&parse(EXAMPLE)).unwrap(), 15);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day09::part2(&parse(EXAMPLE)).unwrap(), 1134);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day09::part1(&parse(INPUT)).unwrap(), 512);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day09::part2(&parse(INPUT)).unwrap(), 1600104);
+}
@@ -1,10 +1,13 @@
+//! Day 10: Syntax Scoring, scoring the corrupted and incomplete lines of the navigation subsystem.
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
 struct LineError {
     found:      char,
-    expected:   Option<char>
 }
 
 struct Line {
@@ -13,8 +16,9 @@ struct Line {
     completion_chars:   Vec<char>,
 }
 
-type Score = u64;
+pub type Score = u64;
 
+/// Every line of chunks of the navigation subsystem.
 pub struct NavigationSubsystem {
     lines:              Vec<Line>
 }
@@ -52,14 +56,14 @@ impl Line {
                     let last_stack_element = stack_to_close.pop();
                     if last_stack_element.is_none() {
 
-                        self.invalid_characther = Some(LineError{found: characther, expected: None});
+                        self.invalid_characther = Some(LineError{found: characther});
                         return;
                     }
                     
                     let last_stack_characther = last_stack_element.unwrap();
                     if last_stack_characther != characther {
 
-                        self.invalid_characther = Some(LineError{found: characther, expected: Some(last_stack_characther)});
+                        self.invalid_characther = Some(LineError{found: characther});
                         return;
                     }
                 },
@@ -89,7 +93,7 @@ impl Line {
             let invalid_info = self.invalid_characther.as_ref().unwrap();
             let found_characther = invalid_info.found;
 
-            let score : Score = score_map.get(&found_characther).unwrap().clone();
+            let score : Score = *score_map.get(&found_characther).unwrap();
             return score;
         }
     }
@@ -118,22 +122,28 @@ impl Line {
 
 impl NavigationSubsystem {
 
-    pub fn new(lines_chars: Vec<Vec<char>>) -> NavigationSubsystem {
+    pub fn new(lines_chars: Vec<Vec<char>>) -> AocResult<NavigationSubsystem> {
+
+        if let Some(characther) = lines_chars.iter().flatten().find(|characther| !"()[]{}<>".contains(**characther)) {
+            return Err(AocError::malformed(&format!("'{}' is not a bracket", characther)));
+        }
 
         let lines : Vec<Line> = lines_chars.into_iter()
-            .map(|chars| Line::new(chars)).collect();
+            .map(Line::new).collect();
 
-        NavigationSubsystem {
+        Ok(NavigationSubsystem {
             lines: lines,
-        }
+        })
     }
 
+    /// Finds which lines are corrupted and how the incomplete ones end, needed before scoring.
     pub fn evaluate_lines(&mut self) {
         for line in self.lines.iter_mut() {
             line.compute_validity();
         }
     }
 
+    /// Sum of the scores of the first illegal characther of every corrupted line.
     pub fn compute_syntax_error_score(&self) -> Score {
 
         let mut sum : Score = 0;
@@ -144,7 +154,8 @@ impl NavigationSubsystem {
         return sum;
     }
 
-    pub fn compute_middle_completion_score(&self) -> Score {
+    /// Middle score among the completions of the incomplete lines.
+    pub fn compute_middle_completion_score(&self) -> AocResult<Score> {
 
         let mut scores : Vec<Score> = Vec::new();
         for line in self.lines.iter() {
@@ -155,9 +166,74 @@ impl NavigationSubsystem {
             }
         }
 
+        if scores.len() == 0 { return Err(AocError::unsolvable("there are no incomplete lines")) }
         scores.sort();
 
         let number_scores = scores.len();
-        return scores[(number_scores - 1) / 2];
+        return Ok(scores[(number_scores - 1) / 2]);
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 scores the corrupted lines, part 2 the incomplete ones.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = Score;
+    type Answer2 = Score;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, InputError> {
+
+        let lines = aoc_input::read_chars_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(column) = line.iter().position(|characther| !"()[]{}<>".contains(*characther)) {
+                return Err(InputError::malformed("expected only brackets", index + 1).at(index + 1, column + 1));
+            }
+        }
+
+        return Ok(lines);
     }
-}
\ No newline at end of file
+
+    fn part1(lines: &Vec<Vec<char>>) -> AocResult<Score> {
+        let mut navigation_subsystem = NavigationSubsystem::new(lines.clone())?;
+        navigation_subsystem.evaluate_lines();
+        return Ok(navigation_subsystem.compute_syntax_error_score());
+    }
+
+    fn part2(lines: &Vec<Vec<char>>) -> AocResult<Score> {
+        let mut navigation_subsystem = NavigationSubsystem::new(lines.clone())?;
+        navigation_subsystem.evaluate_lines();
+        return navigation_subsystem.compute_middle_completion_score();
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn evaluate(line: &str) -> Line {
+        let mut line = Line::new(line.chars().collect());
+        line.compute_validity();
+        return line;
+    }
+
+    #[test]
+    fn scores_corrupted_lines() {
+        let line = evaluate("{([(<{}[<>[]}>{[]{[(<()>");
+        assert_eq!(line.compute_error_score(), 1197);
+        assert_eq!(line.compute_completion_score(), None);
+    }
+
+    #[test]
+    fn scores_incomplete_lines() {
+        let line = evaluate("<{([{{}}[<[[[<>{}]]]>[]]");
+        assert_eq!(line.completion_chars.iter().collect::<String>(), "])}>");
+        assert_eq!(line.compute_error_score(), 0);
+        assert_eq!(line.compute_completion_score(), Some(294));
+    }
+}
@@ -1,12 +1,10 @@
-mod read;
-mod lib;
-
-use lib::NavigationSubsystem;
+use aoc_common::Solution;
+use day_10::{NavigationSubsystem, Day10};
 
 fn main() {
 
-    let characthers : Vec<Vec<char>> = read::read_chars("input.txt".to_owned());
-    let mut navigation_subsystem : NavigationSubsystem = NavigationSubsystem::new(characthers);
+    let characthers = Day10::parse(&aoc_input::read_to_string("../inputs/day10.txt").unwrap()).unwrap();
+    let mut navigation_subsystem : NavigationSubsystem = NavigationSubsystem::new(characthers).unwrap();
     navigation_subsystem.evaluate_lines();
 
     // Part 1
@@ -14,6 +12,6 @@ fn main() {
     println!("🗺️  The computed syntax error score is '{}' (Part 1)", count_1);
     
     // Part 2
-    let count_2 : u64 = navigation_subsystem.compute_middle_completion_score();
+    let count_2 : u64 = navigation_subsystem.compute_middle_completion_score().unwrap();
     println!("🗺️  The computed syntax completion score is '{}' (Part 2)", count_2);
 }
\ No newline at end of file
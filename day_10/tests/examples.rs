@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_10::Day10;
+
+const EXAMPLE : &str = include_str!("../../inputs/day10.example.txt");
+const INPUT : &str = include_str!("../../inputs/day10.txt");
+
+fn parse(input: &str) -> <Day10 as Solution>::Input { Day10::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day10::part1(&parse(EXAMPLE)).unwrap(), 26397);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day10::part2(&parse(EXAMPLE)).unwrap(), 288957);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day10::part1(&parse(INPUT)).unwrap(), 442131);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day10::part2(&parse(INPUT)).unwrap(), 3646451424);
+}
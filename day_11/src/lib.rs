@@ -1,13 +1,16 @@
+//! Day 11: Dumbo Octopus, following the flashes of a grid of bioluminescent octopi.
+
 use std::fmt;
-use std::collections::HashMap;
+use std::collections::HashSet;
 
 use colored::*;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::{Grid, Neighbourhood, Position};
+
 // ================================================== STRUCTS ==================================================
 
-type EnergyLevel = u32;
-type PositionUnit = i64;
-type Position = (PositionUnit, PositionUnit);
+pub type EnergyLevel = u32;
 
 const RESET_ENERGY_LEVEL : EnergyLevel = 0;
 const FLASH_ENERGY_LEVEL : EnergyLevel = 9;
@@ -18,11 +21,12 @@ struct DumboOctopus {
     activated_this_iter:    bool
 }
 
+/// The grid of octopi with how many steps and flashes happened so far.
 pub struct Map {
     iteration:          u64,
     number_of_flashes:  u64,
-    map_size:           Position,
-    octopi:             HashMap<Position, DumboOctopus>
+    octopi:             Grid<DumboOctopus>,
+    seen_states:        HashSet<Vec<EnergyLevel>>,
 }
 
 // ================================================== AUX FUNCTIONS ==================================================
@@ -53,32 +57,23 @@ impl DumboOctopus {
 
 impl Map {
 
-    pub fn new(energy_levels: Vec<Vec<EnergyLevel>>) -> Map {
+    pub fn new(energy_levels: Vec<Vec<EnergyLevel>>) -> AocResult<Map> {
 
-        let number_of_rows : usize = energy_levels.len();
-        let number_of_columns : usize = energy_levels.iter()
-            .map(|row| row.len()).max().unwrap();
+        let octopi : Vec<Vec<DumboOctopus>> = energy_levels.into_iter()
+            .map(|row| row.into_iter().map(DumboOctopus::new).collect())
+            .collect();
 
-        let octopi : HashMap<Position, DumboOctopus>= energy_levels.into_iter().enumerate()
-            .map(|(row_index, row)| row.into_iter().enumerate()
-                .map(|(col_index, energy)| ((row_index as PositionUnit, col_index as PositionUnit), DumboOctopus::new(energy)))
-                .collect::<Vec<(Position, DumboOctopus)>>())
-            .collect::<Vec<Vec<(Position, DumboOctopus)>>>()
-            .into_iter().flatten()
-            .collect::<Vec<(Position, DumboOctopus)>>()
-            .into_iter()
-            .collect::<HashMap<Position, DumboOctopus>>();
-
-        Map {
+        Ok(Map {
             iteration: 0,
             number_of_flashes: 0,
-            map_size: (number_of_rows as PositionUnit, number_of_columns as PositionUnit) as Position,
-            octopi: octopi,
-        }
+            octopi: Grid::from_rows(octopi)?.with_neighbourhood(Neighbourhood::Eight),
+            seen_states: HashSet::new(),
+        })
     }
 
     pub fn get_number_of_flashes(&self) -> u64 { self.number_of_flashes }
     pub fn get_iteration(&self) -> u64 { self.iteration }
+    pub fn get_energy_levels(&self) -> Grid<EnergyLevel> { self.octopi.map(|octopi| octopi.energy_level) }
 
     fn reset_octopi_iteration(&mut self) -> bool {
 
@@ -96,11 +91,12 @@ impl Map {
         return all_flash;
     }
 
+    /// Runs one step, true when every octopus flashed in it.
     pub fn do_iteration(&mut self) -> bool {
 
         let mut positions_flashed : Vec<Position> = Vec::new();
         // Increment all Octopi one value
-        for (&octopi_position, octopi) in self.octopi.iter_mut() {
+        for (octopi_position, octopi) in self.octopi.iter_mut() {
 
             let flashed : bool = octopi.increment_energy_level() == FlashOption::Flahsed;
             if flashed {
@@ -115,25 +111,17 @@ impl Map {
         while positions_flashed.len() != 0 {
 
             let octopi_position = positions_flashed.pop().unwrap();
-            for row_var in -1..=1 {
-                for col_var in -1..=1 {
+            for neighbour_octopi_position in self.octopi.neighbours(octopi_position) {
 
-                    if row_var == 0 && col_var == 0 { continue }
-                    let neighbour_octopi_position = ( octopi_position.0 + row_var, octopi_position.1 + col_var );
-                    let neighbour_option = self.octopi.get_mut(&neighbour_octopi_position);
+                let neighbour = &mut self.octopi[neighbour_octopi_position];
+                if neighbour.activated_this_iter { continue }
 
-                    if neighbour_option.is_none() { continue }
-                    let neighbour = neighbour_option.unwrap();
+                let flashed : bool = neighbour.increment_energy_level() == FlashOption::Flahsed;
+                if flashed {
 
-                    if neighbour.activated_this_iter { continue }
-                    
-                    let flashed : bool = neighbour.increment_energy_level() == FlashOption::Flahsed;
-                    if flashed {
-
-                        neighbour.activated_this_iter = true;
-                        positions_flashed.push(neighbour_octopi_position);
-                        self.number_of_flashes = self.number_of_flashes + 1;
-                    }
+                    neighbour.activated_this_iter = true;
+                    positions_flashed.push(neighbour_octopi_position);
+                    self.number_of_flashes = self.number_of_flashes + 1;
                 }
             }
         }
@@ -144,17 +132,28 @@ impl Map {
         let all_flashed : bool = self.reset_octopi_iteration();
         return all_flashed;
     }
+
+    /// Like `do_iteration`, but unsolvable once the octopi come back to energy levels they already had,
+    /// the steps are bound to repeat from there without all of them ever flashing together.
+    pub fn do_synchronizing_iteration(&mut self) -> AocResult<bool> {
+
+        let energy_levels : Vec<EnergyLevel> = self.octopi.iter().map(|(_, octopi)| octopi.energy_level).collect();
+        if !self.seen_states.insert(energy_levels) {
+            return Err(AocError::unsolvable(&format!("the octopi repeat after {} steps without flashing all at once", self.iteration)));
+        }
+
+        return Ok(self.do_iteration());
+    }
 }
 
 impl fmt::Display for Map {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
         let mut line : String = format!("Iteration: {}\n", self.iteration);
-        for row_index in 0..self.map_size.0 {
+        for row in self.octopi.rows() {
 
-            for col_index in 0..self.map_size.1 {
+            for octopi in row {
 
-                let octopi = self.octopi.get(&(row_index, col_index)).unwrap();
                 if octopi.energy_level == 0 { line = format!("{} {}", line, "0".green()) }
                 else { line = format!("{} {}", line, octopi.energy_level) }
             }
@@ -165,4 +164,52 @@ impl fmt::Display for Map {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 counts the flashes of 100 steps, part 2 finds the first step where all of them flash.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Vec<EnergyLevel>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<EnergyLevel>>, InputError> { aoc_input::read_digits_from(input.as_bytes()) }
+
+    fn part1(energy_levels: &Vec<Vec<EnergyLevel>>) -> AocResult<u64> {
+        let mut map = Map::new(energy_levels.clone())?;
+        for _ in 0..100 { map.do_iteration(); }
+        return Ok(map.get_number_of_flashes());
+    }
+
+    fn part2(energy_levels: &Vec<Vec<EnergyLevel>>) -> AocResult<u64> {
+        let mut map = Map::new(energy_levels.clone())?;
+        while !map.do_synchronizing_iteration()? {}
+        return Ok(map.get_iteration());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn octopus_flashes_above_nine() {
+        let mut octopus = DumboOctopus::new(8);
+        assert!(octopus.increment_energy_level() == FlashOption::NotFlashed);
+        assert!(octopus.increment_energy_level() == FlashOption::Flahsed);
+        assert_eq!(octopus.energy_level, RESET_ENERGY_LEVEL);
+    }
+
+    #[test]
+    fn octopi_that_never_flash_together_are_unsolvable() {
+        let energy_levels = Day11::parse("02").unwrap();
+        assert!(matches!(Day11::part2(&energy_levels), Err(AocError::Unsolvable(_))));
+        assert!(Day11::part2(&Day11::parse("00").unwrap()).is_ok());
+    }
+}
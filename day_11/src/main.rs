@@ -1,12 +1,10 @@
-mod read;
-mod lib;
-
-use lib::Map;
+use aoc_common::Solution;
+use day_11::{Map, Day11};
 
 fn main() {
 
-    let energy_levels : Vec<Vec<u32>> = read::read_digits("input.txt".to_owned());
-    let mut map = Map::new(energy_levels);
+    let energy_levels = Day11::parse(&aoc_input::read_to_string("../inputs/day11.txt").unwrap()).unwrap();
+    let mut map = Map::new(energy_levels).unwrap();
     
     // Part 1
     for _ in 0..100 {
@@ -17,7 +15,7 @@ fn main() {
     let count_1 : u64 = map.get_number_of_flashes();
     
     // Part 2
-    while !map.do_iteration() {
+    while !map.do_synchronizing_iteration().unwrap() {
         println!("{}", map);
     }
     println!("{}", map);
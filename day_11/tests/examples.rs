@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_11::Day11;
+
+const EXAMPLE : &str = include_str!("../../inputs/day11.example.txt");
+const INPUT : &str = include_str!("../../inputs/day11.txt");
+
+fn parse(input: &str) -> <Day11 as Solution>::Input { Day11::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day11::part1(&parse(EXAMPLE)).unwrap(), 1656);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day11::part2(&parse(EXAMPLE)).unwrap(), 195);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day11::part1(&parse(INPUT)).unwrap(), 1655);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day11::part2(&parse(INPUT)).unwrap(), 337);
+}
@@ -1,8 +1,12 @@
+//! Day 12: Passage Pathing, counting the paths through the cave system.
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution, Supervisor};
+
 // ================================================== STRUCTS ==================================================
 
-type CaveID = String;
+pub type CaveID = String;
 
 fn get_start_id() -> CaveID { String::from("start") }
 fn get_end_id() -> CaveID { String::from("end") }
@@ -11,16 +15,18 @@ fn get_end_id() -> CaveID { String::from("end") }
 enum CaveType { Start, Small, Big, End }
 
 struct Cave {
-    id:         CaveID,
     cave_type:  CaveType,
 }
 
+/// The caves and the passages between them, small caves are the lowercase ones.
 pub struct Map {
     caves:      HashMap<CaveID, Cave>,
     cave_links: HashMap<CaveID, Vec<CaveID>>,
+    supervisor: Supervisor,
 }
 
-type Path = Vec<CaveID>;
+/// The caves visited in order, from 'start' to 'end'.
+pub type Path = Vec<CaveID>;
 
 // ================================================== AUX FUNCTIONS ==================================================
 
@@ -49,7 +55,6 @@ impl Cave {
         else { cave_type = CaveType::Small }
 
         Cave {
-            id: id,
             cave_type: cave_type
         }
     }
@@ -57,7 +62,8 @@ impl Cave {
 
 impl Map {
 
-    pub fn new(links: Vec<(CaveID, CaveID)>) -> Map {
+    /// Unsolvable when two big caves are linked, paths could bounce between them forever.
+    pub fn new(links: Vec<(CaveID, CaveID)>) -> AocResult<Map> {
 
         let mut caves : HashMap<CaveID, Cave> = HashMap::new();
         let mut cave_links : HashMap<CaveID, Vec<CaveID>> = HashMap::new();
@@ -66,6 +72,9 @@ impl Map {
 
             if !caves.contains_key(&link_from) { caves.insert(link_from.clone(), Cave::new(link_from.clone())); }
             if !caves.contains_key(&link_to) { caves.insert(link_to.clone(), Cave::new(link_to.clone())); }
+            if caves[&link_from].cave_type == CaveType::Big && caves[&link_to].cave_type == CaveType::Big {
+                return Err(AocError::unsolvable(&format!("big caves '{}' and '{}' are linked, there are infinitely many paths", link_from, link_to)));
+            }
 
             if cave_links.contains_key(&link_from) {
 
@@ -82,10 +91,19 @@ impl Map {
             } else { cave_links.insert(link_to.clone(), vec!(link_from.clone())); }
         }
 
-        Map {
+        if !caves.contains_key(&get_start_id()) { return Err(AocError::malformed("there is no 'start' cave")) }
+        if !caves.contains_key(&get_end_id()) { return Err(AocError::malformed("there is no 'end' cave")) }
+
+        Ok(Map {
             caves: caves,
             cave_links: cave_links,
-        }
+            supervisor: Supervisor::new(),
+        })
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> Map {
+        self.supervisor = supervisor;
+        return self;
     }
 
     fn compute_max_number_of_small_revisited(&self, path: &Path) -> Option<(CaveID, u64)> {
@@ -106,20 +124,21 @@ impl Map {
         return max_repetitions_option;
     }
 
-    pub fn compute_paths(&self, allow_one_rep: bool) -> Vec<Path> {
+    /// Every path from 'start' to 'end', one supervisor step per partial path explored.
+    pub fn compute_paths(&mut self, allow_one_rep: bool) -> AocResult<Vec<Path>> {
 
         let mut valid_paths : Vec<Path> = Vec::new();
         let mut current_paths : Vec<Path> = vec!(vec!(get_start_id()));
         while current_paths.len() != 0 {
 
-            // println!();
             let current_path = current_paths.pop().unwrap();
-            // println!("Current Path: {:?}", current_path);
+            if self.supervisor.exhausted() {
+                return Err(self.supervisor.give_up(&format!("{} paths found so far", valid_paths.len())));
+            }
             let last_cave = current_path.last().unwrap();
 
             for possible_cave_id in self.cave_links.get(last_cave).unwrap() {
 
-                // println!("\tAdding: {}", possible_cave_id);
                 let possible_cave = self.caves.get(possible_cave_id).unwrap();
 
                 // Exceptions
@@ -130,7 +149,6 @@ impl Map {
                         { continue; }
                 if possible_cave.cave_type == CaveType::Start { continue; }
 
-                // println!("Added somewhere!");
                 // Add path
                 let mut new_path = current_path.clone();
                 new_path.push(possible_cave_id.clone());
@@ -140,7 +158,78 @@ impl Map {
             }
         }
 
+        return Ok(valid_paths);
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 visits small caves once, part 2 lets a single one be visited twice.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(CaveID, CaveID)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(CaveID, CaveID)>, InputError> {
+        return aoc_input::lines(input).enumerate()
+            .map(|(index, line)| {
+                let line_split : Vec<&str> = line.split('-').collect();
+                if line_split.len() != 2 { return Err(InputError::malformed("expected '<cave>-<cave>'", index + 1)) }
+                return Ok((line_split[0].to_owned(), line_split[1].to_owned()));
+            }).collect();
+    }
+
+    fn part1(links: &Vec<(CaveID, CaveID)>) -> AocResult<usize> { Self::part1_supervised(links, Supervisor::new()) }
+    fn part2(links: &Vec<(CaveID, CaveID)>) -> AocResult<usize> { Self::part2_supervised(links, Supervisor::new()) }
 
-        return valid_paths;
+    fn part1_supervised(links: &Vec<(CaveID, CaveID)>, supervisor: Supervisor) -> AocResult<usize> {
+        Ok(Map::new(links.clone())?.with_supervisor(supervisor).compute_paths(false)?.len())
     }
-}
\ No newline at end of file
+
+    fn part2_supervised(links: &Vec<(CaveID, CaveID)>, supervisor: Supervisor) -> AocResult<usize> {
+        Ok(Map::new(links.clone())?.with_supervisor(supervisor).compute_paths(true)?.len())
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn counts_repeated_elements() {
+        let path : Path = vec!("start".to_owned(), "b".to_owned(), "A".to_owned(), "b".to_owned());
+        assert_eq!(count_element(&path, &"b".to_owned()), 2);
+        assert_eq!(count_element(&path, &"end".to_owned()), 0);
+    }
+
+    #[test]
+    fn classifies_caves() {
+        assert!(Cave::new("start".to_owned()).cave_type == CaveType::Start);
+        assert!(Cave::new("end".to_owned()).cave_type == CaveType::End);
+        assert!(Cave::new("HN".to_owned()).cave_type == CaveType::Big);
+        assert!(Cave::new("kj".to_owned()).cave_type == CaveType::Small);
+    }
+
+    #[test]
+    fn path_search_gives_up_when_out_of_steps() {
+        use aoc_common::Budget;
+
+        let links = Day12::parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end").unwrap();
+        let mut map = Map::new(links).unwrap().with_supervisor(Supervisor::new().with_budget(Budget::unlimited().with_max_steps(3)));
+        let error = map.compute_paths(false).unwrap_err();
+        assert!(matches!(error, AocError::GaveUp { .. }));
+        assert!(error.to_string().starts_with("gave up, out of steps after 4 steps, "));
+    }
+
+    #[test]
+    fn linked_big_caves_are_unsolvable() {
+        let links = Day12::parse("start-A\nA-B\nB-end").unwrap();
+        assert!(matches!(Day12::part1(&links), Err(AocError::Unsolvable(_))));
+        assert!(matches!(Day12::part2(&links), Err(AocError::Unsolvable(_))));
+    }
+}
@@ -1,28 +1,20 @@
-mod read;
-mod lib;
-
-use lib::Map;
+use aoc_common::Solution;
+use day_12::{Map, Day12};
 
 fn main() {
 
-    let lines : Vec<String> = read::read_lines("input.txt".to_owned());
-    let info : Vec<(String, String)> = lines.into_iter()
-        .map(|line| {
-
-            let line_split : Vec<&str> = line.split("-").collect();
-            return (line_split[0].to_owned(), line_split[1].to_owned())
-        }).collect();
+    let info = Day12::parse(&aoc_input::read_to_string("../inputs/day12.txt").unwrap()).unwrap();
 
-    let map : Map = Map::new(info);
+    let mut map : Map = Map::new(info).unwrap();
     
     // Part 1
-    let paths = map.compute_paths(false);
+    let paths = map.compute_paths(false).unwrap();
     //for path in paths.iter() { println!("\t{:?}", path); }
     let count_1 = paths.len();
     println!("🏞️  The number of paths found was '{}' (Part 1)", count_1);
     
     // Part 2
-    let paths = map.compute_paths(true);
+    let paths = map.compute_paths(true).unwrap();
     //for path in paths.iter() { println!("\t{:?}", path); }
     let count_2 = paths.len();
     println!("🏞️  The number of paths found was '{}' (Part 2)", count_2);
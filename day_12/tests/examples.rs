@@ -0,0 +1,28 @@
+use aoc_common::Solution;
+use day_12::Day12;
+
+const EXAMPLE : &str = include_str!("../../inputs/day12.example.txt");
+const INPUT : &str = include_str!("../../inputs/day12.txt");
+
+fn parse(input: &str) -> <Day12 as Solution>::Input { Day12::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day12::part1(&parse(EXAMPLE)).unwrap(), 10);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day12::part2(&parse(EXAMPLE)).unwrap(), 36);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day12::part1(&parse(INPUT)).unwrap(), 5457);
+}
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn input_part2() {
+    assert_eq!(Day12::part2(&parse(INPUT)).unwrap(), 128506);
+}
@@ -1,9 +1,13 @@
+//! Day 13: Transparent Origami, folding the transparent paper to read the activation code.
+
 use std::fmt;
-use std::collections::HashMap;
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::Grid;
 
 // ================================================== STRUCTS ==================================================
 
-type CoordinateUnit = u64;
+pub type CoordinateUnit = u64;
 
 #[derive(PartialEq, Copy, Clone)]
 enum PointInfo { Free, Set, Fold }
@@ -11,56 +15,57 @@ enum PointInfo { Free, Set, Fold }
 #[derive(PartialEq, Copy, Clone)]
 enum FoldAxis { X, Y }
 
+/// The sheet of paper with its dots and the folds still to make.
 pub struct Map {
     grid_size:      (CoordinateUnit, CoordinateUnit),
-    grid:           HashMap<(CoordinateUnit, CoordinateUnit), PointInfo>,
+    grid:           Grid<PointInfo>,
     folds:          Vec<(FoldAxis, CoordinateUnit)>,
 }
 
+// Official sheets stay under 1400 dots wide, the grid of a far bigger one would not fit in memory
+const MAX_COORDINATE : CoordinateUnit = 4000;
+
 // ================================================== AUX FUNCTIONS ==================================================
 
 // ================================================== IMPLEMENTATIONS ==================================================
 
 impl Map {
 
-    pub fn new(points: Vec<(CoordinateUnit, CoordinateUnit)>, folds: Vec<(String, CoordinateUnit)>) -> Map {
-
-        let mut size_x : CoordinateUnit = 0;
-        let mut size_y : CoordinateUnit = 0;
-
-        let mut map : HashMap<(CoordinateUnit, CoordinateUnit), PointInfo> = HashMap::new();
-
-        for point in points.into_iter() {
+    /// Folds are an axis, 'x' or 'y', and the line to fold along.
+    pub fn new(points: Vec<(CoordinateUnit, CoordinateUnit)>, folds: Vec<(String, CoordinateUnit)>) -> AocResult<Map> {
 
-            map.insert(point, PointInfo::Set);
-            size_x = std::cmp::max(size_x, point.0);
-            size_y = std::cmp::max(size_y, point.1);
+        let size_x : CoordinateUnit = points.iter().map(|point| point.0).max().unwrap_or(0);
+        let size_y : CoordinateUnit = points.iter().map(|point| point.1).max().unwrap_or(0);
+        if size_x > MAX_COORDINATE || size_y > MAX_COORDINATE {
+            return Err(AocError::malformed(&format!("dots must lie within {} of the corner", MAX_COORDINATE)));
         }
 
-        for y_pos in 0..(size_y + 1) {
-            for x_pos in 0..(size_x + 1) {
-                if !map.contains_key(&(x_pos, y_pos)) {
-                    map.insert((x_pos, y_pos), PointInfo::Free);
-                }
-            }
-        }
+        let mut map : Grid<PointInfo> = Grid::filled(size_x as usize + 1, size_y as usize + 1, PointInfo::Free);
+        for point in points.into_iter() { map[(point.0 as usize, point.1 as usize)] = PointInfo::Set; }
 
         let treated_folds : Vec<(FoldAxis, CoordinateUnit)> = folds.into_iter()
             .map(|(axis, value)| {
-                if axis == "x" { return (FoldAxis::X, value); }
-                else if axis == "y" { return (FoldAxis::Y, value); }
-                else { panic!("🚨  Axis not recognized '{}'!", axis); }
-            }).rev().collect();
+                if value == 0 { return Err(AocError::malformed("cannot fold along the first line")); }
+                if axis == "x" { return Ok((FoldAxis::X, value)); }
+                else if axis == "y" { return Ok((FoldAxis::Y, value)); }
+                else { return Err(AocError::malformed(&format!("axis not recognized '{}'", axis))); }
+            }).rev().collect::<AocResult<Vec<(FoldAxis, CoordinateUnit)>>>()?;
 
-        Map {
+        Ok(Map {
             grid_size: (size_x, size_y),
             grid: map,
             folds: treated_folds,
-        }
+        })
     }
 
     pub fn number_of_folds(&self) -> usize { self.folds.len() }
 
+    // A fold line past the last dot has nothing to mark
+    fn set_fold(&mut self, position: (CoordinateUnit, CoordinateUnit)) {
+        if let Some(point) = self.grid.get_mut((position.0 as usize, position.1 as usize)) { *point = PointInfo::Fold }
+    }
+
+    /// Folds along the next line, nothing happens when there are no folds left.
     pub fn make_next_fold(&mut self) {
 
         if self.folds.len() == 0 { return }
@@ -70,7 +75,7 @@ impl Map {
 
             FoldAxis::X => {
 
-                for y_pos in 0..(self.grid_size.1 + 1) { self.grid.insert((fold.1, y_pos), PointInfo::Fold); }
+                for y_pos in 0..(self.grid_size.1 + 1) { self.set_fold((fold.1, y_pos)); }
                 let mut still_valid : bool = true;
                 let mut x_variation : CoordinateUnit = 1;
 
@@ -84,9 +89,8 @@ impl Map {
                         let left : CoordinateUnit = fold.1 - x_variation;
                         let right : CoordinateUnit = fold.1 + x_variation;
                         for y_pos in 0..(self.grid_size.1 + 1) {
-                            let &right_side_value = self.grid.get(&(right, y_pos)).unwrap();
-                            if right_side_value == PointInfo::Set {
-                                self.grid.insert((left, y_pos), PointInfo::Set);
+                            if self.grid[(right as usize, y_pos as usize)] == PointInfo::Set {
+                                self.grid[(left as usize, y_pos as usize)] = PointInfo::Set;
                             }
                         }
                     }
@@ -94,12 +98,13 @@ impl Map {
                     x_variation = x_variation + 1;
                 }
 
-                self.grid_size.0 = fold.1 - 1;
+                // A fold past the last dot leaves the sheet as it was
+                self.grid_size.0 = self.grid_size.0.min(fold.1 - 1);
             }
 
             FoldAxis::Y => {
 
-                for x_pos in 0..(self.grid_size.0 + 1) { self.grid.insert((x_pos, fold.1), PointInfo::Fold); }
+                for x_pos in 0..(self.grid_size.0 + 1) { self.set_fold((x_pos, fold.1)); }
                 let mut still_valid : bool = true;
                 let mut y_variation : CoordinateUnit = 1;
 
@@ -113,9 +118,8 @@ impl Map {
                         let top : CoordinateUnit = fold.1 - y_variation;
                         let bottom : CoordinateUnit = fold.1 + y_variation;
                         for x_pos in 0..(self.grid_size.0 + 1) {
-                            let &bottom_side_value = self.grid.get(&(x_pos, bottom)).unwrap();
-                            if bottom_side_value == PointInfo::Set {
-                                self.grid.insert((x_pos, top), PointInfo::Set);
+                            if self.grid[(x_pos as usize, bottom as usize)] == PointInfo::Set {
+                                self.grid[(x_pos as usize, top as usize)] = PointInfo::Set;
                             }
                         }
                     }
@@ -123,19 +127,33 @@ impl Map {
                     y_variation = y_variation + 1;
                 }
 
-                self.grid_size.1 = fold.1 - 1;
+                self.grid_size.1 = self.grid_size.1.min(fold.1 - 1);
             }
         }
     }
 
+    /// The whole sheet as first seen, with the parts already folded away left blank.
+    pub fn get_sheet(&self) -> Grid<char> {
+        return Grid::from_fn(self.grid.width(), self.grid.height(), |position| {
+
+            let visible : bool = position.0 as CoordinateUnit <= self.grid_size.0 && position.1 as CoordinateUnit <= self.grid_size.1;
+            return match self.grid[position] {
+                _ if !visible => ' ',
+                PointInfo::Free => '.',
+                PointInfo::Set => '#',
+                PointInfo::Fold => '-',
+            };
+        });
+    }
+
+    /// Number of visible dots.
     pub fn count_set(&self) -> u64 {
 
         let mut count : u64 = 0;
         for y_value in 0..(self.grid_size.1 + 1) {
             for x_value in 0..(self.grid_size.0 + 1) {
 
-                let &point_info = self.grid.get(&(x_value, y_value)).unwrap();
-                if point_info == PointInfo::Set {
+                if self.grid[(x_value as usize, y_value as usize)] == PointInfo::Set {
                     count = count + 1;
                 }
             }
@@ -153,7 +171,7 @@ impl fmt::Display for Map {
 
             for x_value in 0..(self.grid_size.0 + 1) {
 
-                let &point_info = self.grid.get(&(x_value, y_value)).unwrap();
+                let point_info = self.grid[(x_value as usize, y_value as usize)];
                 if point_info == PointInfo::Free { line = format!("{} .", line) }
                 else if point_info == PointInfo::Set { line = format!("{} #", line) }
                 else { line = format!("{} -", line) }
@@ -165,4 +183,89 @@ impl fmt::Display for Map {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 counts the dots after the first fold, part 2 draws the code left after all of them.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<(CoordinateUnit, CoordinateUnit)>, Vec<(String, CoordinateUnit)>);
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let mut points : Vec<(CoordinateUnit, CoordinateUnit)> = Vec::new();
+        let mut folds : Vec<(String, CoordinateUnit)> = Vec::new();
+        let mut reading_folds : bool = false;
+        for (index, line) in aoc_input::lines(input).enumerate() {
+
+            if line == "" { reading_folds = true }
+            else if !reading_folds {
+                let values : Vec<CoordinateUnit> = aoc_input::parse_list(line, ",", index + 1)?;
+                if values.len() != 2 { return Err(InputError::malformed("expected 'x,y'", index + 1)) }
+                points.push((values[0], values[1]));
+            } else {
+                let fold_info : Vec<&str> = line.trim_start_matches("fold along ").split('=').collect();
+                if fold_info.len() != 2 || (fold_info[0] != "x" && fold_info[0] != "y") {
+                    return Err(InputError::malformed("expected 'fold along <x|y>=<value>'", index + 1));
+                }
+                folds.push((fold_info[0].to_owned(), aoc_input::parse_value(fold_info[1], index + 1, 14)?));
+            }
+        }
+
+        return Ok((points, folds));
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<u64> {
+        let mut map = Map::new(input.0.clone(), input.1.clone())?;
+        map.make_next_fold();
+        return Ok(map.count_set());
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<String> {
+        let mut map = Map::new(input.0.clone(), input.1.clone())?;
+        while map.number_of_folds() != 0 { map.make_next_fold(); }
+        return Ok(format!("\n{}", map));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn folding_overlaps_dots() {
+        let mut map = Map::new(vec!((0, 0), (0, 4), (2, 1)), vec!(("y".to_owned(), 2))).unwrap();
+        assert_eq!(map.count_set(), 3);
+
+        map.make_next_fold();
+        assert_eq!(map.number_of_folds(), 0);
+        assert_eq!(map.count_set(), 2);
+        assert_eq!(map.get_sheet().render(|&point| point), "#..\n..#\n   \n   \n   \n");
+    }
+
+    #[test]
+    fn folding_past_the_sheet_changes_nothing() {
+        let mut map = Map::new(vec!((1, 1)), vec!(("y".to_owned(), 5))).unwrap();
+        map.make_next_fold();
+        assert_eq!(map.count_set(), 1);
+        assert_eq!(map.to_string(), " . .\n . #\n");
+        assert_eq!(map.get_sheet().render(|&point| point), "..\n.#\n");
+    }
+
+    #[test]
+    fn dots_far_from_the_corner_are_rejected() {
+        assert!(matches!(Map::new(vec!((1000000000, 1000000000)), Vec::new()), Err(AocError::Input(_))));
+    }
+
+    #[test]
+    fn folding_along_unknown_axis_is_rejected() {
+        assert!(Map::new(vec!((0, 0)), vec!(("z".to_owned(), 2))).is_err());
+    }
+}
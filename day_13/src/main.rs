@@ -1,33 +1,11 @@
-use std::io::{Error, ErrorKind};
-
-mod read;
-mod lib;
-
-use lib::Map;
+use aoc_common::Solution;
+use day_13::{Map, Day13};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let index_of = lines.iter().position(|line| line == "").unwrap();
-
-    let input_values: Vec<Vec<i64>> = lines[..index_of].iter()
-        .map(|line| line.split(',')
-            .map(|line| line.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
-            .collect::<Result<_, _>>().unwrap())
-        .collect::<Vec<Vec<i64>>>();
-    let pairs_input_values : Vec<(u64, u64)>= input_values.into_iter()
-        .map(|row| (row[0] as u64, row[1] as u64))
-        .collect();
-
-    let fold_info : Vec<(String, u64)> = lines[(index_of + 1)..].iter()
-        .map(|line| line.replace("fold along ", "")
-            .split("=")
-            .map(|value| value.to_owned())
-            .collect::<Vec<String>>())
-        .map(|line| (line[0].clone(), line[1].parse().unwrap()))
-        .collect();
+    let (pairs_input_values, fold_info) = Day13::parse(&aoc_input::read_to_string("../inputs/day13.txt").unwrap()).unwrap();
 
-    let mut map : Map = Map::new(pairs_input_values, fold_info);
+    let mut map : Map = Map::new(pairs_input_values, fold_info).unwrap();
     //println!("{}", map);
     
     
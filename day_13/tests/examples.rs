@@ -0,0 +1,35 @@
+use aoc_common::Solution;
+use day_13::Day13;
+
+const EXAMPLE : &str = include_str!("../../inputs/day13.example.txt");
+const INPUT : &str = include_str!("../../inputs/day13.txt");
+
+fn parse(input: &str) -> <Day13 as Solution>::Input { Day13::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day13::part1(&parse(EXAMPLE)).unwrap(), 17);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day13::part2(&parse(EXAMPLE)).unwrap(), "\n # # # # #\n # . . . #\n # . . . #\n # . . . #\n # # # # #\n . . . . .\n . . . . .\n");
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day13::part1(&parse(INPUT)).unwrap(), 610);
+}
+
+#[test]
+fn input_part2() {
+    let letters = [
+        " # # # . . # # # # . # # # # . . . # # . # . . # . # # # . . # # # # . # # # # .",
+        " # . . # . . . . # . # . . . . . . . # . # . . # . # . . # . # . . . . . . . # .",
+        " # . . # . . . # . . # # # . . . . . # . # # # # . # . . # . # # # . . . . # . .",
+        " # # # . . . # . . . # . . . . . . . # . # . . # . # # # . . # . . . . . # . . .",
+        " # . . . . # . . . . # . . . . # . . # . # . . # . # . # . . # . . . . # . . . .",
+        " # . . . . # # # # . # . . . . . # # . . # . . # . # . . # . # . . . . # # # # .",
+    ];
+    assert_eq!(Day13::part2(&parse(INPUT)).unwrap(), format!("\n{}\n", letters.join("\n")));
+}
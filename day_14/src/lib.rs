@@ -1,12 +1,17 @@
+//! Day 14: Extended Polymerization, growing polymers from a template and pair insertion rules.
+
 use std::fmt;
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
-type Element = char;
-type Polymer = Vec<Element>;
+pub type Element = char;
+pub type Polymer = Vec<Element>;
 
 
+/// Builds the whole polymer on every step, its length doubles each time.
 pub struct Polymerization {
     rules:              HashMap<(Element, Element), Element>,
     // Incremental change
@@ -14,6 +19,7 @@ pub struct Polymerization {
     current_polymer:    Polymer
 }
 
+/// Only counts how many times each pair of elements appears, so the polymer can grow for any number of steps.
 pub struct ExpPolymerization {
     template:           Polymer,
     rules:              HashMap<(Element, Element), Element>,
@@ -28,6 +34,7 @@ pub struct ExpPolymerization {
 
 impl Polymerization {
 
+    /// Every rule inserts its third element between the first two.
     pub fn new(template: Polymer, rules: Vec<(Element, Element, Element)>) -> Polymerization {
 
         let rules_converted : HashMap<(Element, Element), Element> = rules.into_iter()
@@ -66,6 +73,8 @@ impl Polymerization {
         self.iteration = self.iteration + 1;
     }
 
+    /// How many times each element is in the polymer.
+    /// How many times each element is in the polymer.
     pub fn count_element(&self) -> Vec<(Element, usize)> {
 
         let mut count : HashMap<Element, usize> = HashMap::new();
@@ -74,11 +83,10 @@ impl Polymerization {
             if count.contains_key(&element) {
                 let current_value = count.get_mut(&element).unwrap();
                 *current_value = *current_value + 1; 
-            } else { count.insert(element, 0); }
+            } else { count.insert(element, 1); }
         }
 
         let mut count_vec : Vec<(Element, usize)> = count.into_iter()
-            .map(|(element, count)| (element, count))
             .collect();
         count_vec.sort_by_key(|element| element.1);
 
@@ -88,12 +96,19 @@ impl Polymerization {
 
 impl ExpPolymerization {
 
-    pub fn new(template: Polymer, rules: Vec<(Element, Element, Element)>) -> ExpPolymerization {
+    pub fn new(template: Polymer, rules: Vec<(Element, Element, Element)>) -> AocResult<ExpPolymerization> {
 
         let rules_converted : HashMap<(Element, Element), Element> = rules.iter()
             .map(|rule| ((rule.0, rule.1), rule.2))
             .collect();
 
+        // Only pairs are counted, so every pair that can ever appear needs a rule
+        let template_pairs = template.windows(2).map(|pair| (pair[0], pair[1]));
+        let generated_pairs = rules.iter().flat_map(|rule| vec!((rule.0, rule.2), (rule.2, rule.1)));
+        if let Some(pair) = template_pairs.chain(generated_pairs).find(|pair| !rules_converted.contains_key(pair)) {
+            return Err(AocError::malformed(&format!("there is no rule for the pair '{}{}'", pair.0, pair.1)));
+        }
+
         let mut current_count_convert : HashMap<(Element, Element), usize> = rules.iter()
             .map(|rule| ((rule.0, rule.1), 0))
             .collect();
@@ -104,13 +119,13 @@ impl ExpPolymerization {
             *current_count_seq = *current_count_seq + 1;
         }
 
-        ExpPolymerization {
+        Ok(ExpPolymerization {
             template: template,
             rules: rules_converted,
             // Incremental change
             iteration: 0,
             current_count: current_count_convert
-        }
+        })
     }
 
     pub fn get_current_iteration(&self) -> usize { self.iteration }
@@ -160,8 +175,9 @@ impl ExpPolymerization {
 
         } else { count.insert(*last_elem, 1); }
 
+        // Elements only found in the rules may never be generated, they are not part of the polymer
         let mut count_vec : Vec<(Element, usize)> = count.into_iter()
-            .map(|(element, count)| (element, count))
+            .filter(|&(_, element_count)| element_count != 0)
             .collect();
         count_vec.sort_by_key(|element| element.1);
 
@@ -177,4 +193,83 @@ impl fmt::Display for Polymerization {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Difference between the most and least common elements after 10 steps in part 1 and 40 in part 2.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Polymer, Vec<(Element, Element, Element)>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let lines : Vec<&str> = aoc_input::lines(input).collect();
+        if lines.len() < 2 || lines[0] == "" || lines[1] != "" {
+            return Err(InputError::malformed("expected a template followed by an empty line", 1));
+        }
+
+        let template : Polymer = lines[0].chars().collect();
+        let rules = lines[2..].iter().enumerate()
+            .map(|(index, line)| {
+
+                let line_split : Vec<&str> = line.split(" -> ").collect();
+                let origin : Vec<Element> = line_split[0].chars().collect();
+                let result : Vec<Element> = line_split.get(1).map(|result| result.chars().collect()).unwrap_or_default();
+                if line_split.len() != 2 || origin.len() != 2 || result.len() != 1 {
+                    return Err(InputError::malformed("expected '<element><element> -> <element>'", index + 3));
+                }
+
+                return Ok((origin[0], origin[1], result[0]));
+            }).collect::<Result<_, _>>()?;
+
+        return Ok((template, rules));
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<usize> {
+        let mut polymerization = Polymerization::new(input.0.clone(), input.1.clone());
+        while polymerization.get_current_iteration() != 10 { polymerization.do_iteration() }
+
+        let counts = polymerization.count_element();
+        return Ok(counts.last().unwrap().1 - counts.first().unwrap().1);
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<usize> {
+        let mut polymerization = ExpPolymerization::new(input.0.clone(), input.1.clone())?;
+        while polymerization.get_current_iteration() != 40 { polymerization.do_iteration() }
+
+        let counts = polymerization.count_element();
+        return Ok(counts.last().unwrap().1 - counts.first().unwrap().1);
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn polymer_grows_between_pairs() {
+        let mut polymerization = Polymerization::new(vec!('N', 'N', 'C', 'B'), vec!(('N', 'N', 'C'), ('N', 'C', 'B'), ('C', 'B', 'H')));
+        polymerization.do_iteration();
+        assert_eq!(polymerization.current_polymer.iter().collect::<String>(), "NCNBCHB");
+    }
+
+    #[test]
+    fn empty_count_covers_every_rule() {
+        let polymerization = ExpPolymerization::new(vec!('N', 'N'), vec!(('N', 'N', 'C'), ('N', 'C', 'N'), ('C', 'N', 'C'), ('C', 'C', 'N'))).unwrap();
+        let empty_count = polymerization.generate_empty_count();
+        assert_eq!(empty_count.len(), 4);
+        assert!(empty_count.values().all(|&count| count == 0));
+    }
+
+    #[test]
+    fn missing_pair_rule_is_rejected() {
+        assert!(ExpPolymerization::new(vec!('N', 'N'), vec!(('N', 'N', 'C'))).is_err());
+    }
+}
@@ -1,24 +1,12 @@
-mod read;
-mod lib;
-
-use lib::{Polymerization, ExpPolymerization};
+use aoc_common::Solution;
+use day_14::{Polymerization, ExpPolymerization, Day14};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let template : Vec<char> = lines[0].chars().collect();
-    let mut rules : Vec<(char, char, char)> = Vec::new();
-    for line in lines[2..].into_iter() {
-
-        let line_split : Vec<&str> = line.split(" -> ").collect();
-        let origin : Vec<char> = line_split[0].chars().collect();
-        let result : Vec<char> = line_split[1].chars().collect();
-
-        rules.push((origin[0], origin[1], result[0]));
-    }
+    let (template, rules) = Day14::parse(&aoc_input::read_to_string("../inputs/day14.txt").unwrap()).unwrap();
 
     let mut polymerization = Polymerization::new(template.clone(), rules.clone());
-    let mut exp_polymerization = ExpPolymerization::new(template, rules);
+    let mut exp_polymerization = ExpPolymerization::new(template, rules).unwrap();
     
     // Part 1
 
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_14::Day14;
+
+const EXAMPLE : &str = include_str!("../../inputs/day14.example.txt");
+const INPUT : &str = include_str!("../../inputs/day14.txt");
+
+fn parse(input: &str) -> <Day14 as Solution>::Input { Day14::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day14::part1(&parse(EXAMPLE)).unwrap(), 1588);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day14::part2(&parse(EXAMPLE)).unwrap(), 2188189693529);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day14::part1(&parse(INPUT)).unwrap(), 2375);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day14::part2(&parse(INPUT)).unwrap(), 1976896901756);
+}
@@ -0,0 +1,44 @@
+use proptest::prelude::*;
+
+use day_14::{ExpPolymerization, Polymerization};
+
+const ELEMENTS : [char; 4] = ['B', 'C', 'H', 'N'];
+
+/// A rule for every pair of elements, each inserting the element at the given index.
+fn build_rules(insertions: &Vec<usize>) -> Vec<(char, char, char)> {
+    return ELEMENTS.iter()
+        .flat_map(|&first| ELEMENTS.iter().map(move |&second| (first, second)))
+        .zip(insertions.iter())
+        .map(|((first, second), &insertion)| (first, second, ELEMENTS[insertion]))
+        .collect();
+}
+
+fn sorted(mut counts: Vec<(char, usize)>) -> Vec<(char, usize)> {
+    counts.sort();
+    return counts;
+}
+
+proptest! {
+
+    #[test]
+    fn both_polymerizations_count_alike(
+        template in prop::collection::vec(prop::sample::select(ELEMENTS.to_vec()), 1..8),
+        insertions in prop::collection::vec(0..ELEMENTS.len(), ELEMENTS.len() * ELEMENTS.len()),
+        steps in 0..8usize,
+    ) {
+
+        let rules = build_rules(&insertions);
+        let mut polymerization = Polymerization::new(template.clone(), rules.clone());
+        let mut exp_polymerization = ExpPolymerization::new(template.clone(), rules).unwrap();
+        while polymerization.get_current_iteration() != steps {
+            polymerization.do_iteration();
+            exp_polymerization.do_iteration();
+        }
+
+        let counts = sorted(polymerization.count_element());
+        prop_assert_eq!(&counts, &sorted(exp_polymerization.count_element()));
+        // Every step inserts an element between each pair
+        let expected_length : usize = (template.len() - 1) * (1 << steps) + 1;
+        prop_assert_eq!(counts.iter().map(|count| count.1).sum::<usize>(), expected_length);
+    }
+}
@@ -1,11 +1,14 @@
+//! Day 15: Chiton, finding the path of lowest risk through the cave.
+
 use std::fmt;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::{Grid, Position};
 
 // ================================================== STRUCTS ==================================================
 
-type RiskLevel = u32;
-type PositionUnit = u32;
-type Position = (PositionUnit, PositionUnit);
+pub type RiskLevel = u32;
 
 #[derive(Clone, Copy)]
 struct Node {
@@ -14,10 +17,11 @@ struct Node {
     min_distance:   Option<RiskLevel>,
 }
 
+/// The risk level of every position of the cave, entering a position costs its risk.
 pub struct Map {
     map_start:      Position,
     map_end:        Position,
-    map_risks:      HashMap<Position, Node>,
+    map_risks:      Grid<Node>,
 }
 
 // ================================================== AUX FUNCTIONS ==================================================
@@ -26,103 +30,66 @@ pub struct Map {
 
 impl Map {
 
-    pub fn new(values: Vec<Vec<RiskLevel>>, tiling: usize) -> Map {
-
-        let map_start : Position = (0, 0);
-
-        let tile_end_x : PositionUnit = values.iter().map(|line| line.len()).max().unwrap() as PositionUnit;
-        let tile_end_y : PositionUnit = values.len() as PositionUnit;
-
-        let map_end_x : PositionUnit = tile_end_x * (tiling as PositionUnit) - 1;
-        let map_end_y : PositionUnit = tile_end_y * (tiling as PositionUnit) - 1;
-        let map_end : Position = (map_end_x, map_end_y);
-
-        let mut map_risks : HashMap<Position, Node> = HashMap::new();
-
-        // Iterate Values
-        for (row_index, row) in values.into_iter().enumerate() {
-            for (column_index, value) in row.into_iter().enumerate() {
-
-                // Place values in each tile
-                for x_tile in 0..tiling {
-                    for y_tile in 0..tiling {
-
-                        let tile_value : RiskLevel = x_tile as RiskLevel + y_tile as RiskLevel;
-                        let cell_value : RiskLevel = ( value - 1 + tile_value ) % 9 + 1;
-
-                        let position_x : PositionUnit = (column_index as PositionUnit) + x_tile as PositionUnit * tile_end_x;
-                        let position_y : PositionUnit = (row_index as PositionUnit) + y_tile as PositionUnit * tile_end_y;
+    /// The cave is the given tile repeated `tiling` times in each direction.
+    pub fn new(values: Vec<Vec<RiskLevel>>, tiling: usize) -> AocResult<Map> {
 
-                        let node : Node = Node{ enter_risk: cell_value, closed: false, min_distance: None };
-                        map_risks.insert((position_x, position_y), node);
+        let tile = Grid::from_rows(values)?;
+        if tiling == 0 { return Err(AocError::malformed("the map needs at least one tile")) }
+        if tile.iter().any(|(_, &risk)| risk == 0) { return Err(AocError::malformed("risk levels go from 1 to 9")) }
 
-                    }
-                }
+        // Each tile to the right or below adds one to the risk, wrapping from 9 back to 1
+        let map_risks : Grid<Node> = Grid::from_fn(tile.width() * tiling, tile.height() * tiling, |(x, y)| {
 
-            }
-        }
+            let tile_value : RiskLevel = (x / tile.width() + y / tile.height()) as RiskLevel;
+            let value : RiskLevel = tile[(x % tile.width(), y % tile.height())];
+            let cell_value : RiskLevel = ( value - 1 + tile_value ) % 9 + 1;
+            return Node{ enter_risk: cell_value, closed: false, min_distance: None };
+        });
 
-        Map {
-            map_start: map_start,
-            map_end: map_end,
+        Ok(Map {
+            map_start: (0, 0),
+            map_end: (map_risks.width() - 1, map_risks.height() - 1),
             map_risks: map_risks,
-        }
+        })
 
     }
 
-    pub fn get_shortest_path(&mut self) -> Option<RiskLevel> {
+    /// Lowest total risk from the top left to the bottom right corner.
+    pub fn get_shortest_path(&mut self) -> AocResult<RiskLevel> {
 
-        let start_node = self.map_risks.get_mut(&self.map_start).unwrap();
-        start_node.min_distance = Some(0);
+        self.map_risks[self.map_start].min_distance = Some(0);
         let mut possible_points_to_expand : HashSet<Position> = HashSet::new();
-        possible_points_to_expand.insert(self.map_start.clone());
-
-        while !self.map_risks.get(&self.map_end).as_ref().unwrap().closed {
+        possible_points_to_expand.insert(self.map_start);
 
-            if possible_points_to_expand.len() == 0 { panic!("🚨 No points to expand!") }
+        while !self.map_risks[self.map_end].closed {
 
-            let to_expand = possible_points_to_expand.iter()
-                .filter(|&position| self.map_risks.contains_key(position))
-                .map(|&position| (position, self.map_risks.get(&position).unwrap()))
-                .min_by(|(_, &a_node), (_, &b_node)| a_node.min_distance.unwrap().cmp(&b_node.min_distance.unwrap()))
-                .unwrap().0;
+            if possible_points_to_expand.len() == 0 { return Err(AocError::unsolvable("the end cannot be reached")) }
 
-            let to_expand_distance : RiskLevel = self.map_risks.get(&to_expand).unwrap().min_distance.unwrap();
+            let to_expand = *possible_points_to_expand.iter()
+                .min_by_key(|&&position| self.map_risks[position].min_distance.unwrap())
+                .unwrap();
 
-            for var_x in -1..=1 {
-                for var_y in -1..=1 {
+            let to_expand_distance : RiskLevel = self.map_risks[to_expand].min_distance.unwrap();
 
-                    // Do not stay in the same spot
-                    if var_x == 0 && var_y == 0 { continue }
-                    // Do not move diagonally
-                    if var_x != 0 && var_y != 0 { continue }
+            for new_position in self.map_risks.neighbours(to_expand) {
 
-                    let new_x = to_expand.0 as i32 + var_x;
-                    let new_y = to_expand.1 as i32 + var_y;
-                    if new_x < 0 || new_y < 0 { continue }
+                let new_node = &mut self.map_risks[new_position];
+                if new_node.closed { continue }
 
-                    let new_position : Position = ( new_x as PositionUnit, new_y as PositionUnit );
-                    let new_node_option = self.map_risks.get_mut(&new_position);
-                    if new_node_option.is_none() { continue }
+                let new_distance = to_expand_distance + new_node.enter_risk;
 
-                    let new_node = new_node_option.unwrap();
-                    if new_node.closed { continue }
-
-                    let new_distance = to_expand_distance + new_node.enter_risk;
-
-                    if new_node.min_distance.is_none() { new_node.min_distance = Some(new_distance); }
-                    else if new_node.min_distance.unwrap() > new_distance { new_node.min_distance = Some(new_distance); }
-
-                    possible_points_to_expand.insert(new_position);
+                if new_node.min_distance.is_none() || new_node.min_distance.unwrap() > new_distance {
+                    new_node.min_distance = Some(new_distance);
                 }
+
+                possible_points_to_expand.insert(new_position);
             }
 
-            let to_expand_node = self.map_risks.get_mut(&to_expand).unwrap();
-            to_expand_node.closed = true;
+            self.map_risks[to_expand].closed = true;
             possible_points_to_expand.remove(&to_expand);
         }
 
-        return self.map_risks.get(&self.map_end).unwrap().min_distance;
+        return Ok(self.map_risks[self.map_end].min_distance.unwrap());
     }
 }
 
@@ -130,12 +97,8 @@ impl fmt::Display for Map {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
         let mut line : String = String::new();
-        for y_value in (self.map_start.1)..=(self.map_end.1) {
-            for x_value in (self.map_start.0)..=(self.map_end.0) {
-
-                let point = self.map_risks.get(&(x_value, y_value));
-                if point.is_some() { line = format!("{} {}", line, &point.unwrap().enter_risk); }
-            }
+        for row in self.map_risks.rows() {
+            for point in row { line = format!("{} {}", line, point.enter_risk); }
 
             line = format!("{}\n", line);
 
@@ -143,4 +106,50 @@ impl fmt::Display for Map {
 
         return write!(f, "{}", line);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 crosses the cave as given, part 2 the cave tiled five times in each direction.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Vec<RiskLevel>>;
+    type Answer1 = RiskLevel;
+    type Answer2 = RiskLevel;
+
+    fn parse(input: &str) -> Result<Vec<Vec<RiskLevel>>, InputError> { aoc_input::read_digits_from(input.as_bytes()) }
+    fn part1(values: &Vec<Vec<RiskLevel>>) -> AocResult<RiskLevel> { Map::new(values.clone(), 1)?.get_shortest_path() }
+    fn part2(values: &Vec<Vec<RiskLevel>>) -> AocResult<RiskLevel> { Map::new(values.clone(), 5)?.get_shortest_path() }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn shortest_path_avoids_risky_cells() {
+        let mut map = Map::new(vec!(vec!(1, 1), vec!(9, 1)), 1).unwrap();
+        assert_eq!(map.get_shortest_path().unwrap(), 2);
+    }
+
+    #[test]
+    fn tiling_wraps_risk_back_to_one() {
+        // Tiles hold 8, 9 / 9, 1 so the cheapest path costs 9 + 1
+        let mut map = Map::new(vec!(vec!(8)), 2).unwrap();
+        assert_eq!(map.get_shortest_path().unwrap(), 10);
+    }
+
+    #[test]
+    fn ragged_map_is_rejected() {
+        assert!(Map::new(vec!(vec!(1, 1), vec!(1)), 1).is_err());
+    }
+
+    #[test]
+    fn zero_risk_is_rejected() {
+        assert!(Map::new(vec!(vec!(1, 0)), 1).is_err());
+    }
+}
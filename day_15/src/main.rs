@@ -1,13 +1,11 @@
-mod read;
-mod lib;
-
-use lib::{Map};
+use aoc_common::Solution;
+use day_15::{Map, Day15};
 
 fn main() {
 
-    let values = read::read_digits("input.txt".to_owned());
-    let mut map = Map::new(values.clone(), 1); 
-    let mut tilled_map = Map::new(values, 5); 
+    let values = Day15::parse(&aoc_input::read_to_string("../inputs/day15.txt").unwrap()).unwrap();
+    let mut map = Map::new(values.clone(), 1).unwrap();
+    let mut tilled_map = Map::new(values, 5).unwrap();
     
     // Part 1
     let risk_level = map.get_shortest_path().unwrap();
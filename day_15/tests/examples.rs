@@ -0,0 +1,28 @@
+use aoc_common::Solution;
+use day_15::Day15;
+
+const EXAMPLE : &str = include_str!("../../inputs/day15.example.txt");
+const INPUT : &str = include_str!("../../inputs/day15.txt");
+
+fn parse(input: &str) -> <Day15 as Solution>::Input { Day15::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day15::part1(&parse(EXAMPLE)).unwrap(), 40);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day15::part2(&parse(EXAMPLE)).unwrap(), 315);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day15::part1(&parse(INPUT)).unwrap(), 403);
+}
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn input_part2() {
+    assert_eq!(Day15::part2(&parse(INPUT)).unwrap(), 2840);
+}
@@ -1,8 +1,12 @@
+//! Day 16: Packet Decoder, decoding the BITS transmission into its nested packets.
+
 use std::fmt;
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 // ================================================== STRUCTS ==================================================
 
-type ID = u64;
+pub type ID = u64;
 
 #[derive(Copy, Clone)]
 enum PacketTypeEnum {
@@ -21,6 +25,7 @@ struct PacketType {
     packet_type_enum:   PacketTypeEnum,
 }
 
+/// A literal value or an operator over its sub packets.
 pub struct Packet {
     version_id:     ID,
     packet_type:    PacketType,
@@ -31,6 +36,7 @@ pub struct Packet {
     value:          Option<u64>,
 }
 
+/// The outermost packets of a transmission.
 pub struct SystemBITS {
     packets:        Vec<Packet>,
 }
@@ -41,8 +47,8 @@ fn all_zeros(transmission_bin: &String) -> bool {
     return !transmission_bin.chars().any(|characther| characther == '1');
 }
 
-fn convert_hex_binary(hex_char: char) -> String {
-    match hex_char {
+fn convert_hex_binary(hex_char: char) -> AocResult<String> {
+    let binary = match hex_char {
         '0' => String::from("0000"),
         '1' => String::from("0001"),
         '2' => String::from("0010"),
@@ -59,11 +65,18 @@ fn convert_hex_binary(hex_char: char) -> String {
         'D' => String::from("1101"),
         'E' => String::from("1110"),
         'F' => String::from("1111"),
-        _ => panic!("🚨  Characther not recognized as hexadecimal: {}", hex_char),
-    }
+        _ => return Err(AocError::malformed(&format!("characther not recognized as hexadecimal '{}'", hex_char))),
+    };
+
+    return Ok(binary);
 }
 
-fn convert_id_packet_type(packet_type_id: ID) -> PacketType {
+fn check_length(info: &[char], length: usize) -> AocResult<()> {
+    if info.len() < length { return Err(AocError::malformed("transmission ends in the middle of a packet")) }
+    return Ok(());
+}
+
+fn convert_id_packet_type(packet_type_id: ID) -> AocResult<PacketType> {
 
     let type_enum : PacketTypeEnum = match packet_type_id {
         0 =>    PacketTypeEnum::Sum,
@@ -74,13 +87,13 @@ fn convert_id_packet_type(packet_type_id: ID) -> PacketType {
         5 =>    PacketTypeEnum::GreaterThan,
         6 =>    PacketTypeEnum::LessThan,
         7 =>    PacketTypeEnum::EqualTo,
-        _ =>    panic!("🚨  No known PacketType")
+        _ =>    return Err(AocError::malformed(&format!("no known packet type '{}'", packet_type_id))),
     };
 
-    PacketType {
+    Ok(PacketType {
         id:                 packet_type_id,
         packet_type_enum:   type_enum,
-    }
+    })
 }
 
 fn convert_packet_type_string(packet_type: PacketTypeEnum) -> String {
@@ -100,14 +113,14 @@ fn convert_packet_type_string(packet_type: PacketTypeEnum) -> String {
 
 impl Packet {
 
-    fn get_literal_from_info(info: String) -> (u64, String) {
+    fn get_literal_from_info(info: String) -> AocResult<(u64, String)> {
 
         const LITERAL_SIZE : usize = 5;
 
         let info_chunks : Vec<Vec<char>> = info.chars()
             .collect::<Vec<char>>()
             .chunks(LITERAL_SIZE)
-            .map(|chunk| chunk.into_iter()
+            .map(|chunk| chunk.iter()
                 .collect::<String>().chars()
                 .collect::<Vec<char>>())
             .collect();
@@ -120,6 +133,7 @@ impl Packet {
             if reached_zero {
                 for characther in info_chunk { remaining_info.push(characther) }
             } else {
+                check_length(&info_chunk, LITERAL_SIZE)?;
                 let bit = info_chunk[0];
                 let usable_info = info_chunk[1..].to_vec();
                 for characther in usable_info { literal_binary.push(characther) }
@@ -128,12 +142,17 @@ impl Packet {
             
         }
 
-        return (ID::from_str_radix(&literal_binary, 2).unwrap(), remaining_info)
+        if !reached_zero { return Err(AocError::malformed("literal has no last group")) }
+        let literal = ID::from_str_radix(&literal_binary, 2)
+            .map_err(|_| AocError::malformed("literal does not fit in 64 bits"))?;
+
+        return Ok((literal, remaining_info));
     }
 
-    fn get_sub_packets_from_info(info: String) -> (Vec<Packet>, String) {
+    fn get_sub_packets_from_info(info: String) -> AocResult<(Vec<Packet>, String)> {
 
         let mut info_characthers : Vec<char> = info.chars().collect();
+        check_length(&info_characthers, 1)?;
         let length_bit = info_characthers[0];
         info_characthers = info_characthers[1..].to_vec();
 
@@ -142,49 +161,51 @@ impl Packet {
 
         if length_bit == '0' {
 
-            let total_length_bin : String = info_characthers[0..15].to_vec()
-                .into_iter().collect::<String>();
+            check_length(&info_characthers, 15)?;
+            let total_length_bin : String = info_characthers[0..15].iter().collect::<String>();
             info_characthers = info_characthers[15..].to_vec();
             let total_length = usize::from_str_radix(&total_length_bin, 2).unwrap();
 
-            let mut info_for_packets : String = info_characthers[0..total_length].into_iter().collect();
-            remaining_info = info_characthers[total_length..].into_iter().collect();
+            check_length(&info_characthers, total_length)?;
+            let mut info_for_packets : String = info_characthers[0..total_length].iter().collect();
+            remaining_info = info_characthers[total_length..].iter().collect();
 
             while !all_zeros(&info_for_packets) {
 
-                let return_info = Packet::new(info_for_packets);
+                let return_info = Packet::new(info_for_packets)?;
                 packets.push(return_info.0);
                 info_for_packets = return_info.1;
             }
 
         } else if length_bit == '1' {
 
-            let number_packets_bin : String = info_characthers[0..11].to_vec()
-                .into_iter().collect::<String>();
+            check_length(&info_characthers, 11)?;
+            let number_packets_bin : String = info_characthers[0..11].iter().collect::<String>();
             info_characthers = info_characthers[11..].to_vec();
             let number_packets = usize::from_str_radix(&number_packets_bin, 2).unwrap();
 
             remaining_info = info_characthers.into_iter().collect();
             for _ in 0..number_packets {
 
-                let return_info = Packet::new(remaining_info);
+                let return_info = Packet::new(remaining_info)?;
                 packets.push(return_info.0);
                 remaining_info = return_info.1;
             }
         
         }
 
-        return (packets, remaining_info);
+        return Ok((packets, remaining_info));
     }
 
-    fn new(packet_info_binary: String) -> (Packet, String) {
-        
+    fn new(packet_info_binary: String) -> AocResult<(Packet, String)> {
+
+        check_length(&packet_info_binary.chars().collect::<Vec<char>>(), 6)?;
         let version_id_binary = packet_info_binary[0..3].to_owned();
         let version_id = ID::from_str_radix(&version_id_binary, 2).unwrap();
         
         let packet_type_id_binary = packet_info_binary[3..6].to_owned();
         let packet_type_id = ID::from_str_radix(&packet_type_id_binary, 2).unwrap();
-        let packet_type = convert_id_packet_type(packet_type_id);
+        let packet_type = convert_id_packet_type(packet_type_id)?;
 
         let mut literal : Option<u64> = None;
         let mut sub_packets : Option<Vec<Packet>> = None;
@@ -192,13 +213,21 @@ impl Packet {
         let mut remaining_info : String = packet_info_binary[6..].to_string();
         match packet_type.packet_type_enum {
             PacketTypeEnum::Literal => {
-                let return_info = Packet::get_literal_from_info(remaining_info);
+                let return_info = Packet::get_literal_from_info(remaining_info)?;
                 literal = Some(return_info.0);
                 remaining_info = return_info.1;
             },
 
             _ => {
-                let return_info = Packet::get_sub_packets_from_info(remaining_info);
+                let return_info = Packet::get_sub_packets_from_info(remaining_info)?;
+                // Operators are evaluated over their sub packets, comparisons over exactly two
+                let expected_sub_packets = return_info.0.len();
+                match packet_type.packet_type_enum {
+                    PacketTypeEnum::GreaterThan | PacketTypeEnum::LessThan | PacketTypeEnum::EqualTo if expected_sub_packets != 2 =>
+                        return Err(AocError::malformed("comparison packets need exactly two sub packets")),
+                    _ if expected_sub_packets == 0 => return Err(AocError::malformed("operator packet without sub packets")),
+                    _ => (),
+                }
                 sub_packets = Some(return_info.0);
                 remaining_info = return_info.1;
             }
@@ -214,10 +243,20 @@ impl Packet {
             value: None,
         };
 
-        return (new_packet, remaining_info);
+        return Ok((new_packet, remaining_info));
     }
 
-    fn sum_versions(&self) -> ID {
+    pub fn get_version_id(&self) -> ID { self.version_id }
+    /// The type ID, 4 for literals and the operation otherwise.
+    pub fn get_type_id(&self) -> ID { self.packet_type.id }
+    pub fn get_literal(&self) -> Option<u64> { self.literal }
+    /// The packets an operator works on, none for literals.
+    pub fn get_sub_packets(&self) -> &[Packet] { self.sub_packets.as_deref().unwrap_or(&[]) }
+    /// The value of the packet, only known once computed.
+    pub fn get_value(&self) -> Option<u64> { self.value }
+
+    /// Version of the packet plus the versions of every packet inside it.
+    pub fn sum_versions(&self) -> ID {
 
         let mut sum_value : ID = self.version_id;
         if self.sub_packets.is_some() {
@@ -229,7 +268,8 @@ impl Packet {
         return sum_value;
     }
 
-    fn compute_value(&mut self) -> u64 {
+    /// Evaluates the packet and every packet inside it, keeping their values.
+    pub fn compute_value(&mut self) -> u64 {
 
         let mut sub_packets_values : Vec<u64> = Vec::new();
         if self.sub_packets.is_some() {
@@ -290,27 +330,31 @@ impl fmt::Display for Packet {
 
 impl SystemBITS {
 
-    pub fn new(transmission_hex: String) -> SystemBITS {
+    /// Decodes a transmission written in hexadecimal.
+    pub fn new(transmission_hex: String) -> AocResult<SystemBITS> {
 
         let mut transmission_bin : String = transmission_hex.chars()
-            .into_iter()
-            .map(|hex_char| convert_hex_binary(hex_char))
-            .collect::<Vec<String>>()
+            .map(convert_hex_binary)
+            .collect::<AocResult<Vec<String>>>()?
             .join("");
 
         let mut packets : Vec<Packet> = Vec::new();
         while !all_zeros(&transmission_bin) {
 
-            let return_info = Packet::new(transmission_bin);
+            let return_info = Packet::new(transmission_bin)?;
             packets.push(return_info.0);
             transmission_bin = return_info.1;
         }
 
-        SystemBITS {
+        if packets.len() == 0 { return Err(AocError::malformed("transmission has no packets")) }
+
+        Ok(SystemBITS {
             packets: packets
-        }
+        })
     }
 
+    pub fn get_packets(&self) -> &Vec<Packet> { &self.packets }
+
     pub fn get_sum_of_versions_of_packets(&self) -> Vec<ID> {
         return self.packets.iter()
             .map(|packet| packet.sum_versions())
@@ -331,3 +375,77 @@ impl fmt::Display for SystemBITS {
         return Ok(());
     }
 }
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 adds up the versions of every packet, part 2 evaluates the outermost one.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = String;
+    type Answer1 = ID;
+    type Answer2 = ID;
+
+    fn parse(input: &str) -> Result<String, InputError> {
+
+        let line = aoc_input::lines(input).next().ok_or_else(|| InputError::malformed("missing transmission", 1))?;
+        if let Some(column) = line.chars().position(|characther| !characther.is_ascii_hexdigit()) {
+            return Err(InputError::malformed("expected an hexadecimal transmission", 1).at(1, column + 1));
+        }
+
+        return Ok(line.to_uppercase());
+    }
+
+    fn part1(transmission: &String) -> AocResult<ID> { Ok(SystemBITS::new(transmission.clone())?.get_sum_of_versions_of_packets()[0]) }
+    fn part2(transmission: &String) -> AocResult<ID> { Ok(SystemBITS::new(transmission.clone())?.compute_values()[0]) }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn hex_characthers_expand_to_four_bits() {
+        assert_eq!(convert_hex_binary('0').unwrap(), "0000");
+        assert_eq!(convert_hex_binary('A').unwrap(), "1010");
+        assert_eq!(convert_hex_binary('F').unwrap(), "1111");
+    }
+
+    #[test]
+    fn literal_groups_stop_at_leading_zero() {
+        let (literal, remaining) = Packet::get_literal_from_info("101111111000101000".to_owned()).unwrap();
+        assert_eq!(literal, 2021);
+        assert_eq!(remaining, "000");
+        assert!(all_zeros(&remaining));
+    }
+
+    #[test]
+    fn literal_packet_is_decoded() {
+        let (mut packet, remaining) = Packet::new("110100101111111000101000".to_owned()).unwrap();
+        assert_eq!(packet.sum_versions(), 6);
+        assert_eq!(packet.compute_value(), 2021);
+        assert!(all_zeros(&remaining));
+    }
+
+    #[test]
+    fn truncated_transmission_is_rejected() {
+        assert!(SystemBITS::new("D2FE".to_owned()).is_err());
+        assert!(SystemBITS::new("38006F45291200".to_owned()).is_ok());
+    }
+
+    #[test]
+    fn packets_can_be_walked() {
+        // A less than operator over the literals 10 and 20
+        let mut system = SystemBITS::new("38006F45291200".to_owned()).unwrap();
+        system.compute_values();
+
+        let packet = &system.get_packets()[0];
+        assert_eq!((packet.get_version_id(), packet.get_type_id(), packet.get_value()), (1, 6, Some(1)));
+        let literals : Vec<Option<u64>> = packet.get_sub_packets().iter().map(|sub_packet| sub_packet.get_literal()).collect();
+        assert_eq!(literals, vec!(Some(10), Some(20)));
+        assert!(packet.get_sub_packets()[0].get_sub_packets().is_empty());
+    }
+}
@@ -1,12 +1,10 @@
-mod read;
-mod lib;
-
-use lib::{SystemBITS};
+use aoc_common::Solution;
+use day_16::{SystemBITS, Day16};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let mut system = SystemBITS::new(lines[0].clone());
+    let transmission = Day16::parse(&aoc_input::read_to_string("../inputs/day16.txt").unwrap()).unwrap();
+    let mut system = SystemBITS::new(transmission).unwrap();
     
     //println!("{}", system);
 
@@ -0,0 +1,32 @@
+use aoc_common::Solution;
+use day_16::Day16;
+
+const INPUT : &str = include_str!("../../inputs/day16.txt");
+
+fn parse(input: &str) -> <Day16 as Solution>::Input { Day16::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    let sums = [("8A004A801A8002F478", 16), ("620080001611562C8802118E34", 12), ("C0015000016115A2E0802F182340", 23), ("A0016C880162017C3686B18A3D4780", 31)];
+    for (transmission, sum) in sums {
+        assert_eq!(Day16::part1(&parse(transmission)).unwrap(), sum, "{}", transmission);
+    }
+}
+
+#[test]
+fn example_part2() {
+    let values = [("C200B40A82", 3), ("04005AC33890", 54), ("880086C3E88112", 7), ("CE00C43D881120", 9), ("D8005AC2A8F0", 1), ("F600BC2D8F", 0), ("9C005AC2F8F0", 0), ("9C0141080250320F1802104A08", 1)];
+    for (transmission, value) in values {
+        assert_eq!(Day16::part2(&parse(transmission)).unwrap(), value, "{}", transmission);
+    }
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day16::part1(&parse(INPUT)).unwrap(), 989);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day16::part2(&parse(INPUT)).unwrap(), 7936430475134);
+}
@@ -1,9 +1,13 @@
+//! Day 17: Trick Shot, launching the probe so it lands in the target area.
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
+
 
 // ================================================== STRUCTS ==================================================;
 
-type PositionUnit = i64;
-type VelocityUnit = i64;
-type AccelarationUnit = i64;
+pub type PositionUnit = i64;
+pub type VelocityUnit = i64;
+pub type AccelarationUnit = i64;
 
 #[derive(Copy, Clone)]
 pub struct Position {
@@ -23,6 +27,7 @@ pub struct Accelaration {
     pub y:  AccelarationUnit,
 }
 
+/// The target area, with the probe launched from the origin under drag and gravity.
 pub struct Map {
     start_interval:         Position,
     end_interval:           Position,
@@ -38,19 +43,20 @@ pub struct Map {
 
 impl Map {
 
-    pub fn new(x_start: PositionUnit, y_start: PositionUnit, x_end: PositionUnit, y_end: PositionUnit) -> Map {
+    pub fn new(x_start: PositionUnit, y_start: PositionUnit, x_end: PositionUnit, y_end: PositionUnit) -> AocResult<Map> {
 
-        if x_start <= 0 || x_end <= 0 { panic!("🚨  Invalid choice of x limits!") }
-        if y_start >= 0 || y_end >= 0 { panic!("🚨  Invalid choice of y limits!") }
+        if x_start <= 0 || x_end <= 0 { return Err(AocError::malformed("the target area must be ahead of the probe")) }
+        if y_start >= 0 || y_end >= 0 { return Err(AocError::malformed("the target area must be below the probe")) }
+        if x_start > x_end || y_start > y_end { return Err(AocError::malformed("the target area ranges must go from the lowest to the highest value")) }
 
-        Map {
+        Ok(Map {
             start_interval: Position{ x: x_start, y: y_start },
             end_interval: Position{ x: x_end, y: y_end },
             // Constants
             start_probe_position: Position{ x: 0, y: 0},
             gravity: -1,
             drag: -1,
-        }
+        })
     }
 
     fn compute_position_velocity_x_constant(&self, mut velocity_x: VelocityUnit) -> PositionUnit {
@@ -104,7 +110,10 @@ impl Map {
         return max_height;
     }
 
-    pub fn compute_highest_y_velocity(&self) -> (Velocity, PositionUnit) {
+    /// The launch that goes the highest while still hitting the target, with that height.
+    ///
+    /// Unsolvable when no launch hits the target at all.
+    pub fn compute_highest_y_velocity(&self) -> AocResult<(Velocity, PositionUnit)> {
 
         let mut x_min : VelocityUnit = 0;
         let x_max : VelocityUnit = self.end_interval.x;
@@ -118,8 +127,7 @@ impl Map {
             x_min = possible_x_min;
         }
 
-        let mut max_velocity = Velocity{x: 0, y: 0};
-        let mut max_height : PositionUnit = 0;
+        let mut best_shot : Option<(Velocity, PositionUnit)> = None;
 
         for x_velocity in x_min..=x_max {
             for y_velocity in y_min..=y_max {
@@ -129,16 +137,16 @@ impl Map {
                 if !hit { continue }
 
                 let heighest_position = self.compute_heighest_point(tmp_velocity);
-                if heighest_position > max_height {
-                    max_height = heighest_position;
-                    max_velocity = tmp_velocity;
+                if best_shot.is_none_or(|(_, max_height)| heighest_position > max_height) {
+                    best_shot = Some((tmp_velocity, heighest_position));
                 }
             }
         }
 
-        return (max_velocity, max_height);
+        return best_shot.ok_or_else(|| AocError::unsolvable("no launch hits the target area"));
     }
 
+    /// Number of distinct launch velocities that hit the target.
     pub fn compute_number_of_shots(&self) -> usize {
 
         let mut x_min : VelocityUnit = 0;
@@ -167,4 +175,79 @@ impl Map {
 
         return hit_shots;
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 finds the highest launch, part 2 counts every launch that hits.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (PositionUnit, PositionUnit, PositionUnit, PositionUnit);
+    type Answer1 = PositionUnit;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let line = aoc_input::lines(input).next().ok_or_else(|| InputError::malformed("missing target area", 1))?;
+        let ranges : Vec<(PositionUnit, PositionUnit)> = line.trim_start_matches("target area: ").split(", ")
+            .map(|coordinate_info| {
+
+                let split_coordinate_info : Vec<&str> = coordinate_info.split('=').collect();
+                if split_coordinate_info.len() != 2 { return Err(InputError::malformed("expected 'target area: x=<min>..<max>, y=<min>..<max>'", 1)) }
+                let values : Vec<PositionUnit> = aoc_input::parse_list(split_coordinate_info[1], "..", 1)?;
+                if values.len() != 2 { return Err(InputError::malformed("expected 'target area: x=<min>..<max>, y=<min>..<max>'", 1)) }
+                return Ok((values[0], values[1]));
+            }).collect::<Result<_, _>>()?;
+
+        if ranges.len() != 2 { return Err(InputError::malformed("expected 'target area: x=<min>..<max>, y=<min>..<max>'", 1)) }
+        return Ok((ranges[0].0, ranges[1].0, ranges[0].1, ranges[1].1));
+    }
+
+    fn part1(area: &Self::Input) -> AocResult<PositionUnit> { Ok(Map::new(area.0, area.1, area.2, area.3)?.compute_highest_y_velocity()?.1) }
+    fn part2(area: &Self::Input) -> AocResult<usize> { Ok(Map::new(area.0, area.1, area.2, area.3)?.compute_number_of_shots()) }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn example_map() -> Map { Map::new(20, -10, 30, -5).unwrap() }
+
+    #[test]
+    fn velocities_hit_or_miss_the_target() {
+        let map = example_map();
+        assert!(map.check_if_velocity_hits(Velocity{ x: 7, y: 2 }));
+        assert!(map.check_if_velocity_hits(Velocity{ x: 6, y: 9 }));
+        assert!(!map.check_if_velocity_hits(Velocity{ x: 17, y: -4 }));
+    }
+
+    #[test]
+    fn heighest_point_of_a_shot() {
+        let map = example_map();
+        assert_eq!(map.compute_heighest_point(Velocity{ x: 6, y: 9 }), 45);
+        assert_eq!(map.compute_position_velocity_x_constant(6), 21);
+    }
+
+    #[test]
+    fn target_above_the_probe_is_rejected() {
+        assert!(Map::new(20, 5, 30, 10).is_err());
+    }
+
+    #[test]
+    fn reversed_target_ranges_are_rejected() {
+        let area = Day17::parse("target area: x=30..20, y=-10..-5").unwrap();
+        assert!(matches!(Day17::part1(&area), Err(AocError::Input(_))));
+        assert!(matches!(Day17::part2(&area), Err(AocError::Input(_))));
+        assert!(Map::new(20, -5, 30, -10).is_err());
+    }
+
+    #[test]
+    fn highest_shot_of_the_example() {
+        let (velocity, height) = example_map().compute_highest_y_velocity().unwrap();
+        assert_eq!((velocity.y, height), (9, 45));
+    }
+}
@@ -1,36 +1,16 @@
-mod read;
-mod lib;
-
-use lib::{Map};
-
-use std::io::{Error, ErrorKind};
+use aoc_common::Solution;
+use day_17::{Map, Day17};
 
 fn main() {
 
-    let mut line : String = read::read_lines("input.txt".to_owned())[0].clone();
-    line = line.replace("target area: ", "");
-
-    let splitted_values : Vec<(i64, i64)> = line.split(", ").into_iter()
-        .map(|coordinate_info| {
-
-            let split_coordinate_info : Vec<&str> = coordinate_info.split("=").into_iter().collect();
-            let split_values : Vec<i64> = split_coordinate_info[1].split("..").into_iter()
-                .map(|value_str| value_str.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
-                .collect::<Result<_, _>>().unwrap();
-
-            let value_min = split_values[0];
-            let value_max = split_values[1];
-
-            return (value_min, value_max);
-
-        }).collect();
+    let (x_start, y_start, x_end, y_end) = Day17::parse(&aoc_input::read_to_string("../inputs/day17.txt").unwrap()).unwrap();
 
-    let map = Map::new(splitted_values[0].0, splitted_values[1].0, splitted_values[0].1, splitted_values[1].1);
+    let map = Map::new(x_start, y_start, x_end, y_end).unwrap();
     
     //println!("{}", system);
 
     // Part 1
-    let highest_y_info = map.compute_highest_y_velocity();
+    let highest_y_info = map.compute_highest_y_velocity().unwrap();
     let initial_velocity = highest_y_info.0;
     let highest_y_value = highest_y_info.1;
     println!("🚀  The peak achieved was '{}' for a velocity of '{{ x: {}, y: {} }}' (Part 1)", highest_y_value, initial_velocity.x, initial_velocity.y);
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_17::Day17;
+
+const EXAMPLE : &str = include_str!("../../inputs/day17.example.txt");
+const INPUT : &str = include_str!("../../inputs/day17.txt");
+
+fn parse(input: &str) -> <Day17 as Solution>::Input { Day17::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day17::part1(&parse(EXAMPLE)).unwrap(), 45);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day17::part2(&parse(EXAMPLE)).unwrap(), 112);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day17::part1(&parse(INPUT)).unwrap(), 5050);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day17::part2(&parse(INPUT)).unwrap(), 2223);
+}
@@ -1,11 +1,13 @@
+//! Day 18: Snailfish, doing the homework of snailfish math.
+
 use std::fmt;
 use std::collections::{HashMap, VecDeque};
 
-use std::io::{Error, ErrorKind};
+use aoc_common::{AocError, AocResult, InputError, Solution};
 
 // ================================================== STRUCTS ==================================================
 
-type Literal = i64;
+pub type Literal = i64;
 
 #[derive(Copy, Clone, PartialEq)]
 enum SnailNumberType { LiteralNumber, PairNumber }
@@ -37,6 +39,7 @@ struct PairNumber {
     second_elem:    Box<dyn SnailFishNumber>,
 }
 
+/// The snailfish numbers of the homework, in order.
 pub struct SnailMathProblem {
     elements:       Vec<Box<dyn SnailFishNumber>>,
     sum_element:    Option<Box<dyn SnailFishNumber>>,
@@ -184,70 +187,82 @@ impl SnailFishNumber for PairNumber {
 
 impl SnailMathProblem {
 
-    fn get_elements_from_input(input_lines: Vec<String>) -> Vec<Box<dyn SnailFishNumber>> {
+    /// Reads one number per line, every pair needs its two elements split by a ',' and closed right after the second.
+    fn get_elements_from_input(input_lines: &Vec<String>) -> Result<Vec<Box<dyn SnailFishNumber>>, InputError> {
 
-        let elements : Vec<Box<dyn SnailFishNumber>> = input_lines.into_iter()
-            .map(|input_line| {
+        let elements : Vec<Box<dyn SnailFishNumber>> = input_lines.iter().enumerate()
+            .map(|(line_index, input_line)| {
 
+                let malformed = |message: &str, index: usize| InputError::malformed(message, line_index + 1).at(line_index + 1, index + 1);
                 let mut current_heap_by_level : HashMap<usize, VecDeque<Box<dyn SnailFishNumber>>> = HashMap::new();
                 let mut level_count : usize = 0;
-        
+                // A number starts the line, opens a pair or follows its ','
+                let mut expecting_element : bool = true;
+
+                let characthers : Vec<char> = input_line.chars().collect();
                 let mut current_index : usize = 0;
-                while current_index < input_line.len() {
+                while current_index < characthers.len() {
 
                     // Add to the heap level
-                    if !current_heap_by_level.contains_key(&level_count) {
-                        current_heap_by_level.insert(level_count, VecDeque::new());
-                    }
-                    
-                    let current_char : char = input_line.chars().nth(current_index).unwrap();
+                    current_heap_by_level.entry(level_count).or_default();
+                    let elements_in_level : usize = current_heap_by_level[&level_count].len();
+
+                    let current_char : char = characthers[current_index];
                     // Opened new pair
                     if current_char == '[' {
+                        if !expecting_element { return Err(malformed("expected ',' or ']'", current_index)) }
                         level_count = level_count + 1;
                         current_index = current_index + 1;
                     }
                     // Closed pair
                     else if current_char == ']' {
 
+                        if expecting_element || level_count == 0 || elements_in_level != 2 {
+                            return Err(malformed("expected a pair of two elements before ']'", current_index));
+                        }
+
                         let current_heap = current_heap_by_level.get_mut(&level_count).unwrap();
                         let first_elem = current_heap.pop_front().unwrap();
                         let second_elem = current_heap.pop_front().unwrap();
-                        
+
                         level_count = level_count - 1;
                         current_index = current_index + 1;
                         let new_elem = PairNumber::new(first_elem, second_elem).clone_box();
                         current_heap_by_level.get_mut(&level_count).unwrap().push_back(new_elem);
                     }
-                    else if current_char == ',' { current_index = current_index + 1; }
+                    else if current_char == ',' {
+                        if expecting_element || level_count == 0 || elements_in_level != 1 {
+                            return Err(malformed("expected ',' only after the first element of a pair", current_index));
+                        }
+                        expecting_element = true;
+                        current_index = current_index + 1;
+                    }
                     // Saw other things (must be number for literal, only possibility)
                     else {
 
-                        let index_of_other_symbol = vec!('[', ',', ']').into_iter()
-                            .map(|symbol| input_line.as_str()[current_index..]
-                                .find(symbol).map(|i| i + current_index))
-                            .filter(|option| option.is_some())
-                            .map(|option| option.unwrap())
-                            .min().unwrap();
-                        
-                        let sub_string_with_value = input_line.as_str()[current_index..index_of_other_symbol].to_owned();
+                        if !expecting_element { return Err(malformed("expected ',' or ']'", current_index)) }
+                        let index_of_other_symbol = characthers[current_index..].iter()
+                            .position(|characther| !characther.is_ascii_digit())
+                            .map_or(characthers.len(), |position| position + current_index);
 
+                        let sub_string_with_value : String = characthers[current_index..index_of_other_symbol].iter().collect();
                         let value = sub_string_with_value.parse()
-                            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
-                            .unwrap();
+                            .map_err(|_| malformed(&format!("'{}' is not a regular number", sub_string_with_value), current_index))?;
 
                         let literal_number = LiteralNumber::new(value).clone_box();
                         current_heap_by_level.get_mut(&level_count).unwrap().push_back(literal_number);
+                        expecting_element = false;
                         current_index = index_of_other_symbol;
                     }
                 }
 
-                let snail_number : Box<dyn SnailFishNumber> = current_heap_by_level.get_mut(&0).unwrap()
-                    .pop_front().unwrap();
+                if level_count != 0 || expecting_element { return Err(malformed("snailfish number has unbalanced pairs", characthers.len())) }
+                let snail_number : Box<dyn SnailFishNumber> = current_heap_by_level.get_mut(&0).unwrap().pop_front().unwrap();
 
-                return snail_number;
-            }).collect();
+                return Ok(snail_number);
+            }).collect::<Result<Vec<Box<dyn SnailFishNumber>>, InputError>>()?;
 
-        return elements
+        return Ok(elements);
     }
 
     fn join_elements(first_elem: Box<dyn SnailFishNumber>, second_elem: Box<dyn SnailFishNumber>) -> Box<dyn SnailFishNumber> {
@@ -256,16 +271,18 @@ impl SnailMathProblem {
         return current_element;
     }
 
-    pub fn new(input_lines: Vec<String>) -> SnailMathProblem {
+    pub fn new(input_lines: Vec<String>) -> AocResult<SnailMathProblem> {
 
-        let elements = SnailMathProblem::get_elements_from_input(input_lines);
+        let elements = SnailMathProblem::get_elements_from_input(&input_lines)?;
+        if elements.len() == 0 { return Err(AocError::malformed("there are no snailfish numbers")) }
 
-        SnailMathProblem {
+        Ok(SnailMathProblem {
             elements: elements,
             sum_element: None,
-        }
+        })
     }
 
+    /// Adds every number in order, reducing after each addition.
     pub fn sum_elements(&mut self) {
 
         let mut current_value = self.elements[0].clone_box();
@@ -280,8 +297,10 @@ impl SnailMathProblem {
         self.sum_element = Some(current_value);
     }
 
+    /// Magnitude of the final sum, `sum_elements` has to be called first.
     pub fn get_magnitude(&self) -> Literal { self.sum_element.as_ref().unwrap().get_magnitude() }
     
+    /// The two different numbers whose sum has the largest magnitude, with that magnitude.
     pub fn compute_max_sum(&self) -> Option<((usize, usize), Literal)> {
 
         let mut max_info : Option<((usize, usize), Literal)> = None;
@@ -322,4 +341,83 @@ impl fmt::Display for PairNumber {
         write!(f, "[{}, {}]", self.first_elem, self.second_elem)?;
         return Ok(());
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 is the magnitude of the whole sum, part 2 the largest of any two numbers.
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type Answer1 = Literal;
+    type Answer2 = Literal;
+
+    fn parse(input: &str) -> Result<Vec<String>, InputError> {
+
+        let lines = aoc_input::read_lines_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(column) = line.chars().position(|characther| !"[],".contains(characther) && !characther.is_ascii_digit()) {
+                return Err(InputError::malformed("expected a snailfish number", index + 1).at(index + 1, column + 1));
+            }
+        }
+
+        SnailMathProblem::get_elements_from_input(&lines)?;
+
+        return Ok(lines);
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<Literal> {
+        let mut problem = SnailMathProblem::new(lines.clone())?;
+        problem.sum_elements();
+        return Ok(problem.get_magnitude());
+    }
+
+    fn part2(lines: &Vec<String>) -> AocResult<Literal> {
+        let max_info = SnailMathProblem::new(lines.clone())?.compute_max_sum();
+        return max_info.map(|max_info| max_info.1).ok_or_else(|| AocError::unsolvable("adding needs at least two numbers"));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse_number(line: &str) -> Box<dyn SnailFishNumber> {
+        return SnailMathProblem::get_elements_from_input(&vec!(line.to_owned())).unwrap().remove(0);
+    }
+
+    #[test]
+    fn magnitude_of_nested_pairs() {
+        assert_eq!(parse_number("[9,1]").get_magnitude(), 29);
+        assert_eq!(parse_number("[[1,2],[[3,4],5]]").get_magnitude(), 143);
+    }
+
+    #[test]
+    fn addition_is_reduced() {
+        let mut number = SnailMathProblem::join_elements(parse_number("[[[[4,3],4],4],[7,[[8,4],9]]]"), parse_number("[1,1]"));
+        while !number.do_iteration() {}
+        assert_eq!(number.get_magnitude(), 1384);
+    }
+
+    #[test]
+    fn unbalanced_number_is_rejected() {
+        assert!(SnailMathProblem::get_elements_from_input(&vec!("[[1,2]".to_owned())).is_err());
+        assert!(SnailMathProblem::get_elements_from_input(&vec!("[1,2]]".to_owned())).is_err());
+    }
+
+    #[test]
+    fn pairs_need_a_comma_and_a_closing_bracket() {
+        let error = Day18::parse("[1,2]\n[[1,2]3]").err().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(7)));
+
+        assert_eq!(Day18::parse("[1,2,3]").err().unwrap().column, Some(5));
+        assert_eq!(Day18::parse("[,1]").err().unwrap().column, Some(2));
+        assert_eq!(Day18::parse("[1,]").err().unwrap().column, Some(4));
+        assert_eq!(Day18::parse("[1,2][3,4]").err().unwrap().column, Some(6));
+        assert!(Day18::parse("1").is_ok());
+    }
+}
@@ -1,12 +1,10 @@
-mod read;
-mod lib;
-
-use lib::{SnailMathProblem};
+use aoc_common::Solution;
+use day_18::{SnailMathProblem, Day18};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let mut problem = SnailMathProblem::new(lines);
+    let lines = Day18::parse(&aoc_input::read_to_string("../inputs/day18.txt").unwrap()).unwrap();
+    let mut problem = SnailMathProblem::new(lines).unwrap();
 
     // Part 1
     problem.sum_elements();
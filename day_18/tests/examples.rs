@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_18::Day18;
+
+const EXAMPLE : &str = include_str!("../../inputs/day18.example.txt");
+const INPUT : &str = include_str!("../../inputs/day18.txt");
+
+fn parse(input: &str) -> <Day18 as Solution>::Input { Day18::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day18::part1(&parse(EXAMPLE)).unwrap(), 4140);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day18::part2(&parse(EXAMPLE)).unwrap(), 3993);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day18::part1(&parse(INPUT)).unwrap(), 4008);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day18::part2(&parse(INPUT)).unwrap(), 4667);
+}
@@ -1,12 +1,16 @@
+//! Day 19: Beacon Scanner, assembling the map of beacons from scanners of unknown position and orientation.
+
 use std::collections::{HashMap};
 
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+use aoc_common::{AocError, AocResult, InputError, Progress, Solution, Supervisor};
+
 // ================================================== STRUCTS ==================================================
 
-type PositionUnit = i64;
-type Position = (PositionUnit, PositionUnit, PositionUnit);
+pub type PositionUnit = i64;
+pub type Position = (PositionUnit, PositionUnit, PositionUnit);
 
 #[derive(PartialEq, Copy, Clone)]
 enum MapEntry { Scanner, Beacon }
@@ -19,9 +23,11 @@ struct ScannerInfo {
     absolute_positions:         Option<Vec<Position>>,
 }
 
+/// The scanners with the beacons they detect, placed relative to the first one as they are aligned.
 pub struct Map {
     entry_map:              HashMap<Position, MapEntry>,
     scanners:               Vec<ScannerInfo>,
+    supervisor:             Supervisor,
 }
 
 const MAX_DISTANCE_DETECTABLE : PositionUnit = 1000;
@@ -98,7 +104,7 @@ impl ScannerInfo {
                 (FacingAxis::NegY, UpAxis::NegX) => (value.2, value.1, - value.0),
                 (FacingAxis::NegZ, UpAxis::NegX) => (value.1, - value.2, - value.0),
 
-                _ => panic!("🚨  Should not be reached")
+                _ => unreachable!("Invalid orientation filtered above")
             };
             
             permutations.push(value_permed);
@@ -125,9 +131,15 @@ impl Map {
         Map {
             entry_map: HashMap::new(),
             scanners: scanners_infos,
+            supervisor: Supervisor::new(),
         }
     }
 
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> Map {
+        self.supervisor = supervisor;
+        return self;
+    }
+
     pub fn get_number_of_scanners(&self) -> usize { self.scanners.len() }
 
     pub fn number_of_scanner_processed(&self) -> usize {
@@ -142,7 +154,10 @@ impl Map {
         return count;
     }
 
-    pub fn add_next_scanner_info(&mut self) {
+    /// Aligns one more scanner with the ones already placed, needing twelve shared beacons.
+    pub fn add_next_scanner_info(&mut self) -> AocResult<()> {
+
+        self.supervisor.report(&Progress::new("scanners placed", self.number_of_scanner_processed(), Some(self.scanners.len())));
         // First can be blindly added
         if self.number_of_scanner_processed() == 0 {
             
@@ -156,7 +171,7 @@ impl Map {
             
             scanner_info.scanner_absolute_position = Some((0, 0, 0));
             scanner_info.absolute_positions = Some(absolute_positions);
-            return
+            return Ok(());
         }
 
         else {
@@ -165,6 +180,7 @@ impl Map {
                 .filter(|scanner| scanner.absolute_positions.is_some())
                 .map(|scanner| (scanner.identification.clone(), scanner.scanner_absolute_position.unwrap(), scanner.absolute_positions.clone().unwrap()))
                 .collect();
+            let number_of_scanners : usize = self.scanners.len();
             let scanners_unprocessed : Vec<&mut ScannerInfo> = self.scanners.iter_mut()
                 .filter(|scanner| scanner.absolute_positions.is_none())
                 .collect();
@@ -179,6 +195,11 @@ impl Map {
 
                         for processed_values_to_check in scanners_processed_values.iter() {
 
+                            // One step per placed scanner tried against an orientation of an unplaced one
+                            if self.supervisor.exhausted() {
+                                return Err(self.supervisor.give_up(&format!("placed {} out of {} scanners", scanners_processed_values.len(), number_of_scanners)));
+                            }
+
                             for link_unprocessed_value in unprocessed_values_to_check.iter() {
                                 for link_processed_value in processed_values_to_check.2.iter() {
 
@@ -223,7 +244,7 @@ impl Map {
 
                                     if count_matches >= THRESHOLD && !invalid {
 
-                                        println!("🪞  Matched '{}' and '{}'", processed_values_to_check.0, scanner_unprocessed.identification);
+                                        log::debug!("🪞  Matched '{}' and '{}'", processed_values_to_check.0, scanner_unprocessed.identification);
 
                                         self.entry_map.insert(possible_origin, MapEntry::Scanner);
                                         for &position in absolute_values.iter() {
@@ -233,7 +254,7 @@ impl Map {
                                         scanner_unprocessed.scanner_absolute_position = Some(possible_origin);
                                         scanner_unprocessed.absolute_positions = Some(absolute_values);
 
-                                        return
+                                        return Ok(());
                                     }
                                 }
                             }
@@ -245,7 +266,7 @@ impl Map {
             }
         }
 
-        panic!("🚨  No scanner info was able of being added");
+        return Err(AocError::unsolvable("no remaining scanner overlaps the ones already placed"));
     }
 
     pub fn compute_number_of_beacons(&self) -> usize {
@@ -260,6 +281,7 @@ impl Map {
         return count;
     }
 
+    /// The two scanners furthest apart, with their Manhattan distance.
     pub fn largest_distance_scanners(&self) -> Option<(String, String, PositionUnit)> {
 
         let mut max_distance : Option<(String, String, PositionUnit)> = None;
@@ -284,4 +306,83 @@ impl Map {
 
         return max_distance;
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+fn build_full_map(scanners: &Vec<(String, Vec<Vec<PositionUnit>>)>, supervisor: Supervisor) -> AocResult<Map> {
+    let mut map = Map::new(scanners.clone()).with_supervisor(supervisor);
+    while map.get_number_of_scanners() != map.number_of_scanner_processed() { map.add_next_scanner_info()? }
+    return Ok(map);
+}
+
+/// Part 1 counts the beacons, part 2 finds the largest distance between two scanners.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<(String, Vec<Vec<PositionUnit>>)>;
+    type Answer1 = usize;
+    type Answer2 = PositionUnit;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let mut scanner_lines : Vec<(String, Vec<Vec<PositionUnit>>)> = Vec::new();
+        for (index, line) in aoc_input::lines(input).enumerate() {
+
+            if line.contains("---") {
+                let identification = line.replace("--- ", "").replace(" ---", "");
+                scanner_lines.push((identification, Vec::new()));
+            } else if line != "" {
+
+                let values : Vec<PositionUnit> = aoc_input::parse_list(line, ",", index + 1)?;
+                if values.len() != 3 { return Err(InputError::malformed("expected 'x,y,z'", index + 1)) }
+                match scanner_lines.last_mut() {
+                    Some(scanner) => scanner.1.push(values),
+                    None => return Err(InputError::malformed("beacon found before any scanner", index + 1)),
+                }
+            }
+        }
+
+        return Ok(scanner_lines);
+    }
+
+    fn part1(scanners: &Self::Input) -> AocResult<usize> { Self::part1_supervised(scanners, Supervisor::new()) }
+    fn part2(scanners: &Self::Input) -> AocResult<PositionUnit> { Self::part2_supervised(scanners, Supervisor::new()) }
+
+    fn part1_supervised(scanners: &Self::Input, supervisor: Supervisor) -> AocResult<usize> {
+        Ok(build_full_map(scanners, supervisor)?.compute_number_of_beacons())
+    }
+
+    fn part2_supervised(scanners: &Self::Input, supervisor: Supervisor) -> AocResult<PositionUnit> {
+        let max_info = build_full_map(scanners, supervisor)?.largest_distance_scanners();
+        return max_info.map(|max_info| max_info.2).ok_or_else(|| AocError::unsolvable("there are no scanners"));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn default_orientation_keeps_positions() {
+        let scanner = ScannerInfo::new("0".to_owned(), vec!((1, 2, 3), (-4, 5, -6)));
+        assert_eq!(scanner.get_permutation(FacingAxis::X, UpAxis::Z), Some(vec!((1, 2, 3), (-4, 5, -6))));
+        assert_eq!(scanner.get_permutation(FacingAxis::NegX, UpAxis::Z), Some(vec!((-1, -2, 3), (4, -5, -6))));
+    }
+
+    #[test]
+    fn twenty_four_orientations() {
+        let scanner = ScannerInfo::new("0".to_owned(), vec!((1, 2, 3)));
+        assert_eq!(scanner.get_permutation(FacingAxis::Y, UpAxis::NegY), None);
+
+        let orientations : std::collections::HashSet<Position> = FacingAxis::iter()
+            .flat_map(|facing| UpAxis::iter().map(move |up| (facing, up)))
+            .filter_map(|(facing, up)| scanner.get_permutation(facing, up))
+            .map(|positions| positions[0])
+            .collect();
+        assert_eq!(orientations.len(), 24);
+    }
+}
@@ -1,46 +1,12 @@
-mod read;
-mod lib;
-
-use std::io::{Error, ErrorKind};
-
-use lib::{Map};
+use aoc_common::Solution;
+use day_19::{Map, Day19};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let mut scanner_lines : Vec<(String, Vec<Vec<i64>>)> = Vec::new();
-
-    let mut current_scanner_option : Option<(String, Vec<Vec<i64>>)> = None;
-    for line in lines.into_iter() {
-        // Scanner ID
-        if line.contains("---") {
-
-            let mut identification = line.replace("--- ", "");
-            identification = identification.replace(" ---", "");
-
-            current_scanner_option = Some((identification, Vec::new()));
-        }
-        // Empty Line
-        else if line == "" {
-            if current_scanner_option.is_some() {
-                scanner_lines.push(current_scanner_option.clone().unwrap())
-            }
-        }
-        // Scanner Positions
-        else {
-            let data: Vec<i64> = line.split(",")
-                .map(|line| line.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
-                .collect::<Result<_, _>>().unwrap();
-            let mut current_scanner = current_scanner_option.unwrap();
-            current_scanner.1.push(data);
-            current_scanner_option = Some(current_scanner);
-        }
-    }
-    if current_scanner_option.is_some() { scanner_lines.push(current_scanner_option.unwrap()) }
-
+    let scanner_lines = Day19::parse(&aoc_input::read_to_string("../inputs/day19.txt").unwrap()).unwrap();
 
     let mut map = Map::new(scanner_lines);
-    while map.get_number_of_scanners() != map.number_of_scanner_processed() { map.add_next_scanner_info() }
+    while map.get_number_of_scanners() != map.number_of_scanner_processed() { map.add_next_scanner_info().unwrap() }
     println!();
 
     // Part 1
@@ -0,0 +1,29 @@
+use aoc_common::Solution;
+use day_19::Day19;
+
+const EXAMPLE : &str = include_str!("../../inputs/day19.example.txt");
+const INPUT : &str = include_str!("../../inputs/day19.txt");
+
+fn parse(input: &str) -> <Day19 as Solution>::Input { Day19::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day19::part1(&parse(EXAMPLE)).unwrap(), 22);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day19::part2(&parse(EXAMPLE)).unwrap(), 1200);
+}
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn input_part1() {
+    assert_eq!(Day19::part1(&parse(INPUT)).unwrap(), 330);
+}
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn input_part2() {
+    assert_eq!(Day19::part2(&parse(INPUT)).unwrap(), 9634);
+}
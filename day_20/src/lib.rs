@@ -1,7 +1,12 @@
+//! Day 20: Trench Map, enhancing the infinite image of the ocean floor.
+
 use std::fmt;
 
 use std::collections::{HashMap};
 
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::Grid;
+
 // ================================================== STRUCTS ==================================================
 
 #[derive(PartialEq, Copy, Clone)]
@@ -9,18 +14,17 @@ enum SlotState { Emtpy, Filled }
 
 struct Image {
     infinite_slot:          SlotState,
-    slots:                  HashMap<(i64, i64), SlotState>,
-    finite_dimension_start: (i64, i64),
-    finite_dimension_end:   (i64, i64),
+    slots:                  Grid<SlotState>,
 }
 
+/// The enhancement algorithm and the image it is applied to, the infinite outside included.
 pub struct EnhancingTool {
     current_iteration:  usize,
     codification:       HashMap<i64, SlotState>,
     current_image:      Image,
 }
 
-const INFLUENCE_RADIUS : i64 = 1;
+const INFLUENCE_RADIUS : isize = 1;
 
 // ================================================== AUX FUNCTIONS ==================================================
 
@@ -31,11 +35,11 @@ fn convert_slot_state_to_symbol(slot: SlotState) -> char {
     }
 }
 
-fn convert_char_to_slot_state(slot_char: char) -> SlotState {
+fn convert_char_to_slot_state(slot_char: char) -> AocResult<SlotState> {
     match slot_char {
-        '.' => SlotState::Emtpy,
-        '#' => SlotState::Filled,
-        _ => panic!("🚨  Unrecognized symbol for a slot '{}'", slot_char),
+        '.' => Ok(SlotState::Emtpy),
+        '#' => Ok(SlotState::Filled),
+        _ => Err(AocError::malformed(&format!("unrecognized symbol for a slot '{}'", slot_char))),
     }
 }
 
@@ -59,74 +63,61 @@ fn get_code_from_chars(slots: Vec<SlotState>) -> usize {
 
 impl EnhancingTool {
 
-    pub fn new(codification: String, start_image_info: Vec<String>) -> EnhancingTool {
+    /// The algorithm is a line of 512 '#' or '.', the image the lines below it.
+    pub fn new(codification: String, start_image_info: Vec<String>) -> AocResult<EnhancingTool> {
 
-        let codification_fixed : HashMap<i64, SlotState> = codification.chars().into_iter().enumerate()
-            .map(|(index, characther)| (index as i64, convert_char_to_slot_state(characther)))
-            .collect();
+        // Every 3x3 neighbourhood is a 9 bit index into the codification
+        if codification.chars().count() != 512 { return Err(AocError::malformed("the codification must have 512 slots")) }
+        if start_image_info.len() == 0 { return Err(AocError::malformed("the image is empty")) }
 
-        let rows : i64 = start_image_info.len() as i64;
-        let columns : i64 = start_image_info.iter()
-            .map(|info_line| info_line.len())
-            .max().unwrap() as i64;
-        let mut image_slots : HashMap<(i64, i64), SlotState> = HashMap::new();
-        for (row_index, string_info) in start_image_info.into_iter().enumerate() {
-            for (col_index, characther) in string_info.chars().into_iter().enumerate() {
-                image_slots.insert((row_index as i64, col_index as i64), convert_char_to_slot_state(characther));
-            }
-        }
+        let codification_fixed : HashMap<i64, SlotState> = codification.chars().enumerate()
+            .map(|(index, characther)| Ok((index as i64, convert_char_to_slot_state(characther)?)))
+            .collect::<AocResult<HashMap<i64, SlotState>>>()?;
+
+        let image_slots : Vec<Vec<SlotState>> = start_image_info.into_iter()
+            .map(|string_info| string_info.chars().map(convert_char_to_slot_state).collect::<AocResult<Vec<SlotState>>>())
+            .collect::<AocResult<Vec<Vec<SlotState>>>>()?;
 
         let start_image : Image = Image {
             infinite_slot: SlotState::Emtpy,
-            slots: image_slots,
-            finite_dimension_start: (0, 0),
-            finite_dimension_end: (rows, columns),
+            slots: Grid::from_rows(image_slots)?,
         };
 
-        EnhancingTool {
+        Ok(EnhancingTool {
             current_iteration: 0,
             codification: codification_fixed,
             current_image: start_image
-        }
+        })
     }
 
     pub fn do_iteration(&mut self) {
 
-        let new_image_start : (i64, i64) = ( self.current_image.finite_dimension_start.0 - INFLUENCE_RADIUS,
-            self.current_image.finite_dimension_start.1 - INFLUENCE_RADIUS);
-        let new_image_end : (i64, i64) = ( self.current_image.finite_dimension_end.0 + INFLUENCE_RADIUS,
-            self.current_image.finite_dimension_end.1 + INFLUENCE_RADIUS);
-
         let mut infinite_code : Vec<SlotState> = Vec::new();
         for _ in 0..((2 * INFLUENCE_RADIUS + 1).pow(2)) { infinite_code.push(self.current_image.infinite_slot) }
         let infinite_slot_index : i64 = get_code_from_chars(infinite_code) as i64;
         let infinite_slot : SlotState = *self.codification.get(&infinite_slot_index).unwrap();
 
-        let mut image_slots : HashMap<(i64, i64), SlotState> = HashMap::new();
-        
-        for row in new_image_start.0..=new_image_end.0 {
-            for col in new_image_start.1..=new_image_end.1 {
+        // The image grows by the influence radius on every side, so new (x, y) sits over old (x - radius, y - radius)
+        let current_slots : &Grid<SlotState> = &self.current_image.slots;
+        let growth : usize = 2 * INFLUENCE_RADIUS as usize;
+        let image_slots : Grid<SlotState> = Grid::from_fn(current_slots.width() + growth, current_slots.height() + growth, |position| {
 
-                let mut slots : Vec<SlotState> = Vec::new();
-                for var_row in -INFLUENCE_RADIUS..=INFLUENCE_RADIUS {
-                    for var_col in -INFLUENCE_RADIUS..=INFLUENCE_RADIUS {
+            let mut slots : Vec<SlotState> = Vec::new();
+            for var_row in -INFLUENCE_RADIUS..=INFLUENCE_RADIUS {
+                for var_col in -INFLUENCE_RADIUS..=INFLUENCE_RADIUS {
 
-                        let slot_option = self.current_image.slots.get(&(row + var_row, col + var_col));
-                        if slot_option.is_none() { slots.push(self.current_image.infinite_slot) }
-                        else { slots.push(*slot_option.unwrap()) }
-                    }
+                    let slot_option = current_slots.offset(position, (var_col - INFLUENCE_RADIUS, var_row - INFLUENCE_RADIUS))
+                        .map(|old_position| current_slots[old_position]);
+                    slots.push(slot_option.unwrap_or(self.current_image.infinite_slot));
                 }
-
-                let slot_code_index : i64 = get_code_from_chars(slots) as i64;
-                let slot_code : SlotState = *self.codification.get(&slot_code_index).unwrap();
-                image_slots.insert((row, col), slot_code);
             }
-        }
+
+            let slot_code_index : i64 = get_code_from_chars(slots) as i64;
+            return *self.codification.get(&slot_code_index).unwrap();
+        });
 
         let new_image : Image = Image {
             infinite_slot: infinite_slot,
-            finite_dimension_start: new_image_start,
-            finite_dimension_end: new_image_end,
             slots: image_slots,
         };
 
@@ -135,10 +126,12 @@ impl EnhancingTool {
     }
 
     pub fn get_current_iteration(&self) -> usize { self.current_iteration }
+    pub fn get_image(&self) -> Grid<char> { self.current_image.slots.map(|&slot| convert_slot_state_to_symbol(slot)) }
 
+    /// Number of pixels shown as `characther` in the finite part of the image.
     pub fn compute_finite_number_of_characther(&self, characther: char) -> usize {
         self.current_image.slots.iter()
-            .filter(|(_, &slot)| slot == convert_char_to_slot_state(characther))
+            .filter(|(_, &slot)| convert_slot_state_to_symbol(slot) == characther)
             .count()
     }
 }
@@ -146,19 +139,16 @@ impl EnhancingTool {
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
-        let mut line = String::new();
-        for row in (self.finite_dimension_start.0 - 1)..=(self.finite_dimension_end.0 + 1) {
-            for col in (self.finite_dimension_start.1 - 1)..=(self.finite_dimension_end.1 + 1) {
+        // Show one slot of the infinite image around the finite part
+        let infinite_symbol : char = convert_slot_state_to_symbol(self.infinite_slot);
+        let infinite_line : String = infinite_symbol.to_string().repeat(self.slots.width() + 2);
 
-                let slot_option = self.slots.get(&(row, col));
-                if slot_option.is_none() { line = format!("{}{}", line, convert_slot_state_to_symbol(self.infinite_slot)) }
-                else { line = format!("{}{}", line, convert_slot_state_to_symbol(slot_option.unwrap().clone())) }
-            }
-
-            line = format!("{}\n", line);
+        writeln!(f, "{}", infinite_line)?;
+        for line in self.slots.render(|&slot| convert_slot_state_to_symbol(slot)).lines() {
+            writeln!(f, "{}{}{}", infinite_symbol, line, infinite_symbol)?;
         }
+        writeln!(f, "{}", infinite_line)?;
 
-        write!(f, "{}", line)?;
         return Ok(());
     }
 }
@@ -169,4 +159,69 @@ impl fmt::Display for EnhancingTool {
         write!(f, "Current Image:\n{}", self.current_image)?;
         return Ok(());
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Number of lit pixels after 2 enhancements in part 1 and after 50 in part 2.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (String, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+
+        let lines = aoc_input::read_lines_from(input.as_bytes())?;
+        if lines.len() < 3 || lines[1] != "" {
+            return Err(InputError::malformed("expected the codification, an empty line and the image", 1));
+        }
+
+        for (index, line) in lines.iter().enumerate().filter(|&(index, _)| index != 1) {
+            if let Some(column) = line.chars().position(|characther| characther != '#' && characther != '.') {
+                return Err(InputError::malformed("expected only '#' and '.'", index + 1).at(index + 1, column + 1));
+            }
+        }
+
+        return Ok((lines[0].clone(), lines[2..].to_vec()));
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<usize> {
+        let mut tool = EnhancingTool::new(input.0.clone(), input.1.clone())?;
+        while tool.get_current_iteration() != 2 { tool.do_iteration() }
+        return Ok(tool.compute_finite_number_of_characther('#'));
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<usize> {
+        let mut tool = EnhancingTool::new(input.0.clone(), input.1.clone())?;
+        while tool.get_current_iteration() != 50 { tool.do_iteration() }
+        return Ok(tool.compute_finite_number_of_characther('#'));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn slots_are_read_as_binary() {
+        let slots : Vec<SlotState> = "...#...#.".chars().map(|symbol| convert_char_to_slot_state(symbol).unwrap()).collect();
+        assert_eq!(get_code_from_chars(slots), 34);
+    }
+
+    #[test]
+    fn slot_symbols_round_trip() {
+        for symbol in ['.', '#'] {
+            assert_eq!(convert_slot_state_to_symbol(convert_char_to_slot_state(symbol).unwrap()), symbol);
+        }
+    }
+
+    #[test]
+    fn short_codification_is_rejected() {
+        assert!(EnhancingTool::new("#.#".to_owned(), vec!("#.".to_owned())).is_err());
+    }
+}
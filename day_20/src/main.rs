@@ -1,15 +1,11 @@
-mod read;
-mod lib;
-
-use lib::{EnhancingTool};
+use aoc_common::Solution;
+use day_20::{EnhancingTool, Day20};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let codification_line = lines.get(0).unwrap().clone();
-    let image_lines = lines[2..].to_vec();
+    let (codification_line, image_lines) = Day20::parse(&aoc_input::read_to_string("../inputs/day20.txt").unwrap()).unwrap();
 
-    let mut tool : EnhancingTool = EnhancingTool::new(codification_line, image_lines);
+    let mut tool : EnhancingTool = EnhancingTool::new(codification_line, image_lines).unwrap();
 
     // Part 1
     while tool.get_current_iteration() != 2 { tool.do_iteration() }
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_20::Day20;
+
+const EXAMPLE : &str = include_str!("../../inputs/day20.example.txt");
+const INPUT : &str = include_str!("../../inputs/day20.txt");
+
+fn parse(input: &str) -> <Day20 as Solution>::Input { Day20::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day20::part1(&parse(EXAMPLE)).unwrap(), 35);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day20::part2(&parse(EXAMPLE)).unwrap(), 3351);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day20::part1(&parse(INPUT)).unwrap(), 5316);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day20::part2(&parse(INPUT)).unwrap(), 16728);
+}
@@ -1,8 +1,15 @@
+//! Day 21: Dirac Dice, playing against the submarine with deterministic and quantum dice.
+
 use std::collections::{HashMap};
+use std::fmt;
+
+use aoc_common::{AocError, AocResult, InputError, Solution};
 
 // ================================================== STRUCTS ==================================================
 
-type Value = i64;
+pub type Value = i64;
+
+const NUMBER_OF_PLAYERS : usize = 2;
 
 struct DiracDice {
     start_value:    Value,
@@ -22,6 +29,7 @@ struct Player {
     score:          Value,
 }
 
+/// A game with a deterministic die that rolls its values in order.
 pub struct Game {
     players:            Vec<Player>,
     dirac_dice:         DiracDice,
@@ -35,6 +43,7 @@ pub struct Game {
     number_of_rolls:    usize,
 }
 
+/// A game where every roll splits the universe, counting how many universes are in each state.
 pub struct QuantumGame {
     player_universes:   HashMap<Vec<(Value, Value)>, usize>,
     universe_scores:    HashMap<usize, usize>,
@@ -51,7 +60,17 @@ pub struct QuantumGame {
 
 // ================================================== AUX FUNCTIONS ==================================================
 
+fn check_players(player_infos: &Vec<(String, Value)>, map_limits: (Value, Value)) -> AocResult<()> {
+
+    if player_infos.len() != NUMBER_OF_PLAYERS {
+        return Err(AocError::malformed(&format!("expected {} players, found {}", NUMBER_OF_PLAYERS, player_infos.len())));
+    }
+    if let Some(player) = player_infos.iter().find(|player| player.1 < map_limits.0 || player.1 > map_limits.1) {
+        return Err(AocError::malformed(&format!("{} starts outside the board", player.0)));
+    }
 
+    return Ok(());
+}
 
 // ================================================== IMPLEMENTATIONS ==================================================
 
@@ -115,6 +134,7 @@ impl Player {
 
 impl Game {
 
+    /// Players take `number_of_rolls` rolls per turn around the track in `map_limits` until one reaches `score_value`.
     pub fn new(player_infos: Vec<(String, Value)>, map_limits: (Value, Value), dice_limits: (Value, Value), score_value: Value, number_of_rolls: usize) -> Game {
         Game {
             players: player_infos.into_iter().map(|info| Player::new(info.0, info.1)).collect(),
@@ -184,6 +204,7 @@ impl QuantumGame {
 
     pub fn all_universes_finished(&self) -> bool { self.player_universes.len() == 0 }
 
+    /// Universes won by the player that wins in the most of them.
     pub fn get_winning_player_universes(&self) -> usize {
 
         self.universe_scores.iter()
@@ -198,7 +219,7 @@ impl QuantumGame {
         let number_of_players : usize = self.number_of_players;
 
         let mut new_universes : HashMap<Vec<(Value, Value)>, usize> = HashMap::new();
-        println!("🪐  Number of different state Universes: {}", self.player_universes.len());
+        log::debug!("🪐  Number of different state Universes: {}", self.player_universes.len());
         for (players, &count) in self.player_universes.iter() {
             let mut rolls : Vec<Value> = vec![0];
             for _ in 0..self.number_of_rolls {
@@ -239,4 +260,101 @@ impl QuantumGame {
         self.next_player_index = (self.next_player_index + 1) % number_of_players;
         self.player_universes = new_universes;
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        let lines : Vec<String> = self.players.iter()
+            .map(|player| format!("{}: space {}, score {}", player.identification, player.get_position(), player.get_score()))
+            .collect();
+        return write!(f, "Rolls {}:\n{}", self.iterations, lines.join("\n"));
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 plays with a 100 sided deterministic die, part 2 with a three sided quantum one.
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<(String, Value)>;
+    type Answer1 = Value;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(String, Value)>, InputError> {
+        return aoc_input::lines(input).enumerate()
+            .map(|(index, line)| {
+                let splitted : Vec<&str> = line.split(" starting position: ").collect();
+                if splitted.len() != 2 { return Err(InputError::malformed("expected '<player> starting position: <position>'", index + 1)) }
+                let position : Value = aoc_input::parse_value(splitted[1], index + 1, splitted[0].len() + 21)?;
+                return Ok((splitted[0].to_owned(), position));
+            }).collect();
+    }
+
+    fn part1(player_infos: &Vec<(String, Value)>) -> AocResult<Value> {
+        check_players(player_infos, (1, 10))?;
+        let mut game = Game::new(player_infos.clone(), (1, 10), (1, 100), 1000, 3);
+        while !game.some_player_won() { game.next_play() }
+        return Ok(game.get_iteration() as Value * game.get_loosing_score());
+    }
+
+    fn part2(player_infos: &Vec<(String, Value)>) -> AocResult<usize> {
+        check_players(player_infos, (1, 10))?;
+        let mut game = QuantumGame::new(player_infos.clone(), (1, 10), (1, 3), 21, 3);
+        while !game.all_universes_finished() { game.next_play() }
+        return Ok(game.get_winning_player_universes());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn deterministic_dice_wraps() {
+        let mut dice = DiracDice::new(1, 3);
+        let rolls : Vec<Value> = (0..5).map(|_| dice.roll_dice()).collect();
+        assert_eq!(rolls, vec!(1, 2, 3, 1, 2));
+    }
+
+    #[test]
+    fn quantum_dice_splits_every_value() {
+        let mut dice = QuantumDiracDice::new(1, 3);
+        assert_eq!(dice.roll_dice(), vec!(1, 2, 3));
+    }
+
+    #[test]
+    fn first_turn_of_the_example() {
+        let mut game = Game::new(vec!(("1".to_owned(), 4), ("2".to_owned(), 8)), (1, 10), (1, 100), 1000, 3);
+        game.next_play();
+        assert_eq!(game.players[0].get_position(), 10);
+        assert_eq!(game.players[0].get_score(), 10);
+    }
+
+    #[test]
+    fn game_shows_every_player() {
+        let mut game = Game::new(vec!(("Player 1".to_owned(), 4), ("Player 2".to_owned(), 8)), (1, 10), (1, 100), 1000, 3);
+        game.next_play();
+        assert_eq!(game.to_string(), "Rolls 3:\nPlayer 1: space 10, score 10\nPlayer 2: space 8, score 0");
+    }
+
+    #[test]
+    fn players_outside_the_board_are_rejected() {
+        assert!(check_players(&vec!(("Player 1".to_owned(), 4), ("Player 2".to_owned(), 8)), (1, 10)).is_ok());
+        assert!(check_players(&vec!(("Player 1".to_owned(), 4), ("Player 2".to_owned(), 11)), (1, 10)).is_err());
+    }
+
+    #[test]
+    fn games_need_exactly_two_players() {
+        let single_player = Day21::parse("Player 1 starting position: 4").unwrap();
+        assert!(matches!(Day21::part1(&single_player), Err(AocError::Input(_))));
+        assert!(matches!(Day21::part2(&single_player), Err(AocError::Input(_))));
+
+        let three_players = Day21::parse("Player 1 starting position: 4\nPlayer 2 starting position: 8\nPlayer 3 starting position: 1").unwrap();
+        assert!(check_players(&three_players, (1, 10)).is_err());
+    }
+}
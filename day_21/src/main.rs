@@ -1,24 +1,9 @@
-mod read;
-mod lib;
-
-use lib::{Game, QuantumGame};
+use aoc_common::Solution;
+use day_21::{Game, QuantumGame, Day21};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let player_infos : Vec<(String, i64)> = lines.into_iter()
-        .map(|line| {
-
-            let splitted = line.replace(" starting position: ", ":").split(":")
-                .into_iter()
-                .map(|elem| elem.to_owned())
-                .collect::<Vec<String>>();
-
-            let name = splitted[0].clone();
-            let position : i64 = splitted[1].parse().unwrap();
-
-            return (name, position);
-        }).collect();
+    let player_infos = Day21::parse(&aoc_input::read_to_string("../inputs/day21.txt").unwrap()).unwrap();
 
     let mut game : Game = Game::new(player_infos.clone(), (1, 10), (1, 100), 1000, 3);
     let mut quantum_game : QuantumGame = QuantumGame::new(player_infos, (1, 10), (1, 3), 21, 3);
@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_21::Day21;
+
+const EXAMPLE : &str = include_str!("../../inputs/day21.example.txt");
+const INPUT : &str = include_str!("../../inputs/day21.txt");
+
+fn parse(input: &str) -> <Day21 as Solution>::Input { Day21::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day21::part1(&parse(EXAMPLE)).unwrap(), 739785);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day21::part2(&parse(EXAMPLE)).unwrap(), 444356092776315);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day21::part1(&parse(INPUT)).unwrap(), 921585);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day21::part2(&parse(INPUT)).unwrap(), 911090395997650);
+}
@@ -1,7 +1,11 @@
+//! Day 22: Reactor Reboot, turning cuboids of reactor cubes on and off.
+
 use std::fmt;
 
 use std::collections::{HashMap};
-use std::io::{Write, stdout};
+
+use aoc_common::{AocError, AocResult, InputError, Progress, Solution, Supervisor};
+use aoc_common::grid::Grid;
 
 // ================================================== STRUCTS ==================================================
 
@@ -12,8 +16,8 @@ struct Cube {
     state:  CubeState,
 }
 
-type PositionUnit = i64;
-type Position = (PositionUnit, PositionUnit, PositionUnit);
+pub type PositionUnit = i64;
+pub type Position = (PositionUnit, PositionUnit, PositionUnit);
 
 struct Rule {
     to_state:   CubeState,
@@ -22,34 +26,39 @@ struct Rule {
     z_range:    (PositionUnit, PositionUnit),
 }
 
+/// Follows every cube inside the limits one by one, only fit for small regions.
 pub struct LimitedMap {
     iteration:  usize,
     rules:      Vec<Rule>,
     cube_map:   HashMap<Position, Cube>,
     limit_x:    Option<(PositionUnit, PositionUnit)>,
     limit_y:    Option<(PositionUnit, PositionUnit)>,
-    limit_z:    Option<(PositionUnit, PositionUnit)>
+    limit_z:    Option<(PositionUnit, PositionUnit)>,
+    supervisor: Supervisor,
 }
 
+/// A cuboid of cubes between two opposite corners, both included.
 #[derive(Copy, Clone)]
-struct Region {
+pub struct Region {
     start_position: Position,
     end_position:   Position
 }
 
+/// Keeps the cuboids that are on, splitting them when part of them is turned off.
 pub struct UnlimitedMap {
     iteration:          usize,
     rules:              Vec<Rule>,
-    current_regions:    Vec<Region>
+    current_regions:    Vec<Region>,
+    supervisor:         Supervisor,
 }
 
 // ================================================== AUX FUNCTIONS ==================================================
 
-fn convert_string_to_state(state_str: &str) -> CubeState {
+fn convert_string_to_state(state_str: &str) -> AocResult<CubeState> {
     match state_str {
-        "off" => CubeState::Off,
-        "on" => CubeState::On,
-        _ => panic!("🚨  Unrecognized state '{}'", state_str),
+        "off" => Ok(CubeState::Off),
+        "on" => Ok(CubeState::On),
+        _ => Err(AocError::malformed(&format!("unrecognized state '{}'", state_str))),
     }
 }
 
@@ -70,9 +79,9 @@ impl Cube {
 
 impl Rule {
 
-    fn new(state_str: String, ranges: Vec<(char, PositionUnit, PositionUnit)>) -> Rule {
+    fn new(state_str: String, ranges: Vec<(char, PositionUnit, PositionUnit)>) -> AocResult<Rule> {
 
-        let state = convert_string_to_state(&state_str);
+        let state = convert_string_to_state(&state_str)?;
         let mut x_info : Option<(PositionUnit, PositionUnit)> = None;
         let mut y_info : Option<(PositionUnit, PositionUnit)> = None;
         let mut z_info : Option<(PositionUnit, PositionUnit)> = None;
@@ -80,31 +89,33 @@ impl Rule {
         for range in ranges.into_iter() {
 
             let axis_characther : char = range.0;
+            if range.1 > range.2 { return Err(AocError::malformed(&format!("{} range starts after it ends", axis_characther))) }
             if axis_characther == 'x' { x_info = Some((range.1, range.2)) }
             if axis_characther == 'y' { y_info = Some((range.1, range.2)) }
             if axis_characther == 'z' { z_info = Some((range.1, range.2)) }
         }
 
-        if x_info.is_none() { panic!("🚨  X Range not defined for the given rule") }
-        if y_info.is_none() { panic!("🚨  Y Range not defined for the given rule") }
-        if z_info.is_none() { panic!("🚨  Z Range not defined for the given rule") }
+        if x_info.is_none() { return Err(AocError::malformed("x range not defined for the given rule")) }
+        if y_info.is_none() { return Err(AocError::malformed("y range not defined for the given rule")) }
+        if z_info.is_none() { return Err(AocError::malformed("z range not defined for the given rule")) }
 
-        Rule {
+        Ok(Rule {
             to_state: state,
             x_range: x_info.unwrap(),
             y_range: y_info.unwrap(),
             z_range: z_info.unwrap(),
-        }
+        })
     }
 }
 
 impl LimitedMap {
 
-    pub fn new(rules_info: Vec<(String, Vec<(char, PositionUnit, PositionUnit)>)>, limits_info: Vec<(char, PositionUnit, PositionUnit)>) -> LimitedMap {
+    /// Rules are 'on' or 'off' with a range for each axis, limits clip the ranges on the axes they name.
+    pub fn new(rules_info: Vec<(String, Vec<(char, PositionUnit, PositionUnit)>)>, limits_info: Vec<(char, PositionUnit, PositionUnit)>) -> AocResult<LimitedMap> {
 
         let rules : Vec<Rule> = rules_info.into_iter()
             .map(|rule_info| Rule::new(rule_info.0, rule_info.1))
-            .collect();
+            .collect::<AocResult<Vec<Rule>>>()?;
 
         let mut x_info_limit : Option<(PositionUnit, PositionUnit)> = None;
         let mut y_info_limit : Option<(PositionUnit, PositionUnit)> = None;
@@ -116,33 +127,54 @@ impl LimitedMap {
             if axis_characther == 'z' { z_info_limit = Some((range.1, range.2)) }
         }
 
-        LimitedMap {
+        Ok(LimitedMap {
             iteration: 0,
             rules: rules,
             cube_map: HashMap::new(),
             limit_x: x_info_limit,
             limit_y: y_info_limit,
             limit_z: z_info_limit,
-        }
+            supervisor: Supervisor::new(),
+        })
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> LimitedMap {
+        self.supervisor = supervisor;
+        return self;
     }
 
     pub fn completed_rules(&self) -> bool { self.rules.len() == self.iteration }
+    /// Number of cubes in the given state, only cubes that were ever turned on are known to be off.
     pub fn compute_with_state(&self, state: CubeState) -> usize {
         self.cube_map.iter()
             .filter(|(_, cube)| cube.state == state)
             .count()
     }
 
+    /// Number of cubes on along z for every (x, y) inside the limits, `None` without x and y limits.
+    pub fn get_top_view(&self) -> Option<Grid<usize>> {
+
+        let (limit_x, limit_y) = (self.limit_x?, self.limit_y?);
+        let width : usize = (limit_x.1 - limit_x.0 + 1) as usize;
+        let height : usize = (limit_y.1 - limit_y.0 + 1) as usize;
+
+        let mut top_view : Grid<usize> = Grid::filled(width, height, 0);
+        for (position, cube) in self.cube_map.iter() {
+
+            if cube.state != CubeState::On { continue }
+            let grid_position = ((position.0 - limit_x.0) as usize, (position.1 - limit_y.0) as usize);
+            top_view[grid_position] = top_view[grid_position] + 1;
+        }
+
+        return Some(top_view);
+    }
+
     pub fn do_iteration(&mut self) {
 
         if self.completed_rules() { return }
 
-        print!("\r⚙️  Processing {} out of {} ({:.2}%)...", 
-            self.iteration + 1,
-            self.rules.len(),
-            (self.iteration as f64 + 1.0) / (self.rules.len() as f64) * 100.0 );
+        self.supervisor.report(&Progress::new("rules", self.iteration + 1, Some(self.rules.len())));
 
-        stdout().flush().unwrap();
         
         let target_rule : &Rule = self.rules.get(self.iteration).unwrap();
         let mut x_range = target_rule.x_range;
@@ -177,7 +209,7 @@ impl LimitedMap {
 
 impl Region {
 
-    fn new(start_point: Position, end_point: Position) -> Region {
+    pub fn new(start_point: Position, end_point: Position) -> Region {
 
         Region {
             start_position: start_point,
@@ -185,7 +217,11 @@ impl Region {
         }
     }
 
-    fn compute_size(&self) -> i128 {
+    pub fn get_start_position(&self) -> Position { self.start_position }
+    pub fn get_end_position(&self) -> Position { self.end_position }
+
+    /// Number of cubes inside the region.
+    pub fn compute_size(&self) -> i128 {
 
         let x_range = (self.start_position.0 - self.end_position.0).abs() as i128 + 1;
         let y_range = (self.start_position.1 - self.end_position.1).abs() as i128 + 1;
@@ -194,12 +230,13 @@ impl Region {
         return x_range * y_range * z_range;
     }
 
-    fn subtract_region(&self, region_to_remove: &Region) -> Vec<Region> {
+    /// What is left of the region once `region_to_remove` is taken out of it, as at most six regions.
+    pub fn subtract_region(&self, region_to_remove: &Region) -> Vec<Region> {
 
         if self.end_position.0 < region_to_remove.start_position.0 || self.start_position.0 > region_to_remove.end_position.0 ||
             self.end_position.1 < region_to_remove.start_position.1 || self.start_position.1 > region_to_remove.end_position.1 ||
             self.end_position.2 < region_to_remove.start_position.2 || self.start_position.2 > region_to_remove.end_position.2 {
-                return vec!(self.clone());
+                return vec!(*self);
             }
 
         let intersection_x_lower = std::cmp::max(self.start_position.0, region_to_remove.start_position.0) - 1;
@@ -240,7 +277,8 @@ impl Region {
         return new_regions_filtered;
     }
 
-    fn compute_intersection(&self, other_region: &Region) -> Option<Region> {
+    /// The region shared by both, `None` when they do not touch.
+    pub fn compute_intersection(&self, other_region: &Region) -> Option<Region> {
      
         let intersection_x_lower = std::cmp::max(self.start_position.0, other_region.start_position.0);
         let intersection_x_higher = std::cmp::min(self.end_position.0, other_region.end_position.0);
@@ -263,20 +301,30 @@ impl Region {
 
 impl UnlimitedMap {
 
-    pub fn new(rules_info: Vec<(String, Vec<(char, PositionUnit, PositionUnit)>)>) -> UnlimitedMap {
+    pub fn new(rules_info: Vec<(String, Vec<(char, PositionUnit, PositionUnit)>)>) -> AocResult<UnlimitedMap> {
 
         let rules : Vec<Rule> = rules_info.into_iter()
             .map(|rule_info| Rule::new(rule_info.0, rule_info.1))
-            .collect();
+            .collect::<AocResult<Vec<Rule>>>()?;
 
-        UnlimitedMap {
+        Ok(UnlimitedMap {
             iteration: 0,
             rules: rules,
             current_regions: Vec::new(),
-        }
+            supervisor: Supervisor::new(),
+        })
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> UnlimitedMap {
+        self.supervisor = supervisor;
+        return self;
     }
 
     pub fn completed_rules(&self) -> bool { self.rules.len() == self.iteration }
+    /// The regions turned on so far, they may overlap each other.
+    pub fn get_regions(&self) -> &Vec<Region> { &self.current_regions }
+
+    /// Number of cubes that are on.
     pub fn compute_on_state(&mut self) -> i128 {
 
         let intersection_size = self.compute_intersection_size();
@@ -291,11 +339,7 @@ impl UnlimitedMap {
 
         if self.completed_rules() { return }
 
-        print!("\r⚙️  Processing {} out of {} ({:.2}%)...", 
-            self.iteration + 1,
-            self.rules.len(),
-            (self.iteration as f64 + 1.0) / (self.rules.len() as f64) * 100.0 );
-        stdout().flush().unwrap();
+        self.supervisor.report(&Progress::new("rules", self.iteration + 1, Some(self.rules.len())));
         
         let target_rule : &Rule = self.rules.get(self.iteration).unwrap();
         let target_region : Region = Region::new((target_rule.x_range.0, target_rule.y_range.0, target_rule.z_range.0),
@@ -321,15 +365,12 @@ impl UnlimitedMap {
         self.iteration = self.iteration + 1;
     }
 
-    fn compute_intersection_size(&self) -> i128 {
+    fn compute_intersection_size(&mut self) -> i128 {
 
         let mut intersection_size : i128 = 0;
         for region_index in 0..self.current_regions.len() {
             
-            print!("\r⚙️  Processing 'region intersection' {} out of {} ({:.2}%)...", 
-                region_index + 1,
-                self.current_regions.len(),
-                (region_index as f64 + 1.0) / (self.current_regions.len() as f64) * 100.0 );
+            self.supervisor.report(&Progress::new("region intersections", region_index + 1, Some(self.current_regions.len())));
             
             let mut intersections : Vec<Region> = Vec::new();
             let region = self.current_regions.get(region_index).unwrap();
@@ -371,4 +412,125 @@ impl fmt::Display for Region {
         let end : String = format!("({}, {}, {})", self.end_position.0, self.end_position.1, self.end_position.2);
         return write!(f, "{} => {}", start, end);
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 only follows the cubes within 50 of the origin, part 2 every cube.
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<(String, Vec<(char, PositionUnit, PositionUnit)>)>;
+    type Answer1 = usize;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        return aoc_input::lines(input).enumerate()
+            .map(|(index, line)| {
+
+                let split_white : Vec<&str> = line.split_whitespace().collect();
+                if split_white.len() != 2 || (split_white[0] != "on" && split_white[0] != "off") {
+                    return Err(InputError::malformed("expected '<on|off> x=<min>..<max>,y=<min>..<max>,z=<min>..<max>'", index + 1));
+                }
+
+                let ranges : Vec<(char, PositionUnit, PositionUnit)> = split_white[1].split(',')
+                    .map(|axis_info| {
+
+                        let axis_characther = axis_info.chars().next().unwrap_or(' ');
+                        if !"xyz".contains(axis_characther) || !axis_info[1..].starts_with('=') {
+                            return Err(InputError::malformed("expected '<x|y|z>=<min>..<max>'", index + 1));
+                        }
+
+                        let values : Vec<PositionUnit> = aoc_input::parse_list(&axis_info[2..], "..", index + 1)?;
+                        if values.len() != 2 { return Err(InputError::malformed("expected '<x|y|z>=<min>..<max>'", index + 1)) }
+                        return Ok((axis_characther, values[0], values[1]));
+                    }).collect::<Result<_, _>>()?;
+
+                return Ok((split_white[0].to_owned(), ranges));
+            }).collect();
+    }
+
+    fn part1(rules: &Self::Input) -> AocResult<usize> { Self::part1_supervised(rules, Supervisor::new()) }
+    fn part2(rules: &Self::Input) -> AocResult<i128> { Self::part2_supervised(rules, Supervisor::new()) }
+
+    fn part1_supervised(rules: &Self::Input, supervisor: Supervisor) -> AocResult<usize> {
+        let limits : Vec<(char, PositionUnit, PositionUnit)> = vec![('x', -50, 50), ('y', -50, 50), ('z', -50, 50)];
+        let mut map = LimitedMap::new(rules.clone(), limits)?.with_supervisor(supervisor);
+        while !map.completed_rules() { map.do_iteration() }
+        return Ok(map.compute_with_state(CubeState::On));
+    }
+
+    fn part2_supervised(rules: &Self::Input, supervisor: Supervisor) -> AocResult<i128> {
+        let mut map = UnlimitedMap::new(rules.clone())?.with_supervisor(supervisor);
+        while !map.completed_rules() { map.do_iteration() }
+        return Ok(map.compute_on_state());
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn ranges_are_limited() {
+        assert_eq!(limit_range((-60, 10), (-50, 50)), (-50, 10));
+        assert_eq!(limit_range((60, 70), (-50, 50)), (60, 50));
+    }
+
+    #[test]
+    fn region_size_and_intersection() {
+        let region = Region::new((10, 10, 10), (12, 12, 12));
+        let other = Region::new((11, 11, 11), (13, 13, 13));
+        assert_eq!(region.compute_size(), 27);
+        assert_eq!(region.compute_intersection(&other).map(|intersection| intersection.compute_size()), Some(8));
+        assert!(region.compute_intersection(&Region::new((20, 20, 20), (21, 21, 21))).is_none());
+    }
+
+    #[test]
+    fn subtracting_keeps_the_remainder() {
+        let region = Region::new((10, 10, 10), (12, 12, 12));
+        let remaining : i128 = region.subtract_region(&Region::new((11, 11, 11), (13, 13, 13))).iter()
+            .map(|region| region.compute_size())
+            .sum();
+        assert_eq!(remaining, 27 - 8);
+    }
+
+    #[test]
+    fn top_view_counts_cubes_along_z() {
+        let rules = vec!(("on".to_owned(), vec!(('x', 0, 1), ('y', 0, 0), ('z', -2, 2))), ("off".to_owned(), vec!(('x', 1, 1), ('y', 0, 0), ('z', 0, 0))));
+        let mut map = LimitedMap::new(rules, vec!(('x', -1, 1), ('y', 0, 1), ('z', -1, 1))).unwrap();
+        while !map.completed_rules() { map.do_iteration() }
+
+        let top_view = map.get_top_view().unwrap();
+        assert_eq!((top_view.width(), top_view.height()), (3, 2));
+        assert_eq!(top_view.row(0), &[0, 3, 2]);
+        assert_eq!(top_view.row(1), &[0, 0, 0]);
+        assert!(LimitedMap::new(Vec::new(), Vec::new()).unwrap().get_top_view().is_none());
+    }
+
+    #[test]
+    fn rules_are_reported_to_the_supervisor() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let updates : Rc<RefCell<Vec<Progress>>> = Rc::new(RefCell::new(Vec::new()));
+        let observed = Rc::clone(&updates);
+        let rules = vec!(("on".to_owned(), vec!(('x', 0, 1), ('y', 0, 1), ('z', 0, 1))), ("off".to_owned(), vec!(('x', 0, 0), ('y', 0, 0), ('z', 0, 0))));
+        let mut map = LimitedMap::new(rules, Vec::new()).unwrap()
+            .with_supervisor(Supervisor::new().with_observer(Box::new(move |progress: &Progress| observed.borrow_mut().push(progress.clone()))));
+
+        while !map.completed_rules() { map.do_iteration() }
+        assert_eq!(map.compute_with_state(CubeState::On), 7);
+        assert_eq!(*updates.borrow(), vec!(Progress::new("rules", 1, Some(2)), Progress::new("rules", 2, Some(2))));
+    }
+
+    #[test]
+    fn incomplete_or_reversed_rules_are_rejected() {
+        assert!(Rule::new("on".to_owned(), vec!(('x', 0, 1), ('y', 0, 1))).is_err());
+        assert!(Rule::new("on".to_owned(), vec!(('x', 0, 1), ('y', 1, 0), ('z', 0, 1))).is_err());
+        assert!(Rule::new("on".to_owned(), vec!(('x', 0, 1), ('y', 0, 1), ('z', 0, 1))).is_ok());
+    }
+}
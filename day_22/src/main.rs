@@ -1,34 +1,13 @@
-mod read;
-mod lib;
-
-use lib::{CubeState, LimitedMap, UnlimitedMap};
+use aoc_common::Solution;
+use day_22::{CubeState, LimitedMap, UnlimitedMap, Day22};
 
 fn main() {
 
-    let lines = read::read_lines("input.txt".to_owned());
-    let lines_converted : Vec<(String, Vec<(char, i64, i64)>)>= lines.into_iter()
-        .map(|line| {
-
-            let split_white : Vec<&str> = line.split_whitespace().collect();
-            let state : String = split_white[0].to_owned();
-            let ranges : Vec<(char, i64, i64)> = split_white[1].split(',').into_iter()
-                .map(|axis_info| {
-
-                    let mut axis_info_str = axis_info.to_owned();
-                    let axis_characther = axis_info_str.remove(0);
-                    axis_info_str.remove(0);
-                    let ranges : Vec<i64> = axis_info_str.split("..").into_iter()
-                        .map(|number| number.parse().unwrap())
-                        .collect();
-
-                    return (axis_characther, ranges[0], ranges[1]);
-                }).collect();
-            return (state, ranges);
-        }).collect();
+    let lines_converted = Day22::parse(&aoc_input::read_to_string("../inputs/day22.txt").unwrap()).unwrap();
 
     let limits_first : Vec<(char, i64, i64)> = vec![('x', -50, 50), ('y', -50, 50), ('z', -50, 50)];
-    let mut map_limited : LimitedMap = LimitedMap::new(lines_converted.clone(), limits_first);
-    let mut map_unlimited : UnlimitedMap = UnlimitedMap::new(lines_converted);
+    let mut map_limited : LimitedMap = LimitedMap::new(lines_converted.clone(), limits_first).unwrap();
+    let mut map_unlimited : UnlimitedMap = UnlimitedMap::new(lines_converted).unwrap();
 
     // Part 1
     while !map_limited.completed_rules() { map_limited.do_iteration() }
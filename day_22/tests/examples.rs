@@ -0,0 +1,27 @@
+use aoc_common::Solution;
+use day_22::Day22;
+
+const EXAMPLE : &str = include_str!("../../inputs/day22.example.txt");
+const INPUT : &str = include_str!("../../inputs/day22.txt");
+
+fn parse(input: &str) -> <Day22 as Solution>::Input { Day22::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day22::part1(&parse(EXAMPLE)).unwrap(), 39);
+}
+
+#[test]
+fn example_part2() {
+    assert_eq!(Day22::part2(&parse(EXAMPLE)).unwrap(), 39);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day22::part1(&parse(INPUT)).unwrap(), 647076);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day22::part2(&parse(INPUT)).unwrap(), 1233304599156793);
+}
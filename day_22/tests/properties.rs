@@ -0,0 +1,49 @@
+use proptest::prelude::*;
+
+use day_22::{CubeState, LimitedMap, UnlimitedMap};
+
+type Rules = Vec<(String, Vec<(char, i64, i64)>)>;
+
+const LIMIT : i64 = 10;
+
+fn range_strategy() -> impl Strategy<Value = (i64, i64)> {
+    return (-15..15i64, 0..12i64).prop_map(|(start, length)| (start, start + length));
+}
+
+fn rules_strategy() -> impl Strategy<Value = Rules> {
+    let rule = (any::<bool>(), range_strategy(), range_strategy(), range_strategy())
+        .prop_map(|(on, x, y, z)| {
+            let state : &str = if on { "on" } else { "off" };
+            (state.to_owned(), vec!(('x', x.0, x.1), ('y', y.0, y.1), ('z', z.0, z.1)))
+        });
+    return prop::collection::vec(rule, 0..12);
+}
+
+/// The rules with their ranges cut down to the limits, leaving out the ones falling outside.
+fn clip_rules(rules: &Rules) -> Rules {
+    return rules.iter()
+        .map(|(state, ranges)| (state.clone(), ranges.iter().map(|&(axis, start, end)| (axis, start.max(-LIMIT), end.min(LIMIT))).collect::<Vec<_>>()))
+        .filter(|(_, ranges)| ranges.iter().all(|&(_, start, end)| start <= end))
+        .collect();
+}
+
+fn count_limited(rules: Rules) -> usize {
+    let mut map = LimitedMap::new(rules, vec!(('x', -LIMIT, LIMIT), ('y', -LIMIT, LIMIT), ('z', -LIMIT, LIMIT))).unwrap();
+    while !map.completed_rules() { map.do_iteration() }
+    return map.compute_with_state(CubeState::On);
+}
+
+fn count_unlimited(rules: Rules) -> i128 {
+    let mut map = UnlimitedMap::new(rules).unwrap();
+    while !map.completed_rules() { map.do_iteration() }
+    return map.compute_on_state();
+}
+
+proptest! {
+
+    #[test]
+    fn both_maps_agree_inside_the_limits(rules in rules_strategy()) {
+        let clipped = clip_rules(&rules);
+        prop_assert_eq!(count_limited(rules) as i128, count_unlimited(clipped));
+    }
+}
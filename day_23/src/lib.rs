@@ -1,11 +1,13 @@
+//! Day 23: Amphipod, organizing the amphipods into their rooms with the least energy.
+
 use std::hash::Hash;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, BinaryHeap};
 
-// ================================================== STRUCTS ==================================================
+use aoc_common::{AocError, AocResult, InputError, Progress, Solution, Supervisor};
+use aoc_common::grid::{Grid, Position};
 
-type PositionUnit = i32;
-type Position = (PositionUnit, PositionUnit);
+// ================================================== STRUCTS ==================================================
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum AmphipodType { Amber, Bronze, Copper, Desert }
@@ -21,6 +23,9 @@ struct Amphipod {
 #[derive(PartialEq, Copy, Clone)]
 enum PositionType { Wall, Hallway, Room }
 
+// Positions outside the burrow, like the indentation of the lower rows, are empty
+type PositionMap = Grid<Option<PositionType>>;
+
 #[derive(PartialEq, Eq, Hash, Copy, Clone)]
 enum RoomType { AmberRoom, BronzeRoom, CopperRoom, DesertRoom }
 
@@ -30,32 +35,34 @@ struct Room {
     positions:  HashSet<Position>,
 }
 
-type Energy = i64;
+pub type Energy = i64;
 
+/// A state of the burrow: where every amphipod is and the energy spent to get there.
 #[derive(Clone, Eq)]
-struct Node {
+pub struct Node {
     energy_consumed:    Energy,
     amphipods:          Vec<Amphipod>,
 }
 
+/// The burrow, its rooms and the search for the cheapest way of organizing the amphipods.
 pub struct Map {
-    map_size:           (Position, Position),
-    map_positions:      HashMap<Position, PositionType>,
+    map_positions:      PositionMap,
     rooms:              HashMap<Position, Room>,
     // Node Graph
     starting_node:      Node,
     final_node:         Option<Node>,
+    supervisor:         Supervisor,
 }
 
 // ================================================== AUXILIARY FUNCTIONS ==================================================
 
-fn convert_characther_to_amphipod(characther: char) -> AmphipodType {
+fn convert_characther_to_amphipod(characther: char) -> AocResult<AmphipodType> {
     match characther {
-        'A' => AmphipodType::Amber,
-        'B' => AmphipodType::Bronze,
-        'C' => AmphipodType::Copper,
-        'D' => AmphipodType::Desert,
-        _ => panic!("🚨  Amphipod type not recognized '{}'", characther)
+        'A' => Ok(AmphipodType::Amber),
+        'B' => Ok(AmphipodType::Bronze),
+        'C' => Ok(AmphipodType::Copper),
+        'D' => Ok(AmphipodType::Desert),
+        _ => Err(AocError::malformed(&format!("amphipod type not recognized '{}'", characther))),
     }
 }
 
@@ -125,13 +132,15 @@ impl Room {
 
 impl Node {
 
-    pub fn new(amphipods: Vec<Amphipod>) -> Node {
+    fn new(amphipods: Vec<Amphipod>) -> Node {
         Node {
             energy_consumed: 0,
             amphipods: amphipods,
         }
     }
 
+    pub fn get_energy_consumed(&self) -> Energy { self.energy_consumed }
+
     fn get_amphipod_in_position(&self, position: &Position) -> Option<&Amphipod> {
 
         let mut amphipod_option : Option<&Amphipod> = None;
@@ -174,14 +183,12 @@ impl Node {
             (RoomType::BronzeRoom, AmphipodType::Bronze) => (),
             (RoomType::CopperRoom, AmphipodType::Copper) => (),
             (RoomType::DesertRoom, AmphipodType::Desert) => (),
-            (_, _) => ( return false )
+            (_, _) => return false 
         }
 
         // Amphipod must not enter a room with amphipod from wrong type
         let amphipods_in_room : Vec<&Amphipod> = room.positions.iter()
-            .map(|position| self.get_amphipod_in_position(position))
-            .filter(|amphipod| amphipod.is_some())
-            .map(|amphipod| amphipod.unwrap())
+            .filter_map(|position| self.get_amphipod_in_position(position))
             .collect();
         for amphipod_in_room in amphipods_in_room.iter() {
             match (room.room_type, amphipod_in_room.amphipod_type) {
@@ -189,7 +196,7 @@ impl Node {
                 (RoomType::BronzeRoom, AmphipodType::Bronze) => (),
                 (RoomType::CopperRoom, AmphipodType::Copper) => (),
                 (RoomType::DesertRoom, AmphipodType::Desert) => (),
-                (_, _) => ( return false )
+                (_, _) => return false 
             }
         } 
 
@@ -219,11 +226,11 @@ impl Node {
         return lowest_y == *position;
     }
 
-    fn transversable(&self, position_map: &HashMap<Position, PositionType>, position: Position, amphipod: &Amphipod) -> bool {
+    fn transversable(&self, position_map: &PositionMap, position: Position, amphipod: &Amphipod) -> bool {
 
         if amphipod.number_moves >= 2 { return false }
 
-        let position_type = position_map.get(&position);
+        let position_type = position_map.get(position).and_then(|position_type| position_type.as_ref());
         let amphipod_in_position = self.get_amphipod_in_position(&position);
 
         // If not in map not valid
@@ -238,17 +245,17 @@ impl Node {
         if *position_type == PositionType::Hallway { return true }
         if *position_type == PositionType::Room { return true }
 
-        panic!("🚨  I think it should never reach this position");
+        unreachable!("Every position type is handled above");
     }
 
-    fn movable_position(&self, position_map: &HashMap<Position, PositionType>, rooms: &HashMap<Position, Room>, position: Position, amphipod: &Amphipod) -> bool {
+    fn movable_position(&self, position_map: &PositionMap, rooms: &HashMap<Position, Room>, position: Position, amphipod: &Amphipod) -> bool {
 
-        let position_type = position_map.get(&position);
+        let position_type = position_map.get(position).and_then(|position_type| position_type.as_ref());
         let room = rooms.get(&position);
         let amphipod_in_position = self.get_amphipod_in_position(&position);
 
         // Efficiency
-        let position_type_under = position_map.get(&(position.0, position.1 + 1));
+        let position_type_under = position_map.offset(position, (0, 1)).and_then(|under| position_map[under].as_ref());
 
         // If not in map not valid
         if position_type.is_none() { return false }
@@ -269,16 +276,14 @@ impl Node {
         if *position_type == PositionType::Room {
 
             let room = room.unwrap();
-            if self.room_ready_to_receive_amphypod(room, amphipod) && self.lowest_free_position_in_room(room, &position) {
-                return true
-            } else { return false }
+            return self.room_ready_to_receive_amphypod(room, amphipod) && self.lowest_free_position_in_room(room, &position)
 
         }
 
-        panic!("🚨  I think it should never reach this position");
+        unreachable!("Every position type is handled above");
     }
 
-    fn get_movable_positions(&self, position_map: &HashMap<Position, PositionType>, rooms: &HashMap<Position, Room>, amphipod: &Amphipod) -> Vec<(usize, Position)> {
+    fn get_movable_positions(&self, position_map: &PositionMap, rooms: &HashMap<Position, Room>, amphipod: &Amphipod) -> Vec<(usize, Position)> {
 
         type Path = Vec<Position>;
 
@@ -289,31 +294,23 @@ impl Node {
 
             let expand_path = active_paths.pop().unwrap();
             let last_position = expand_path.last().unwrap();
-            for x_var in -1..=1 {
-                for y_var in -1..=1 {
-
-                    if x_var == 0 && y_var == 0 { continue }
-                    if x_var != 0 && y_var != 0 { continue }
+            for new_position in position_map.neighbours(*last_position) {
 
-                    let new_position = (last_position.0 + x_var, last_position.1 + y_var);
-                    if expand_path.contains(&new_position) { continue }
+                if expand_path.contains(&new_position) { continue }
 
-                    let transversable = self.transversable(position_map, new_position, amphipod);
-                    if !transversable { continue }
+                let transversable = self.transversable(position_map, new_position, amphipod);
+                if !transversable { continue }
 
-                    let mut new_expanded_path = expand_path.clone();
-                    new_expanded_path.push(new_position);
-                    active_paths.push(new_expanded_path.clone());
+                let mut new_expanded_path = expand_path.clone();
+                new_expanded_path.push(new_position);
+                active_paths.push(new_expanded_path.clone());
 
 
-                    let movable_to_position = self.movable_position(position_map, rooms, new_position, amphipod);
-                    if movable_to_position { valid_paths.push(new_expanded_path) }
-                }
+                let movable_to_position = self.movable_position(position_map, rooms, new_position, amphipod);
+                if movable_to_position { valid_paths.push(new_expanded_path) }
             }
         }
 
-        //println!("Valid Paths: {:?}", valid_paths);
-
         return valid_paths.into_iter()
             .map(|mut path| (path.len() - 1, path.pop().unwrap()))
             .collect();
@@ -332,14 +329,14 @@ impl Node {
                 (RoomType::BronzeRoom, AmphipodType::Bronze) => (),
                 (RoomType::CopperRoom, AmphipodType::Copper) => (),
                 (RoomType::DesertRoom, AmphipodType::Desert) => (),
-                (_, _) => ( return false )
+                (_, _) => return false 
             }
         }
 
         return true;
     }
 
-    fn create_generated_nodes(&self, position_map: &HashMap<Position, PositionType>, rooms: &HashMap<Position, Room>) -> Vec<Node> {
+    fn create_generated_nodes(&self, position_map: &PositionMap, rooms: &HashMap<Position, Room>) -> Vec<Node> {
 
         let mut new_nodes : Vec<Node> = Vec::new();
         for amphipod in self.amphipods.iter() {
@@ -349,12 +346,12 @@ impl Node {
             for movement_possibility in movement_possibilities.iter() {
 
                 let mut new_node = self.clone();
-                let position_type = position_map.get(&movement_possibility.1).unwrap();
+                let position_type = position_map[movement_possibility.1].as_ref().unwrap();
 
                 let added_energy = amphipod_cost * movement_possibility.0 as Energy;
                 new_node.energy_consumed += added_energy;
 
-                let mut corresponding_amphipod = new_node.get_mut_amphipod_in_position(&amphipod.position).unwrap();
+                let corresponding_amphipod = new_node.get_mut_amphipod_in_position(&amphipod.position).unwrap();
                 corresponding_amphipod.position = movement_possibility.1;
                 corresponding_amphipod.number_moves += 1;
 
@@ -388,16 +385,17 @@ impl PartialEq for Node {
 
 impl Map {
 
-    pub fn new(info_lines: Vec<String>) -> Map {
+    pub fn new(info_lines: Vec<String>) -> AocResult<Map> {
+
+        if info_lines.len() == 0 { return Err(AocError::malformed("the burrow is empty")) }
 
-        let map_limit_x = info_lines.iter().map(|line| line.len()).max().unwrap() as PositionUnit - 1;
-        let map_limit_y = info_lines.len() as PositionUnit - 1;
-        let map_limit : Position = (map_limit_x, map_limit_y);
+        let map_width = info_lines.iter().map(|line| line.chars().count()).max().unwrap();
+        if map_width == 0 { return Err(AocError::malformed("the burrow is empty")) }
         
         let room_type_order : Vec<RoomType> = vec!(RoomType::AmberRoom, RoomType::BronzeRoom,
             RoomType::CopperRoom, RoomType::DesertRoom);
         
-        let mut map_positions : HashMap<Position, PositionType> = HashMap::new();
+        let mut map_positions : PositionMap = Grid::filled(map_width, info_lines.len(), None);
 
         let mut amphipods : Vec<Amphipod> = Vec::new();
         let mut room_info : HashMap<RoomType, HashSet<Position>> = HashMap::new();
@@ -406,23 +404,24 @@ impl Map {
         for (line_index, line) in info_lines.into_iter().enumerate() {
 
             let mut current_room_index : usize = 0;
-            for (char_index, characther) in line.chars().into_iter().enumerate() {
+            for (char_index, characther) in line.chars().enumerate() {
 
-                let current_position : Position = (char_index as PositionUnit, line_index as PositionUnit);
+                let current_position : Position = (char_index, line_index);
                 match (line_index, characther) {
                     (_, ' ') => {},
-                    (_, '#') => { map_positions.insert(current_position, PositionType::Wall); },
-                    (line_index, '.') if line_index <= 1 => { map_positions.insert(current_position, PositionType::Hallway); },
+                    (_, '#') => { map_positions[current_position] = Some(PositionType::Wall); },
+                    (line_index, '.') if line_index <= 1 => { map_positions[current_position] = Some(PositionType::Hallway); },
                     (_, characther) => {
                         
-                        map_positions.insert(current_position, PositionType::Room);
+                        map_positions[current_position] = Some(PositionType::Room);
                         // Add to rooms
-                        let room_type = room_type_order.get(current_room_index).unwrap();
+                        let room_type = room_type_order.get(current_room_index)
+                            .ok_or_else(|| AocError::malformed("there are only four rooms in the burrow"))?;
                         let room_type_info = room_info.get_mut(room_type).unwrap();
                         room_type_info.insert(current_position);
                         // Add to Amphipod if justifiable
                         if characther != '.' {
-                            let amphipod_type = convert_characther_to_amphipod(characther);
+                            let amphipod_type = convert_characther_to_amphipod(characther)?;
                             let amphipod : Amphipod = Amphipod::new(amphipod_type, current_position);
                             amphipods.push(amphipod);
                         }
@@ -441,16 +440,22 @@ impl Map {
             }
         }
 
-        Map {
-            map_size:           ((0, 0), map_limit),
+        Ok(Map {
             map_positions:      map_positions,
             rooms:              rooms,
             starting_node:      Node::new(amphipods),
             final_node:         None,
-        }
+            supervisor:         Supervisor::new(),
+        })
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> Map {
+        self.supervisor = supervisor;
+        return self;
     }
 
-    pub fn reach_final_node(&mut self) {
+    /// Searches the cheapest organization, giving up with the cheapest one so far once the budget is spent.
+    pub fn reach_final_node(&mut self) -> AocResult<()> {
 
         let mut visited : HashSet<String> = HashSet::new();
 
@@ -459,12 +464,18 @@ impl Map {
         while active_nodes.len() != 0 {
 
             let current_node = active_nodes.pop().unwrap();
-            print!("\r⚙️  Processing {} active scenarios ( min = {} ) ...", 
-                active_nodes.len(), current_node.energy_consumed);
+            self.supervisor.report(&Progress::new("scenarios explored", visited.len(), None));
+            if self.supervisor.exhausted() {
+                let partial : String = self.final_node.as_ref()
+                    .map(|final_node| format!("the cheapest organization found so far takes {} energy", final_node.energy_consumed))
+                    .unwrap_or_else(|| "no organization found yet".to_owned());
+                let cheapest : Vec<Energy> = self.final_node.iter().map(|final_node| final_node.energy_consumed).collect();
+                return Err(self.supervisor.give_up_with_answers(&partial, cheapest));
+            }
 
             if visited.contains(&current_node.get_code()) { continue }
             visited.insert(current_node.get_code());
-            //self.print(&current_node);
+            log::trace!("{}", self.draw(&current_node));
 
             if self.final_node.is_some() && self.final_node.as_ref().unwrap().energy_consumed < current_node.energy_consumed { break }
             
@@ -473,28 +484,35 @@ impl Map {
                 if generated_node.finishing_node(&self.rooms) && (
                     self.final_node.is_none() || self.final_node.as_ref().unwrap().energy_consumed > generated_node.energy_consumed
                 ) { self.final_node = Some(generated_node.clone()); }
-                
-                let code_of_generated = generated_node.get_code();
-                
-                //self.print(&generated_node);
-                //println!("{}", code_of_generated);
+
                 active_nodes.push(generated_node);
             }
         }
+
+        return Ok(());
     }
 
-    pub fn get_energy_of_final_node(&self) -> Energy { self.final_node.as_ref().unwrap().energy_consumed }
+    pub fn get_starting_node(&self) -> &Node { &self.starting_node }
+    /// The cheapest organized burrow, once found.
+    pub fn get_final_node(&self) -> Option<&Node> { self.final_node.as_ref() }
+
+    pub fn get_energy_of_final_node(&self) -> AocResult<Energy> {
+        return self.final_node.as_ref()
+            .map(|final_node| final_node.energy_consumed)
+            .ok_or_else(|| AocError::unsolvable("the amphipods cannot be organized"));
+    }
 
-    fn print(&self, node: &Node) {
+    /// The burrow drawn like the puzzle input, with the amphipods of `node` and empty rooms in lowercase.
+    pub fn draw(&self, node: &Node) -> String {
 
         let mut print_info : String = String::new();
-        for y_value in self.map_size.0.1..=self.map_size.1.1 {
-            for x_value in self.map_size.0.0..=self.map_size.1.0 {
+        for y_value in 0..self.map_positions.height() {
+            for x_value in 0..self.map_positions.width() {
 
-                let position = (x_value as PositionUnit, y_value as PositionUnit);
+                let position = (x_value, y_value);
 
                 let amphipod = node.get_amphipod_in_position(&position);
-                let position_type = self.map_positions.get(&position);
+                let position_type = self.map_positions[position].as_ref();
 
                 if amphipod.is_some() {
                     let amphipod_characther = convert_amphipod_type_to_characther(&amphipod.unwrap().amphipod_type);
@@ -512,6 +530,128 @@ impl Map {
             print_info = format!("{}\n", print_info);
         }
 
-        println!("{}", print_info);
+        return print_info;
     }
-}
\ No newline at end of file
+}
+
+// ================================================== SOLUTION ==================================================
+
+// The second puzzle unfolds the diagram with two extra rows in every room
+fn unfold_burrow(lines: &Vec<String>) -> Vec<String> {
+
+    let mut unfolded = lines.clone();
+    if unfolded.len() == 5 {
+        unfolded.insert(3, "  #D#C#B#A#".to_owned());
+        unfolded.insert(4, "  #D#B#A#C#".to_owned());
+    }
+
+    return unfolded;
+}
+
+/// Part 1 organizes the burrow as given, part 2 the unfolded burrow with four amphipods per room.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<String>;
+    type Answer1 = Energy;
+    type Answer2 = Energy;
+
+    fn parse(input: &str) -> Result<Vec<String>, InputError> {
+
+        let lines = aoc_input::read_lines_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(column) = line.chars().position(|characther| !" #.ABCD".contains(characther)) {
+                return Err(InputError::malformed("expected an amphipod burrow", index + 1).at(index + 1, column + 1));
+            }
+        }
+
+        return Ok(lines);
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<Energy> { Self::part1_supervised(lines, Supervisor::new()) }
+    fn part2(lines: &Vec<String>) -> AocResult<Energy> { Self::part2_supervised(lines, Supervisor::new()) }
+
+    fn part1_supervised(lines: &Vec<String>, supervisor: Supervisor) -> AocResult<Energy> {
+        let mut map = Map::new(lines.clone())?.with_supervisor(supervisor);
+        map.reach_final_node()?;
+        return map.get_energy_of_final_node();
+    }
+
+    fn part2_supervised(lines: &Vec<String>, supervisor: Supervisor) -> AocResult<Energy> {
+        let mut map = Map::new(unfold_burrow(lines))?.with_supervisor(supervisor);
+        map.reach_final_node()?;
+        return map.get_energy_of_final_node();
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn amphipods_cost_by_type() {
+        let costs : Vec<Energy> = "ABCD".chars()
+            .map(|characther| Amphipod::new(convert_characther_to_amphipod(characther).unwrap(), (0, 0)).get_movement_cost())
+            .collect();
+        assert_eq!(costs, vec!(1, 10, 100, 1000));
+        assert_eq!(convert_amphipod_type_to_characther(&convert_characther_to_amphipod('C').unwrap()), 'C');
+    }
+
+    #[test]
+    fn burrow_unfolds_once() {
+        let lines : Vec<String> = vec!("#############", "#...........#", "###B#C#B#D###", "  #A#D#C#A#", "  #########").into_iter()
+            .map(|line| line.to_owned())
+            .collect();
+
+        let unfolded = unfold_burrow(&lines);
+        assert_eq!(unfolded.len(), 7);
+        assert_eq!(unfolded[3], "  #D#C#B#A#");
+        assert_eq!(unfolded[4], "  #D#B#A#C#");
+        assert_eq!(unfold_burrow(&unfolded), unfolded);
+    }
+
+    #[test]
+    fn search_gives_up_when_out_of_steps() {
+        use aoc_common::Budget;
+
+        let lines : Vec<String> = vec!("#############", "#...........#", "###B#C#B#D###", "  #A#D#C#A#", "  #########").into_iter()
+            .map(|line| line.to_owned())
+            .collect();
+
+        let budget = Budget::unlimited().with_max_steps(10);
+        let mut map = Map::new(lines).unwrap().with_supervisor(Supervisor::new().with_budget(budget));
+        let error = map.reach_final_node().unwrap_err();
+        assert_eq!(error.to_string(), "gave up, out of steps after 11 steps, no organization found yet");
+    }
+
+    #[test]
+    fn extra_rooms_are_rejected() {
+        let lines : Vec<String> = vec!("###############", "#.............#", "###B#C#B#D#A###", "  ###########").into_iter()
+            .map(|line| line.to_owned())
+            .collect();
+
+        assert!(Map::new(lines).is_err());
+    }
+
+    #[test]
+    fn blank_burrow_is_rejected() {
+        assert!(Map::new(vec!(String::new(), String::new())).is_err());
+    }
+
+    #[test]
+    fn burrow_is_drawn_like_the_input() {
+        let lines : Vec<String> = vec!("#############", "#...........#", "###B#.#B#D###", "  #A#D#C#A#", "  #########").into_iter()
+            .map(|line| line.to_owned())
+            .collect();
+
+        let map = Map::new(lines).unwrap();
+        let drawing = map.draw(map.get_starting_node());
+        assert_eq!(drawing.lines().nth(2), Some("###B#b#B#D###"));
+        assert_eq!(drawing.lines().nth(3), Some("  #A#D#C#A#  "));
+        assert_eq!(map.get_starting_node().get_energy_consumed(), 0);
+        assert!(map.get_final_node().is_none());
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_common::Solution;
+use day_23::Day23;
+
+const EXAMPLE : &str = include_str!("../../inputs/day23.example.txt");
+const INPUT : &str = include_str!("../../inputs/day23.txt");
+
+fn parse(input: &str) -> <Day23 as Solution>::Input { Day23::parse(input).unwrap() }
+
+#[test]
+#[ignore = "amphipod search takes minutes even on the example"]
+fn example_part1() {
+    assert_eq!(Day23::part1(&parse(EXAMPLE)).unwrap(), 12521);
+}
+
+#[test]
+#[ignore = "amphipod search takes minutes even on the example"]
+fn example_part2() {
+    assert_eq!(Day23::part2(&parse(EXAMPLE)).unwrap(), 44169);
+}
+
+#[test]
+#[ignore = "amphipod search takes minutes, part 2 needs gigabytes of memory"]
+fn input_part1() {
+    assert_eq!(Day23::part1(&parse(INPUT)).unwrap(), 15516);
+}
+
+#[test]
+#[ignore = "amphipod search takes minutes, part 2 needs gigabytes of memory"]
+fn input_part2() {
+    assert_eq!(Day23::part2(&parse(INPUT)).unwrap(), 45272);
+}
+
+#[test]
+fn input_parses() {
+    assert_eq!(parse(INPUT).len(), 5);
+}
@@ -1,18 +1,21 @@
+//! Day 24: Arithmetic Logic Unit, finding the model numbers that MONAD accepts.
+
 use std::fmt;
 use std::hash::Hash;
 use std::collections::{HashMap};
 
+use aoc_common::{AocError, AocResult, InputError, Progress, Solution, Supervisor};
+
 // ================================================== STRUCTS ==================================================
 
-type Value = i64;
+pub type Value = i64;
 
-enum ArgumentType { Dimension, Value }
 enum OperationType { Inp, Add, Mul, Div, Mod, Eql}
+/// The four variables of the unit.
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
 pub enum Dimension { X, Y, Z, W }
 
 trait Argument {
-    fn get_type(&self) -> ArgumentType;
     fn get_value(&self, alu_state: &HashMap<Dimension, Value>) -> Value;
     fn store_result(&self, result: Value, alu_state: HashMap<Dimension, Value>) -> HashMap<Dimension, Value>;
 }
@@ -30,18 +33,68 @@ struct Operation {
     arguments:      Vec<Box<dyn Argument>>,
 }
 
+/// Runs a program of 'inp', 'add', 'mul', 'div', 'mod' and 'eql' instructions.
 pub struct ArithmeticLogicUnit {
     state:      HashMap<Dimension, Value>,
     operations: Vec<Operation>,
 }
 
+const NUMBER_OF_DIGITS : usize = 14;
+
+/// Ties two digits of a valid model number: `digit_to` is `digit_from` plus `offset`.
+#[derive(Copy, Clone)]
+pub struct DigitRule {
+    pub digit_from: usize,
+    pub digit_to:   usize,
+    pub offset:     Value,
+}
+
+/// Finds valid model numbers from the rules read in the structure of MONAD, checking them on the unit.
+pub struct ModelNumberSearch {
+    instructions:   Vec<String>,
+    rules:          Vec<DigitRule>,
+    supervisor:     Supervisor,
+}
+
 // ================================================== AUX FUNCTIONS ==================================================
 
+fn split_instructions_by_input(instructions: &Vec<String>) -> Vec<Vec<String>> {
+
+    let mut blocks : Vec<Vec<String>> = Vec::new();
+    let mut current_block : Vec<String> = Vec::new();
+    for line in instructions.iter() {
+        if line.starts_with("inp") {
+            if current_block.len() > 0 { blocks.push(current_block); }
+            current_block = Vec::new();
+        }
+        current_block.push(line.clone());
+    }
+    if current_block.len() > 0 { blocks.push(current_block); }
+
+    return blocks;
+}
+
+fn get_block_value(block: &Vec<String>, instruction: &str) -> Option<Value> {
+    block.iter()
+        .filter_map(|line| line.strip_prefix(instruction))
+        .filter_map(|value| value.trim().parse().ok())
+        .next()
+}
+
+fn get_block_y_offset(block: &Vec<String>) -> Option<Value> {
+    // The offset pushed to z is the constant added to y right after the input digit
+    let input_index = block.iter().position(|line| line == "add y w")?;
+    return get_block_value(&block[(input_index + 1)..].to_vec(), "add y ");
+}
+
+fn convert_digits_to_number(digits: &Vec<Value>) -> Value {
+    digits.iter().fold(0, |number, digit| number * 10 + digit)
+}
+
 // ================================================== IMPLEMENTATIONS ==================================================
 
 impl Argument for DimensionArgument {
     
-    fn get_type(&self) -> ArgumentType { ArgumentType::Dimension }
     fn get_value(&self, alu_state: &HashMap<Dimension, Value>) -> Value { *alu_state.get(&self.dimension).unwrap() }
     fn store_result(&self, result: Value, mut alu_state: HashMap<Dimension, Value>) -> HashMap<Dimension, Value> {
         alu_state.insert(self.dimension, result);
@@ -51,17 +104,16 @@ impl Argument for DimensionArgument {
 
 impl Argument for ValueArgument {
 
-    fn get_type(&self) -> ArgumentType { ArgumentType::Value }
     fn get_value(&self, _alu_state: &HashMap<Dimension, Value>) -> Value { self.value }
     fn store_result(&self, _result: Value, alu_state: HashMap<Dimension, Value>) -> HashMap<Dimension, Value> { alu_state }
 }
 
 impl Operation {
 
-    fn new(line: String) -> Operation {
+    fn new(line: String) -> AocResult<Operation> {
 
-        let mut splitted_line : Vec<&str> = line.split_whitespace()
-            .into_iter().collect();
+        let mut splitted_line : Vec<&str> = line.split_whitespace().collect();
+        if splitted_line.len() == 0 { return Err(AocError::malformed("empty instruction")) }
 
         let operation_str : &str = splitted_line.remove(0);
         let operation : OperationType = match operation_str {
@@ -71,25 +123,34 @@ impl Operation {
             "div" => OperationType::Div,
             "mod" => OperationType::Mod,
             "eql" => OperationType::Eql,
-            _ => panic!("🚨  Operation code not intrepertable")
+            _ => return Err(AocError::malformed(&format!("operation code not interpretable '{}'", operation_str))),
         };
 
+        let arguments_expected : usize = if operation_str == "inp" { 1 } else { 2 };
+        if splitted_line.len() != arguments_expected || !["x", "y", "z", "w"].contains(&splitted_line[0]) {
+            return Err(AocError::malformed(&format!("wrong arguments for '{}'", line)));
+        }
+
         let arguments : Vec<Box<dyn Argument>> = splitted_line.into_iter()
             .map(|argument_info| {
-                return match argument_info {
+                let argument = match argument_info {
                     "x" => Box::new(DimensionArgument{ dimension: Dimension::X }) as Box<dyn Argument>,
                     "y" => Box::new(DimensionArgument{ dimension: Dimension::Y }) as Box<dyn Argument>,
                     "z" => Box::new(DimensionArgument{ dimension: Dimension::Z }) as Box<dyn Argument>,
                     "w" => Box::new(DimensionArgument{ dimension: Dimension::W }) as Box<dyn Argument>,
-                    value => Box::new(ValueArgument{ value: value.parse().unwrap() }) as Box<dyn Argument>,
+                    value => match value.parse() {
+                        Ok(value) => Box::new(ValueArgument{ value: value }) as Box<dyn Argument>,
+                        Err(_) => return Err(AocError::malformed(&format!("'{}' is neither a variable nor a value", value))),
+                    },
                 };
 
-            }).collect();
+                return Ok(argument);
+            }).collect::<AocResult<Vec<Box<dyn Argument>>>>()?;
 
-        Operation {
+        Ok(Operation {
             operation_type: operation,
             arguments: arguments,
-        }
+        })
     }
 
     fn execute(&self, mut alu_state: HashMap<Dimension, Value>, mut input: Vec<Value>) -> (HashMap<Dimension, Value>, Vec<Value>) {
@@ -114,7 +175,7 @@ impl Operation {
 
 impl ArithmeticLogicUnit {
 
-    pub fn new(instructions: Vec<String>) -> ArithmeticLogicUnit {
+    pub fn new(instructions: Vec<String>) -> AocResult<ArithmeticLogicUnit> {
 
         let alu_state : HashMap<Dimension, Value> = vec!(
             (Dimension::X, 0),
@@ -124,13 +185,13 @@ impl ArithmeticLogicUnit {
         ).into_iter().collect();
 
         let instructions : Vec<Operation> = instructions.into_iter()
-            .map(|instruction| Operation::new(instruction))
-            .collect();
+            .map(Operation::new)
+            .collect::<AocResult<Vec<Operation>>>()?;
 
-        ArithmeticLogicUnit {
+        Ok(ArithmeticLogicUnit {
             state: alu_state,
             operations: instructions,
-        }
+        })
     }
 
     pub fn mannual_fix_dimension(&mut self, dimension: Dimension, value: Value) {
@@ -139,6 +200,7 @@ impl ArithmeticLogicUnit {
 
     pub fn get_dimension(&mut self, dimension: Dimension) -> Value { *self.state.get(&dimension).unwrap() }
 
+    /// Runs every instruction, taking the values for 'inp' from the end of `input`.
     pub fn process_input(&mut self, mut input: Vec<Value>) {
 
         for instruction in self.operations.iter() {
@@ -160,4 +222,209 @@ impl fmt::Display for ArithmeticLogicUnit {
 
         return write!(f, "{}", dimensions_info.join(" | "));
     }
-}
\ No newline at end of file
+}
+
+impl ModelNumberSearch {
+
+    pub fn new(instructions: Vec<String>) -> AocResult<ModelNumberSearch> {
+
+        // Candidates are checked on a fresh unit, so the instructions are validated once here
+        ArithmeticLogicUnit::new(instructions.clone())?;
+
+        // Each block either pushes 'digit + y_offset' onto z (base 26) or pops it,
+        // in which case the popped digit and the current one are tied together
+        let mut rules : Vec<DigitRule> = Vec::new();
+        let mut stack : Vec<(usize, Value)> = Vec::new();
+        for (digit_index, block) in split_instructions_by_input(&instructions).iter().enumerate() {
+
+            let divisor = get_block_value(block, "div z ");
+            let x_offset = get_block_value(block, "add x ");
+            let y_offset = get_block_y_offset(block);
+
+            match (divisor, x_offset, y_offset) {
+                (Some(1), _, Some(y_offset)) => stack.push((digit_index, y_offset)),
+                (Some(26), Some(x_offset), _) => {
+                    let popped = stack.pop();
+                    if popped.is_none() { continue }
+
+                    let (digit_from, y_offset) = popped.unwrap();
+                    rules.push(DigitRule{ digit_from: digit_from, digit_to: digit_index, offset: y_offset + x_offset });
+                },
+                _ => {},
+            }
+        }
+
+        Ok(ModelNumberSearch {
+            instructions: instructions,
+            rules: rules,
+            supervisor: Supervisor::new(),
+        })
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Supervisor) -> ModelNumberSearch {
+        self.supervisor = supervisor;
+        return self;
+    }
+
+    pub fn get_rules(&self) -> &Vec<DigitRule> { &self.rules }
+
+    /// True when the unit ends with z at zero for the given digits.
+    pub fn check_model_number(&self, digits: &Vec<Value>) -> AocResult<bool> {
+
+        let mut alu = ArithmeticLogicUnit::new(self.instructions.clone())?;
+        let input : Vec<Value> = digits.iter().rev().cloned().collect();
+        alu.process_input(input);
+        return Ok(alu.get_dimension(Dimension::Z) == 0);
+    }
+
+    /// Every candidate allowed by the rules that the unit accepts, one supervisor step per candidate checked.
+    ///
+    /// Giving up keeps the valid model numbers found so far as the partial answers of the error.
+    pub fn find_valid_model_numbers(&mut self) -> AocResult<Vec<Value>> {
+
+        if self.rules.len() * 2 != NUMBER_OF_DIGITS { return Ok(Vec::new()) }
+
+        // Every rule only allows the pairs of digits that keep both in 1..=9
+        let mut candidates : Vec<Vec<Value>> = vec!(vec!(0; NUMBER_OF_DIGITS));
+        for rule in self.rules.iter() {
+
+            let mut new_candidates : Vec<Vec<Value>> = Vec::new();
+            for candidate in candidates.iter() {
+                for digit_from in 1..=9 {
+
+                    let digit_to = digit_from + rule.offset;
+                    if !(1..=9).contains(&digit_to) { continue }
+
+                    let mut new_candidate = candidate.clone();
+                    new_candidate[rule.digit_from] = digit_from;
+                    new_candidate[rule.digit_to] = digit_to;
+                    new_candidates.push(new_candidate);
+                }
+            }
+
+            candidates = new_candidates;
+        }
+
+        let mut valid_values : Vec<Value> = Vec::new();
+        for (index, candidate) in candidates.iter().enumerate() {
+
+            if self.supervisor.exhausted() {
+                let partial : String = match (valid_values.iter().min(), valid_values.iter().max()) {
+                    (Some(minimum), Some(maximum)) => format!("{} valid model numbers found so far, between '{}' and '{}'", valid_values.len(), minimum, maximum),
+                    _ => format!("no valid model number found yet out of {} candidates", candidates.len()),
+                };
+                return Err(self.supervisor.give_up_with_answers(&partial, valid_values));
+            }
+
+            self.supervisor.report(&Progress::new("candidates checked", index, Some(candidates.len())));
+            if self.check_model_number(candidate)? { valid_values.push(convert_digits_to_number(candidate)) }
+        }
+
+        return Ok(valid_values);
+    }
+}
+
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 is the largest valid model number, part 2 the smallest.
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<String>;
+    type Answer1 = Value;
+    type Answer2 = Value;
+
+    fn parse(input: &str) -> Result<Vec<String>, InputError> {
+
+        let lines = aoc_input::read_lines_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+
+            let line_split : Vec<&str> = line.split_whitespace().collect();
+            let arguments_expected : usize = if line_split.first() == Some(&"inp") { 2 } else { 3 };
+            if line_split.len() != arguments_expected || !["inp", "add", "mul", "div", "mod", "eql"].contains(&line_split[0]) {
+                return Err(InputError::malformed("expected '<instruction> <variable> [<variable|value>]'", index + 1));
+            }
+        }
+
+        return Ok(lines);
+    }
+
+    fn part1(instructions: &Vec<String>) -> AocResult<Value> { Self::part1_supervised(instructions, Supervisor::new()) }
+    fn part2(instructions: &Vec<String>) -> AocResult<Value> { Self::part2_supervised(instructions, Supervisor::new()) }
+
+    fn part1_supervised(instructions: &Vec<String>, supervisor: Supervisor) -> AocResult<Value> {
+        let valid_values = ModelNumberSearch::new(instructions.clone())?.with_supervisor(supervisor).find_valid_model_numbers()?;
+        return valid_values.into_iter().max().ok_or_else(|| AocError::unsolvable("there is no valid model number"));
+    }
+
+    fn part2_supervised(instructions: &Vec<String>, supervisor: Supervisor) -> AocResult<Value> {
+        let valid_values = ModelNumberSearch::new(instructions.clone())?.with_supervisor(supervisor).find_valid_model_numbers()?;
+        return valid_values.into_iter().min().ok_or_else(|| AocError::unsolvable("there is no valid model number"));
+    }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_common::Budget;
+
+    fn to_instructions(lines: &[&str]) -> Vec<String> { lines.iter().map(|line| line.to_string()).collect() }
+
+    #[test]
+    fn alu_negates_input() {
+        let mut alu = ArithmeticLogicUnit::new(to_instructions(&["inp x", "mul x -1"])).unwrap();
+        alu.process_input(vec!(7));
+        assert_eq!(alu.get_dimension(Dimension::X), -7);
+    }
+
+    #[test]
+    fn alu_converts_to_binary() {
+        let mut alu = ArithmeticLogicUnit::new(to_instructions(&["inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2",
+            "div w 2", "add x w", "mod x 2", "div w 2", "mod w 2"])).unwrap();
+        alu.process_input(vec!(11));
+        assert_eq!(alu.to_string().split(" | ").count(), 4);
+        assert_eq!((alu.get_dimension(Dimension::W), alu.get_dimension(Dimension::X), alu.get_dimension(Dimension::Y), alu.get_dimension(Dimension::Z)), (1, 0, 1, 1));
+    }
+
+    #[test]
+    fn instructions_split_by_input() {
+        let blocks = split_instructions_by_input(&to_instructions(&["inp w", "add y w", "add y 6", "inp w", "add x 1"]));
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(get_block_y_offset(&blocks[0]), Some(6));
+        assert_eq!(get_block_y_offset(&blocks[1]), None);
+        assert_eq!(get_block_value(&blocks[1], "add x "), Some(1));
+    }
+
+    #[test]
+    fn digits_join_into_number() {
+        assert_eq!(convert_digits_to_number(&vec!(1, 3, 5, 7)), 1357);
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert!(ArithmeticLogicUnit::new(to_instructions(&["add 1 x"])).is_err());
+        assert!(ArithmeticLogicUnit::new(to_instructions(&["mul x"])).is_err());
+        assert!(ArithmeticLogicUnit::new(to_instructions(&["eql x a"])).is_err());
+    }
+
+    #[test]
+    fn giving_up_keeps_the_valid_model_numbers_found() {
+        let instructions = Day24::parse(include_str!("../../inputs/day24.txt")).unwrap();
+        let supervisor = Supervisor::new().with_budget(Budget::unlimited().with_max_steps(3));
+        let mut search = ModelNumberSearch::new(instructions).unwrap().with_supervisor(supervisor);
+
+        match search.find_valid_model_numbers() {
+            Err(AocError::GaveUp { partial_answers, .. }) => {
+                assert!(!partial_answers.is_empty() && partial_answers.len() <= 3);
+                for answer in partial_answers {
+                    let digits : Vec<Value> = answer.to_string().chars().map(|digit| digit.to_digit(10).unwrap() as Value).collect();
+                    assert!(search.check_model_number(&digits).unwrap());
+                }
+            },
+            other => panic!("expected to give up, got {:?}", other),
+        }
+    }
+}
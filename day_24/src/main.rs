@@ -1,99 +1,16 @@
-mod read;
-mod lib;
-
-use lib::{Dimension, ArithmeticLogicUnit};
+use aoc_common::Solution;
+use day_24::{Dimension, ArithmeticLogicUnit, ModelNumberSearch, Day24};
 
 fn main() {
 
     
-    let lines = read::read_lines("input.txt".to_owned());
+    let lines = Day24::parse(&aoc_input::read_to_string("../inputs/day24.txt").unwrap()).unwrap();
     // Manually analysizing each digit
     manually_understanding_stuff(lines.clone());
     println!();
 
-    let mut valid_values : Vec<i64> = Vec::new();
-    for value_1 in 1..=9 {
-        // Efficiency: Jump over invalid: Rule on 14º
-        if value_1 <= 1 { continue }
-
-        for value_2 in 1..=9 {
-            // Efficiency: Jump over invalid: Rule on 13º
-            if value_2 <= 6 { continue }
-
-            for value_3 in 1..=9 {
-                // Efficiency: Jump over invalid: Rule on 4º
-                if value_3 >= 7 { continue }
-
-                for value_4 in 1..=9 {
-                    // Jump over invalid: Rule on 4º
-                    if value_3 + 3 != value_4 { continue }
-                    
-                    for value_5 in 1..=9 {
-                        // Efficiency: Jump over invalid: Rule on 12º
-                        if value_5 >= 2 { continue }
-                        
-                        for value_6 in 1..=9 {
-                            // Efficiency: Jump over invalid: Rule on 9º
-                            if value_6 >= 9 { continue }
-                            
-                            for value_7 in 1..=9 {
-                                // Efficiency: Jump over invalid: Rule on 8º
-                                if value_7 <= 8 { continue }
-                                
-                                for value_8 in 1..=9 {
-                                    // Jump over invalid: Rule on 8º
-                                    if value_7 - 8 != value_8 { continue }
-
-                                    for value_9 in 1..=9 {
-                                        // Jump over invalid: Rule on 9º
-                                        if value_6 + 1 != value_9 { continue }
-
-                                        for value_10 in 1..=9 {
-                                            // Efficiency: Jump over invalid: Rule on 11º
-                                            if value_10 >= 8 { continue }
-
-                                            for value_11 in 1..=9 {
-                                                // Jump over invalid: Rule on 11º
-                                                if value_10 + 2 != value_11 { continue }
-
-                                                for value_12 in 1..=9 {
-                                                    // Jump over invalid: Rule on 12º
-                                                    if value_5 + 8 != value_12 { continue }
-
-                                                    for value_13 in 1..=9 {
-                                                        // Jump over invalid: Rule on 13º
-                                                        if value_2 - 6 != value_13 { continue }
-
-                                                        for value_14 in 1..=9 {
-                                                            // Jump over invalid: Rule on 14º
-                                                            if value_1 - 1 != value_14 { continue }
-
-                                                            let mut alu = ArithmeticLogicUnit::new(lines.clone());
-                                                            let input : Vec<i64> = vec!(value_1, value_2, value_3, value_4, value_5, value_6, value_7,
-                                                                value_8, value_9, value_10, value_11, value_12, value_13, value_14).into_iter().rev().collect();
-                                                            alu.process_input(input.clone());
-                                                            let result = alu.get_dimension(Dimension::Z);
-                                                            if result == 0 {
-                                                                let number : i64 = input.into_iter().enumerate()
-                                                                    .map(|(digit_index, digit)| (10 as i64).pow(digit_index as u32) * digit)
-                                                                    .sum();
-                                                                valid_values.push(number);
-                                                            }
-
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let mut search = ModelNumberSearch::new(lines).unwrap();
+    let valid_values : Vec<i64> = search.find_valid_model_numbers().unwrap();
 
     // Part 1
     let maximum_valid = valid_values.iter().max().unwrap();
@@ -144,7 +61,7 @@ fn manually_understanding_stuff(lines: Vec<String>) {
 }
 
 fn create_and_test_alu(digit: usize, lines: Vec<String>, input: (Vec<i64>, i64, i64, i64, i64)) {
-    let mut current_alu = ArithmeticLogicUnit::new(lines.clone());
+    let mut current_alu = ArithmeticLogicUnit::new(lines.clone()).unwrap();
     current_alu.mannual_fix_dimension(Dimension::X, input.1);
     current_alu.mannual_fix_dimension(Dimension::Y, input.2);
     current_alu.mannual_fix_dimension(Dimension::Z, input.3);
@@ -0,0 +1,21 @@
+use aoc_common::Solution;
+use day_24::Day24;
+
+const INPUT : &str = include_str!("../../inputs/day24.txt");
+
+fn parse(input: &str) -> <Day24 as Solution>::Input { Day24::parse(input).unwrap() }
+
+#[test]
+fn parse_rejects_unknown_instruction() {
+    assert!(Day24::parse("inp w\njmp w 2\n").is_err());
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day24::part1(&parse(INPUT)).unwrap(), 99691891979938);
+}
+
+#[test]
+fn input_part2() {
+    assert_eq!(Day24::part2(&parse(INPUT)).unwrap(), 27141191213911);
+}
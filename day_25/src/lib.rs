@@ -1,29 +1,29 @@
+//! Day 25: Sea Cucumber, waiting for the herds of sea cucumbers to stop moving.
+
 use std::fmt;
-use std::collections::{HashMap};
 
-// ================================================== STRUCTS ==================================================
+use aoc_common::{AocError, AocResult, InputError, Solution};
+use aoc_common::grid::{Edges, Grid, Position};
 
-type PositionUnit = i64;
-type Position = (PositionUnit, PositionUnit);
+// ================================================== STRUCTS ==================================================
 
 #[derive(PartialEq, Copy, Clone)]
 enum PositionType { Free, EastFacingCucumber, SouthFacingCucumber }
 
+/// The sea floor with the east and south facing herds.
 pub struct Map {
     iteration:  usize,
-    map_start:  Position,
-    map_end:    Position,
-    map:        HashMap<Position, PositionType>,
+    map:        Grid<PositionType>,
 }
 
 // ================================================== AUX FUNCTIONS ==================================================
 
-fn convert_characther_position_type(position_characther: char) -> PositionType {
+fn convert_characther_position_type(position_characther: char) -> AocResult<PositionType> {
     match position_characther {
-        '.' => PositionType::Free,
-        '>' => PositionType::EastFacingCucumber,
-        'v' => PositionType::SouthFacingCucumber,
-        _ => panic!("🚨  Characther not recognized as a position type")
+        '.' => Ok(PositionType::Free),
+        '>' => Ok(PositionType::EastFacingCucumber),
+        'v' => Ok(PositionType::SouthFacingCucumber),
+        _ => Err(AocError::malformed(&format!("characther not recognized as a position type '{}'", position_characther))),
     }
 }
 
@@ -39,64 +39,53 @@ fn convert_position_type_characther(position_type: &PositionType) -> char {
 
 impl Map {
 
-    pub fn new(info: Vec<Vec<char>>) -> Map {
+    pub fn new(info: Vec<Vec<char>>) -> AocResult<Map> {
 
-        let map_end_x = info.iter().map(|line| line.len()).max().unwrap() as PositionUnit - 1;
-        let map_end_y = info.len() as PositionUnit - 1;
+        let position_types : Vec<Vec<PositionType>> = info.into_iter()
+            .map(|line| line.into_iter().map(convert_characther_position_type).collect::<AocResult<Vec<PositionType>>>())
+            .collect::<AocResult<Vec<Vec<PositionType>>>>()?;
 
-        let mut map : HashMap<Position, PositionType> = HashMap::new();
-        for (line_index, line) in info.into_iter().enumerate() {
-            for (characther_index, characther) in line.into_iter().enumerate() {
-                
-                let position = (characther_index as PositionUnit, line_index as PositionUnit);
-                let position_type = convert_characther_position_type(characther);
-                map.insert(position, position_type);
-            }
-        }
-
-        Map {
+        // Cucumbers leaving through an edge come back from the opposite one
+        Ok(Map {
             iteration: 0,
-            map_start: (0, 0),
-            map_end: (map_end_x, map_end_y),
-            map: map,
-        }
+            map: Grid::from_rows(position_types)?.with_edges(Edges::Wrapping),
+        })
     }
 
     fn check_position_free(&self, position: Position) -> bool {
-        let position_type_option = self.map.get(&position);
-        return position_type_option.is_some() && *position_type_option.unwrap() == PositionType::Free;
+        return self.map[position] == PositionType::Free;
     }
     
     fn get_position_to_move(&self, position: Position) -> Position {
 
-        let position_type = *self.map.get(&position).unwrap();
-        let new_position = match position_type {
-            PositionType::Free => position,
-            PositionType::EastFacingCucumber => (position.0 + 1, position.1),
-            PositionType::SouthFacingCucumber => (position.0, position.1 + 1),
+        let delta = match self.map[position] {
+            PositionType::Free => (0, 0),
+            PositionType::EastFacingCucumber => (1, 0),
+            PositionType::SouthFacingCucumber => (0, 1),
         };
 
-        return (new_position.0 % (self.map_end.0 + 1) + self.map_start.0, new_position.1 % (self.map_end.1 + 1) + self.map_start.1);
+        return self.map.offset(position, delta).unwrap();
     }
 
     fn iterate_type(&mut self, type_to_iterate: PositionType) -> bool {
 
         let target_points : Vec<(Position, Position)> = self.map.iter()
             .filter(|(_, &position_type)| position_type == type_to_iterate)
-            .map(|(&position, _)| (position, self.get_position_to_move(position)))
+            .map(|(position, _)| (position, self.get_position_to_move(position)))
             .filter(|&(_, position_to)| self.check_position_free(position_to))
             .collect();
 
         let count_changes : usize = target_points.len();
 
         for (position_from, position_to) in target_points.into_iter() {
-            self.map.insert(position_from, PositionType::Free);
-            self.map.insert(position_to, type_to_iterate);
+            self.map[position_from] = PositionType::Free;
+            self.map[position_to] = type_to_iterate;
         }
 
         return count_changes != 0;
     }
 
+    /// Moves the east facing herd and then the south facing one, false once nothing moved.
     pub fn iterate(&mut self) -> bool {
 
         let east_changes = self.iterate_type(PositionType::EastFacingCucumber);
@@ -107,25 +96,77 @@ impl Map {
         return true;
     }
 
+    /// The first step on which no sea cucumber moves.
     pub fn get_final_iteration(&self) -> usize { self.iteration + 1 }
+    pub fn get_cells(&self) -> Grid<char> { self.map.map(convert_position_type_characther) }
 }
 
 impl fmt::Display for Map {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
-        let mut info_printed : String = format!("Iteration {}:\n", self.iteration);
-        for y_value in self.map_start.1..=self.map_end.1 {
-            for x_value in self.map_start.0..=self.map_end.0 {
+        let info_printed : String = self.map.render(convert_position_type_characther);
+        return write!(f, "Iteration {}:\n{}", self.iteration, info_printed);
+    }
+}
 
-                let position : Position = (x_value as PositionUnit, y_value as PositionUnit);
-                let position_type : &PositionType = self.map.get(&position).unwrap();
-                let position_characther = convert_position_type_characther(position_type);
-                info_printed = format!("{}{}", info_printed, position_characther);
-            }
+// ================================================== SOLUTION ==================================================
+
+/// Part 1 is the first step without movement, there is no puzzle for part 2.
+pub struct Day25;
 
-            info_printed = format!("{}\n", info_printed);
+impl Solution for Day25 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, InputError> {
+
+        let lines = aoc_input::read_chars_from(input.as_bytes())?;
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(column) = line.iter().position(|characther| !">v.".contains(*characther)) {
+                return Err(InputError::malformed("expected only '>', 'v' and '.'", index + 1).at(index + 1, column + 1));
+            }
         }
 
-        return write!(f, "{}", info_printed);
+        return Ok(lines);
     }
-}
\ No newline at end of file
+
+    fn part1(info: &Vec<Vec<char>>) -> AocResult<usize> {
+        let mut map = Map::new(info.clone())?;
+        while map.iterate() {}
+        return Ok(map.get_final_iteration());
+    }
+
+    // The last day only has a single puzzle
+    fn part2(_info: &Vec<Vec<char>>) -> AocResult<&'static str> { Ok("") }
+}
+
+// ================================================== TESTS ==================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn cucumbers_wrap_around_edges() {
+        let map = Map::new(vec!(">.v".chars().collect(), "..>".chars().collect())).unwrap();
+        assert_eq!(map.get_position_to_move((0, 0)), (1, 0));
+        assert_eq!(map.get_position_to_move((2, 0)), (2, 1));
+        assert_eq!(map.get_position_to_move((2, 1)), (0, 1));
+        assert_eq!(map.get_position_to_move((1, 1)), (1, 1));
+    }
+
+    #[test]
+    fn herd_stops_when_blocked() {
+        let mut map = Map::new(vec!(">>".chars().collect())).unwrap();
+        assert!(!map.iterate());
+        assert_eq!(map.get_final_iteration(), 1);
+        assert!(map.to_string().ends_with(">>\n"));
+    }
+
+    #[test]
+    fn ragged_map_is_rejected() {
+        assert!(Map::new(vec!(">.v".chars().collect(), ">".chars().collect())).is_err());
+    }
+}
@@ -1,13 +1,11 @@
-mod read;
-mod lib;
-
-use lib::{Map};
+use aoc_common::Solution;
+use day_25::{Map, Day25};
 
 fn main() {
 
     
-    let lines = read::read_chars("input.txt".to_owned());
-    let mut map = Map::new(lines);
+    let lines = Day25::parse(&aoc_input::read_to_string("../inputs/day25.txt").unwrap()).unwrap();
+    let mut map = Map::new(lines).unwrap();
 
     while map.iterate() {};
     //println!("{}", map);
@@ -0,0 +1,17 @@
+use aoc_common::Solution;
+use day_25::Day25;
+
+const EXAMPLE : &str = include_str!("../../inputs/day25.example.txt");
+const INPUT : &str = include_str!("../../inputs/day25.txt");
+
+fn parse(input: &str) -> <Day25 as Solution>::Input { Day25::parse(input).unwrap() }
+
+#[test]
+fn example_part1() {
+    assert_eq!(Day25::part1(&parse(EXAMPLE)).unwrap(), 58);
+}
+
+#[test]
+fn input_part1() {
+    assert_eq!(Day25::part1(&parse(INPUT)).unwrap(), 435);
+}
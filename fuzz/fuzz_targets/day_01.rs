@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_01::Day01;
+
+fuzz_target!(|input: &str| {
+    let _ = Day01::parse(input);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_02::{AimedSubmarine, Day02, Submarine};
+
+fuzz_target!(|input: &str| {
+    if let Ok(commands) = Day02::parse(input) {
+        let _ = Submarine::new().follow(&commands);
+        let _ = AimedSubmarine::new().follow(&commands);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_03::{Day03, Diagnostic};
+
+fuzz_target!(|input: &str| {
+    if let Ok(lines) = Day03::parse(input) {
+        if let Ok(mut diagnostic) = Diagnostic::new(lines) { let _ = diagnostic.calculate_rates(); }
+    }
+});
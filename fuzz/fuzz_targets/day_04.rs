@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_04::{Day04, Game};
+
+fuzz_target!(|input: &str| {
+    if let Ok((called_numbers, cards_numbers)) = Day04::parse(input) {
+        let _ = Game::new(called_numbers, cards_numbers);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_05::{Day05, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(lines) = Day05::parse(input) {
+        if let Ok(mut map) = Map::new(lines, true) {
+            map.mark_lines();
+            let _ = map.count_positions_with_me(2);
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_06::{Day06, ExponentialSeaFloor, SeaFloor};
+
+fuzz_target!(|input: &str| {
+    if let Ok(fish_lives) = Day06::parse(input) {
+        let _ = SeaFloor::new(fish_lives.clone());
+        let _ = ExponentialSeaFloor::new(fish_lives);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_07::{CrabArmy, CrabEngineering, Day07};
+
+fuzz_target!(|input: &str| {
+    if let Ok(positions) = Day07::parse(input) {
+        let _ = CrabArmy::new(CrabEngineering::Constant, &positions);
+    }
+});
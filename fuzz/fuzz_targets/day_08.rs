@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_08::{Day08, Display, EntryProblem};
+
+fuzz_target!(|input: &str| {
+    if let Ok(entries) = Day08::parse(input) {
+        let entries : Vec<EntryProblem> = entries.into_iter().map(|(patterns, output_codes)| EntryProblem::new(patterns, output_codes)).collect();
+        let _ = Display::new(entries).solve_entries();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_10::{Day10, NavigationSubsystem};
+
+fuzz_target!(|input: &str| {
+    if let Ok(lines_chars) = Day10::parse(input) {
+        if let Ok(mut subsystem) = NavigationSubsystem::new(lines_chars) { subsystem.evaluate_lines(); }
+    }
+});
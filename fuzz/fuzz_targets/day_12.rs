@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_12::{Day12, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day12::parse(input) { let _ = Map::new(parsed); }
+});
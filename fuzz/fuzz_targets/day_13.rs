@@ -0,0 +1,14 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_13::{Day13, Map};
+
+// Sheets past the bound are rejected by the library itself, only the ones it accepts get folded
+fuzz_target!(|input: &str| {
+    if let Ok((points, folds)) = Day13::parse(input) {
+        if let Ok(mut map) = Map::new(points, folds) {
+            while map.number_of_folds() != 0 { map.make_next_fold(); }
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_14::{Day14, ExpPolymerization, Polymerization};
+
+fuzz_target!(|input: &str| {
+    if let Ok((template, rules)) = Day14::parse(input) {
+        let _ = Polymerization::new(template.clone(), rules.clone());
+        let _ = ExpPolymerization::new(template, rules);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_15::{Day15, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(values) = Day15::parse(input) { let _ = Map::new(values, 1); }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_16::{Day16, SystemBITS};
+
+fuzz_target!(|input: &str| {
+    if let Ok(transmission_hex) = Day16::parse(input) { let _ = SystemBITS::new(transmission_hex); }
+});
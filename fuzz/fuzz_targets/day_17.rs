@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_17::{Day17, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok((x_start, y_start, x_end, y_end)) = Day17::parse(input) { let _ = Map::new(x_start, y_start, x_end, y_end); }
+});
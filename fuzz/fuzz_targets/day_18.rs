@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_18::{Day18, SnailMathProblem};
+
+fuzz_target!(|input: &str| {
+    if let Ok(input_lines) = Day18::parse(input) { let _ = SnailMathProblem::new(input_lines); }
+});
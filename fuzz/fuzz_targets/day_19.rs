@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_19::{Day19, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(scanners_values) = Day19::parse(input) { let _ = Map::new(scanners_values); }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_20::{Day20, EnhancingTool};
+
+fuzz_target!(|input: &str| {
+    if let Ok((codification, start_image_info)) = Day20::parse(input) { let _ = EnhancingTool::new(codification, start_image_info); }
+});
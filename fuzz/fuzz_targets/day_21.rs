@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_21::Day21;
+
+// The players are only validated when the game starts, and the deterministic game is short
+fuzz_target!(|input: &str| {
+    if let Ok(player_infos) = Day21::parse(input) { let _ = Day21::part1(&player_infos); }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_22::{Day22, LimitedMap, UnlimitedMap};
+
+fuzz_target!(|input: &str| {
+    if let Ok(rules) = Day22::parse(input) {
+        let _ = LimitedMap::new(rules.clone(), vec!(('x', -1, 1), ('y', -1, 1), ('z', -1, 1)));
+        let _ = UnlimitedMap::new(rules);
+    }
+});
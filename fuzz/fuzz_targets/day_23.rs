@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_23::{Day23, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(info_lines) = Day23::parse(input) { let _ = Map::new(info_lines); }
+});
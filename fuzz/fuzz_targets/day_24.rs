@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_24::{ArithmeticLogicUnit, Day24, ModelNumberSearch};
+
+fuzz_target!(|input: &str| {
+    if let Ok(instructions) = Day24::parse(input) {
+        let _ = ArithmeticLogicUnit::new(instructions.clone());
+        let _ = ModelNumberSearch::new(instructions);
+    }
+});
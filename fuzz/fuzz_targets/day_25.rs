@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+use day_25::{Day25, Map};
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Day25::parse(input) { let _ = Map::new(parsed); }
+});
@@ -0,0 +1,44 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use aoc_common::Solution;
+
+fn parses<S: Solution>(input: &str) -> bool { S::parse(input).is_ok() }
+
+// Whatever the seed, a generated input has to be accepted by its own day
+fuzz_target!(|data: (u8, u64, u8)| {
+
+    let (day, seed, size) = data;
+    let day : u8 = 1 + day % 25;
+    let size : usize = 1 + size as usize % 16;
+    let input : String = aoc_generator::generate(day, seed, size).unwrap();
+
+    let parsed : bool = match day {
+        1 => parses::<day_01::Day01>(&input),
+        2 => parses::<day_02::Day02>(&input),
+        3 => parses::<day_03::Day03>(&input),
+        4 => parses::<day_04::Day04>(&input),
+        5 => parses::<day_05::Day05>(&input),
+        6 => parses::<day_06::Day06>(&input),
+        7 => parses::<day_07::Day07>(&input),
+        8 => parses::<day_08::Day08>(&input),
+        9 => parses::<day_09::Day09>(&input),
+        10 => parses::<day_10::Day10>(&input),
+        11 => parses::<day_11::Day11>(&input),
+        12 => parses::<day_12::Day12>(&input),
+        13 => parses::<day_13::Day13>(&input),
+        14 => parses::<day_14::Day14>(&input),
+        15 => parses::<day_15::Day15>(&input),
+        16 => parses::<day_16::Day16>(&input),
+        17 => parses::<day_17::Day17>(&input),
+        18 => parses::<day_18::Day18>(&input),
+        19 => parses::<day_19::Day19>(&input),
+        20 => parses::<day_20::Day20>(&input),
+        21 => parses::<day_21::Day21>(&input),
+        22 => parses::<day_22::Day22>(&input),
+        23 => parses::<day_23::Day23>(&input),
+        24 => parses::<day_24::Day24>(&input),
+        _ => parses::<day_25::Day25>(&input),
+    };
+    assert!(parsed, "day {} seed {} size {}\n{}", day, seed, size, input);
+});